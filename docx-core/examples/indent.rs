@@ -7,21 +7,21 @@ pub fn main() -> Result<(), DocxError> {
     let file = std::fs::File::create(&path).unwrap();
     Docx::new()
         .add_paragraph(Paragraph::new().add_run(Run::new().add_text(DUMMY)).indent(
-            Some(840),
+            Some(Twip(840)),
             None,
             None,
             None,
         ))
         .add_paragraph(Paragraph::new())
         .add_paragraph(Paragraph::new().add_run(Run::new().add_text(DUMMY)).indent(
-            Some(840),
+            Some(Twip(840)),
             Some(SpecialIndentType::FirstLine(720)),
             None,
             None,
         ))
         .add_paragraph(Paragraph::new())
         .add_paragraph(Paragraph::new().add_run(Run::new().add_text(DUMMY)).indent(
-            Some(1560),
+            Some(Twip(1560)),
             Some(SpecialIndentType::Hanging(720)),
             None,
             None,
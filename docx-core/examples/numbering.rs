@@ -19,7 +19,7 @@ pub fn main() -> Result<(), DocxError> {
                     LevelJc::new("left"),
                 )
                 .indent(
-                    Some(1620),
+                    Some(Twip(1620)),
                     Some(SpecialIndentType::Hanging(320)),
                     None,
                     None,
@@ -0,0 +1,147 @@
+use super::*;
+
+// Paragraph and drawing ids are taken from process wide counters when the elements are created,
+// so they depend on everything built before. A deterministic build numbers them again
+// in document order: the body with its comments and section headers, then the header and footer.
+// Paragraph ids which are set or read are kept.
+pub(crate) fn renumber_auto_ids(docx: &mut Docx) {
+    crate::reset_para_id();
+    let mut drawing_id = 1;
+    for c in docx.document.children.iter_mut() {
+        match c {
+            DocumentChild::Paragraph(p) => renumber_paragraph(p, &mut drawing_id),
+            DocumentChild::Table(t) => renumber_table(t, &mut drawing_id),
+            DocumentChild::CommentStart(c) => renumber_comment(&mut c.comment, &mut drawing_id),
+            DocumentChild::Section(s) => {
+                for (_, h) in s.headers.iter_mut() {
+                    renumber_header(h, &mut drawing_id);
+                }
+            }
+            _ => {}
+        }
+    }
+    renumber_header(&mut docx.header, &mut drawing_id);
+    if let Some(footer) = docx.footer.as_mut() {
+        for c in footer.children.iter_mut() {
+            match c {
+                FooterChild::Paragraph(p) => renumber_paragraph(p, &mut drawing_id),
+                FooterChild::Table(t) => renumber_table(t, &mut drawing_id),
+            }
+        }
+    }
+    // Picture bullets share the ids, and so the media names, of the drawings.
+    for b in docx.numberings.picture_bullets.iter_mut() {
+        b.pic.id = drawing_id;
+        drawing_id += 1;
+    }
+}
+
+fn renumber_header(h: &mut Header, drawing_id: &mut usize) {
+    for c in h.children.iter_mut() {
+        match c {
+            HeaderChild::Paragraph(p) => renumber_paragraph(p, drawing_id),
+            HeaderChild::Table(t) => renumber_table(t, drawing_id),
+        }
+    }
+}
+
+fn renumber_table(t: &mut Table, drawing_id: &mut usize) {
+    for row in t.rows.iter_mut() {
+        for cell in row.cells.iter_mut() {
+            for c in cell.children.iter_mut() {
+                match c {
+                    TableCellContent::Paragraph(p) => renumber_paragraph(p, drawing_id),
+                    TableCellContent::Table(t) => renumber_table(t, drawing_id),
+                }
+            }
+        }
+    }
+}
+
+fn renumber_comment(c: &mut Comment, drawing_id: &mut usize) {
+    for c in c.children.iter_mut() {
+        match c {
+            CommentChild::Paragraph(p) => renumber_paragraph(p, drawing_id),
+            CommentChild::Table(t) => renumber_table(t, drawing_id),
+        }
+    }
+}
+
+fn renumber_paragraph(p: &mut Paragraph, drawing_id: &mut usize) {
+    if p.auto_id {
+        p.id = crate::generate_para_id();
+    }
+    for c in p.children.iter_mut() {
+        match c {
+            ParagraphChild::Run(r) => renumber_run(r, drawing_id),
+            ParagraphChild::Insert(i) => {
+                for c in i.children.iter_mut() {
+                    match c {
+                        InsertChild::Run(r) => renumber_run(r, drawing_id),
+                        InsertChild::Delete(d) => renumber_delete(d, drawing_id),
+                        InsertChild::CommentStart(c) => {
+                            renumber_comment(&mut c.comment, drawing_id)
+                        }
+                        InsertChild::CommentEnd(_) => {}
+                    }
+                }
+            }
+            ParagraphChild::Delete(d) => renumber_delete(d, drawing_id),
+            ParagraphChild::MoveFrom(m) => {
+                for r in m.children.iter_mut() {
+                    renumber_run(r, drawing_id);
+                }
+            }
+            ParagraphChild::MoveTo(m) => {
+                for r in m.children.iter_mut() {
+                    renumber_run(r, drawing_id);
+                }
+            }
+            ParagraphChild::CommentStart(c) => renumber_comment(&mut c.comment, drawing_id),
+            _ => {}
+        }
+    }
+}
+
+fn renumber_delete(d: &mut Delete, drawing_id: &mut usize) {
+    for c in d.children.iter_mut() {
+        match c {
+            DeleteChild::Run(r) => renumber_run(r, drawing_id),
+            DeleteChild::CommentStart(c) => renumber_comment(&mut c.comment, drawing_id),
+            DeleteChild::CommentEnd(_) => {}
+        }
+    }
+}
+
+fn renumber_run(r: &mut Run, drawing_id: &mut usize) {
+    for c in r.children.iter_mut() {
+        match c {
+            RunChild::Drawing(d) => {
+                let content = match d.data.as_mut() {
+                    Some(DrawingData::Pic(pic)) => {
+                        pic.id = *drawing_id;
+                        None
+                    }
+                    Some(DrawingData::TextBox(t)) => {
+                        t.id = *drawing_id;
+                        Some(&mut t.content)
+                    }
+                    Some(DrawingData::Shape(s)) => {
+                        s.id = *drawing_id;
+                        Some(&mut s.content)
+                    }
+                    None => continue,
+                };
+                *drawing_id += 1;
+                for c in content.into_iter().flat_map(|c| c.children.iter_mut()) {
+                    match c {
+                        TextBoxContentChild::Paragraph(p) => renumber_paragraph(p, drawing_id),
+                        TextBoxContentChild::Table(t) => renumber_table(t, drawing_id),
+                    }
+                }
+            }
+            RunChild::CommentStart(c) => renumber_comment(&mut c.comment, drawing_id),
+            _ => {}
+        }
+    }
+}
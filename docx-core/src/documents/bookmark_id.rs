@@ -0,0 +1,25 @@
+#[cfg(not(test))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(not(test))]
+static BOOKMARK_ID: AtomicUsize = AtomicUsize::new(1);
+
+#[cfg(not(test))]
+pub fn generate_bookmark_id() -> usize {
+    BOOKMARK_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+// Keep the generated ids past an id which is in use, i.e. read from a document or given explicitly.
+#[cfg(not(test))]
+pub fn reserve_bookmark_id(id: usize) {
+    if let Some(next) = id.checked_add(1) {
+        BOOKMARK_ID.fetch_max(next, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+pub fn generate_bookmark_id() -> usize {
+    123
+}
+
+#[cfg(test)]
+pub fn reserve_bookmark_id(_id: usize) {}
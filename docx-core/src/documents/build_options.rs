@@ -0,0 +1,64 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompressionLevel {
+    // Store entries without compression. Fastest to write.
+    Stored,
+    // Deflate entries for a smaller package.
+    Deflated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum XmlDeclStyle {
+    // The declaration each part writes, i.e. `<?xml version="1.0" encoding="UTF-8"?>`.
+    Default,
+    // `<?xml version="1.0" encoding="UTF-8" standalone="yes"?>` followed by CRLF on every part,
+    // as Word writes it.
+    Word,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BuildOptions {
+    pub compression: CompressionLevel,
+    // Fix timestamps, revision ids and paragraph and drawing ids so that
+    // the same document always packs into byte-identical output.
+    pub deterministic: bool,
+    pub xml_declaration: XmlDeclStyle,
+    // Prepend a UTF-8 byte order mark to every XML part.
+    pub bom: bool,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self {
+            compression: CompressionLevel::Stored,
+            deterministic: false,
+            xml_declaration: XmlDeclStyle::Default,
+            bom: false,
+        }
+    }
+}
+
+impl BuildOptions {
+    pub fn new() -> BuildOptions {
+        Default::default()
+    }
+
+    pub fn compression(mut self, c: CompressionLevel) -> Self {
+        self.compression = c;
+        self
+    }
+
+    pub fn deterministic(mut self, v: bool) -> Self {
+        self.deterministic = v;
+        self
+    }
+
+    pub fn xml_declaration(mut self, s: XmlDeclStyle) -> Self {
+        self.xml_declaration = s;
+        self
+    }
+
+    pub fn bom(mut self, v: bool) -> Self {
+        self.bom = v;
+        self
+    }
+}
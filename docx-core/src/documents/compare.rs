@@ -0,0 +1,544 @@
+use super::*;
+
+// Compare two documents and produce a document containing the revised
+// content with tracked changes against the original, like Word's
+// "Compare Documents". The diff works at paragraph granularity:
+// a changed paragraph shows up as a deleted paragraph followed by an inserted one,
+// moved paragraphs are reported as a deletion and an insertion,
+// and tables of the same shape are compared cell by cell.
+// Rsids and run formatting are not compared, so paragraphs with the same text are kept as is.
+pub fn compare(original: &Docx, revised: &Docx, options: CompareOptions) -> Docx {
+    Comparer { options }.compare(original, revised)
+}
+
+// Every revision is attributed to the author and date, e.g. "2020-01-01T00:00:00Z".
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareOptions {
+    pub author: String,
+    pub date: String,
+}
+
+impl CompareOptions {
+    pub fn new() -> CompareOptions {
+        Default::default()
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> CompareOptions {
+        self.author = author.into();
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> CompareOptions {
+        self.date = date.into();
+        self
+    }
+}
+
+impl Default for CompareOptions {
+    fn default() -> CompareOptions {
+        CompareOptions {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+        }
+    }
+}
+
+struct Comparer {
+    options: CompareOptions,
+}
+
+enum DiffOp<'a> {
+    Equal(&'a DocumentChild),
+    Delete(&'a DocumentChild),
+    Insert(&'a DocumentChild),
+}
+
+impl Comparer {
+    fn compare(&self, original: &Docx, revised: &Docx) -> Docx {
+        let mut docx = revised.clone();
+        docx.document.children =
+            self.compare_children(&original.document.children, &revised.document.children);
+        docx
+    }
+
+    fn insert(&self) -> Insert {
+        Insert::new_with_empty()
+            .author(self.options.author.clone())
+            .date(self.options.date.clone())
+    }
+
+    fn delete(&self) -> Delete {
+        Delete::new()
+            .author(self.options.author.clone())
+            .date(self.options.date.clone())
+    }
+
+    fn compare_children(
+        &self,
+        original: &[DocumentChild],
+        revised: &[DocumentChild],
+    ) -> Vec<DocumentChild> {
+        let mut children = vec![];
+        let mut deleted: Vec<&DocumentChild> = vec![];
+        let mut inserted: Vec<&DocumentChild> = vec![];
+        for op in diff(original, revised) {
+            match op {
+                DiffOp::Delete(c) => deleted.push(c),
+                DiffOp::Insert(c) => inserted.push(c),
+                DiffOp::Equal(c) => {
+                    self.flush(&mut children, &mut deleted, &mut inserted);
+                    children.push(c.clone());
+                }
+            }
+        }
+        self.flush(&mut children, &mut deleted, &mut inserted);
+        children
+    }
+
+    // Emit a run of unmatched children. A single replaced table keeps its
+    // layout and only its cells are compared.
+    fn flush(
+        &self,
+        children: &mut Vec<DocumentChild>,
+        deleted: &mut Vec<&DocumentChild>,
+        inserted: &mut Vec<&DocumentChild>,
+    ) {
+        if let ([DocumentChild::Table(o)], [DocumentChild::Table(r)]) =
+            (deleted.as_slice(), inserted.as_slice())
+        {
+            if same_shape(o, r) {
+                children.push(DocumentChild::Table(self.compare_table(o, r)));
+                deleted.clear();
+                inserted.clear();
+                return;
+            }
+        }
+        for c in deleted.drain(..) {
+            children.push(self.delete_child(c));
+        }
+        for c in inserted.drain(..) {
+            children.push(self.insert_child(c));
+        }
+    }
+
+    fn compare_table(&self, original: &Table, revised: &Table) -> Table {
+        let mut table = revised.clone();
+        for (row, original_row) in table.rows.iter_mut().zip(&original.rows) {
+            for (cell, original_cell) in row.cells.iter_mut().zip(&original_row.cells) {
+                let o: Vec<DocumentChild> = original_cell
+                    .children
+                    .iter()
+                    .map(cell_content_to_child)
+                    .collect();
+                let r: Vec<DocumentChild> =
+                    cell.children.iter().map(cell_content_to_child).collect();
+                cell.children = self
+                    .compare_children(&o, &r)
+                    .into_iter()
+                    .filter_map(child_to_cell_content)
+                    .collect();
+            }
+        }
+        table
+    }
+
+    fn delete_child(&self, c: &DocumentChild) -> DocumentChild {
+        match c {
+            DocumentChild::Paragraph(p) => DocumentChild::Paragraph(self.delete_paragraph(p)),
+            DocumentChild::Table(t) => {
+                DocumentChild::Table(self.map_table(t, |p| self.delete_paragraph(p)))
+            }
+            _ => c.clone(),
+        }
+    }
+
+    fn insert_child(&self, c: &DocumentChild) -> DocumentChild {
+        match c {
+            DocumentChild::Paragraph(p) => DocumentChild::Paragraph(self.insert_paragraph(p)),
+            DocumentChild::Table(t) => {
+                DocumentChild::Table(self.map_table(t, |p| self.insert_paragraph(p)))
+            }
+            _ => c.clone(),
+        }
+    }
+
+    fn map_table(&self, t: &Table, f: impl Fn(&Paragraph) -> Paragraph + Copy) -> Table {
+        let mut t = t.clone();
+        for row in t.rows.iter_mut() {
+            for cell in row.cells.iter_mut() {
+                for c in cell.children.iter_mut() {
+                    match c {
+                        TableCellContent::Paragraph(p) => *p = f(p),
+                        TableCellContent::Table(nested) => *nested = self.map_table(nested, f),
+                    }
+                }
+            }
+        }
+        t
+    }
+
+    // Bookmarks, comment ranges and raw xml have no content of their own and are kept as is.
+    fn delete_paragraph(&self, p: &Paragraph) -> Paragraph {
+        let mut p = p.clone();
+        let mut children = vec![];
+        for c in p.children.drain(..) {
+            match c {
+                ParagraphChild::Run(run) => {
+                    children.push(ParagraphChild::Delete(
+                        self.delete().add_run(to_delete_run(*run)),
+                    ));
+                }
+                ParagraphChild::MoveTo(m) => {
+                    for run in m.children {
+                        children.push(ParagraphChild::Delete(
+                            self.delete().add_run(to_delete_run(run)),
+                        ));
+                    }
+                }
+                ParagraphChild::Insert(ins) => {
+                    for c in ins.children {
+                        match c {
+                            InsertChild::Run(run) => children.push(ParagraphChild::Delete(
+                                self.delete().add_run(to_delete_run(*run)),
+                            )),
+                            InsertChild::Delete(del) => children.push(ParagraphChild::Delete(del)),
+                            InsertChild::CommentStart(c) => {
+                                children.push(ParagraphChild::CommentStart(c))
+                            }
+                            InsertChild::CommentEnd(c) => {
+                                children.push(ParagraphChild::CommentEnd(c))
+                            }
+                        }
+                    }
+                }
+                ParagraphChild::Delete(_)
+                | ParagraphChild::MoveFrom(_)
+                | ParagraphChild::BookmarkStart(_)
+                | ParagraphChild::BookmarkEnd(_)
+                | ParagraphChild::CommentStart(_)
+                | ParagraphChild::CommentEnd(_)
+                | ParagraphChild::RawXml(_) => children.push(c),
+            }
+        }
+        p.children = children;
+        p.property.run_property = p.property.run_property.delete(self.delete());
+        p
+    }
+
+    // Text deleted in the revised paragraph is inserted and deleted, moves and markers are kept as is.
+    fn insert_paragraph(&self, p: &Paragraph) -> Paragraph {
+        let mut p = p.clone();
+        p.children = p
+            .children
+            .drain(..)
+            .map(|c| match c {
+                ParagraphChild::Run(run) => ParagraphChild::Insert(self.insert().add_run(*run)),
+                ParagraphChild::Delete(del) => {
+                    ParagraphChild::Insert(self.insert().add_delete(del))
+                }
+                ParagraphChild::Insert(_)
+                | ParagraphChild::MoveFrom(_)
+                | ParagraphChild::MoveTo(_)
+                | ParagraphChild::BookmarkStart(_)
+                | ParagraphChild::BookmarkEnd(_)
+                | ParagraphChild::CommentStart(_)
+                | ParagraphChild::CommentEnd(_)
+                | ParagraphChild::RawXml(_) => c,
+            })
+            .collect();
+        p.property.run_property = p.property.run_property.insert(self.insert());
+        p
+    }
+}
+
+fn to_delete_run(mut run: Run) -> Run {
+    run.children = run
+        .children
+        .into_iter()
+        .map(|c| match c {
+            RunChild::Text(t) => RunChild::DeleteText(t.into()),
+            _ => c,
+        })
+        .collect();
+    run
+}
+
+fn cell_content_to_child(c: &TableCellContent) -> DocumentChild {
+    match c {
+        TableCellContent::Paragraph(p) => DocumentChild::Paragraph(p.clone()),
+        TableCellContent::Table(t) => DocumentChild::Table(t.clone()),
+    }
+}
+
+fn child_to_cell_content(c: DocumentChild) -> Option<TableCellContent> {
+    match c {
+        DocumentChild::Paragraph(p) => Some(TableCellContent::Paragraph(p)),
+        DocumentChild::Table(t) => Some(TableCellContent::Table(t)),
+        _ => None,
+    }
+}
+
+fn same_shape(a: &Table, b: &Table) -> bool {
+    a.rows.len() == b.rows.len()
+        && a.rows
+            .iter()
+            .zip(&b.rows)
+            .all(|(a, b)| a.cells.len() == b.cells.len())
+}
+
+// What a paragraph is compared by. Texts of adjacent runs are joined, so runs split
+// differently do not count as a change.
+#[derive(PartialEq)]
+enum Content<'a> {
+    Text(String),
+    RunChild(&'a RunChild),
+    Child(&'a ParagraphChild),
+}
+
+fn paragraph_content(p: &Paragraph) -> Vec<Content<'_>> {
+    let mut content = vec![];
+    for c in &p.children {
+        match c {
+            ParagraphChild::Run(run) => {
+                for c in &run.children {
+                    match (content.last_mut(), c) {
+                        (Some(Content::Text(prev)), RunChild::Text(t)) => prev.push_str(&t.text),
+                        (_, RunChild::Text(t)) => content.push(Content::Text(t.text.clone())),
+                        (_, c) => content.push(Content::RunChild(c)),
+                    }
+                }
+            }
+            _ => content.push(Content::Child(c)),
+        }
+    }
+    content
+}
+
+// Paragraph ids are generated, and rsids and run properties only record who formatted
+// what, so none of them are part of the comparison.
+fn same_paragraph(a: &Paragraph, b: &Paragraph) -> bool {
+    let property = |p: &Paragraph| ParagraphProperty {
+        rsids: Rsids::new(),
+        run_property: RunProperty::new(),
+        ..p.property.clone()
+    };
+    paragraph_content(a) == paragraph_content(b) && property(a) == property(b)
+}
+
+fn same_table(a: &Table, b: &Table) -> bool {
+    a.property == b.property
+        && a.grid == b.grid
+        && a.rows.len() == b.rows.len()
+        && a.rows.iter().zip(&b.rows).all(|(a, b)| {
+            a.property == b.property
+                && a.cells.len() == b.cells.len()
+                && a.cells.iter().zip(&b.cells).all(|(a, b)| {
+                    a.property == b.property
+                        && a.children.len() == b.children.len()
+                        && a.children
+                            .iter()
+                            .zip(&b.children)
+                            .all(|(a, b)| match (a, b) {
+                                (
+                                    TableCellContent::Paragraph(a),
+                                    TableCellContent::Paragraph(b),
+                                ) => same_paragraph(a, b),
+                                (TableCellContent::Table(a), TableCellContent::Table(b)) => {
+                                    same_table(a, b)
+                                }
+                                _ => false,
+                            })
+                })
+        })
+}
+
+fn same_child(a: &DocumentChild, b: &DocumentChild) -> bool {
+    match (a, b) {
+        (DocumentChild::Paragraph(a), DocumentChild::Paragraph(b)) => same_paragraph(a, b),
+        (DocumentChild::Table(a), DocumentChild::Table(b)) => same_table(a, b),
+        _ => a == b,
+    }
+}
+
+// Longest common subsequence of the two child lists.
+fn diff<'a>(original: &'a [DocumentChild], revised: &'a [DocumentChild]) -> Vec<DiffOp<'a>> {
+    let (n, m) = (original.len(), revised.len());
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if same_child(&original[i], &revised[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if same_child(&original[i], &revised[j]) {
+            ops.push(DiffOp::Equal(&revised[j]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            ops.push(DiffOp::Delete(&original[i]));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(&revised[j]));
+            j += 1;
+        }
+    }
+    ops.extend(original[i..].iter().map(DiffOp::Delete));
+    ops.extend(revised[j..].iter().map(DiffOp::Insert));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn paragraph(text: &str) -> Paragraph {
+        Paragraph::new().add_run(Run::new().add_text(text))
+    }
+
+    fn options() -> CompareOptions {
+        CompareOptions::new()
+            .author("bokuweb")
+            .date("2020-01-01T00:00:00Z")
+    }
+
+    #[test]
+    fn test_compare_paragraphs() {
+        let original = Docx::new()
+            .add_paragraph(paragraph("Hello"))
+            .add_paragraph(paragraph("Removed"))
+            .add_paragraph(paragraph("World"));
+        let revised = Docx::new()
+            .add_paragraph(paragraph("Hello"))
+            .add_paragraph(paragraph("World"))
+            .add_paragraph(paragraph("Added"));
+        let docx = compare(&original, &revised, options());
+
+        let del = Delete::new().author("bokuweb").date("2020-01-01T00:00:00Z");
+        let ins = Insert::new_with_empty()
+            .author("bokuweb")
+            .date("2020-01-01T00:00:00Z");
+        let mut removed =
+            Paragraph::new().add_delete(del.clone().add_run(Run::new().add_delete_text("Removed")));
+        removed.property.run_property = RunProperty::new().delete(del);
+        let mut added =
+            Paragraph::new().add_insert(ins.clone().add_run(Run::new().add_text("Added")));
+        added.property.run_property = RunProperty::new().insert(ins);
+
+        assert_eq!(
+            docx.document.children,
+            vec![
+                DocumentChild::Paragraph(paragraph("Hello")),
+                DocumentChild::Paragraph(removed),
+                DocumentChild::Paragraph(paragraph("World")),
+                DocumentChild::Paragraph(added),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_table_cells() {
+        let table = |text: &str| {
+            Table::new(vec![TableRow::new(vec![
+                TableCell::new().add_paragraph(paragraph("Same")),
+                TableCell::new().add_paragraph(paragraph(text)),
+            ])])
+        };
+        let docx = compare(
+            &Docx::new().add_table(table("Before")),
+            &Docx::new().add_table(table("After")),
+            options(),
+        );
+
+        if let DocumentChild::Table(t) = &docx.document.children[0] {
+            assert_eq!(docx.document.children.len(), 1);
+            assert_eq!(
+                t.rows[0].cells[0].children,
+                vec![TableCellContent::Paragraph(paragraph("Same"))]
+            );
+            assert_eq!(t.rows[0].cells[1].children.len(), 2);
+        } else {
+            panic!("table expected");
+        }
+    }
+
+    #[test]
+    fn test_compare_marks_every_child() {
+        let del = Delete::new().author("bokuweb").date("2020-01-01T00:00:00Z");
+        let ins = Insert::new_with_empty()
+            .author("bokuweb")
+            .date("2020-01-01T00:00:00Z");
+        let moved = Paragraph::new()
+            .add_run(Run::new().add_text("Kept"))
+            .add_move_to(MoveTo::new().add_run(Run::new().add_text("Moved")));
+        let redlined = Paragraph::new()
+            .add_run(Run::new().add_text("New"))
+            .add_delete(Delete::new().add_run(Run::new().add_delete_text("Old")));
+        let docx = compare(
+            &Docx::new().add_paragraph(moved),
+            &Docx::new().add_paragraph(redlined),
+            options(),
+        );
+
+        let mut removed = Paragraph::new()
+            .add_delete(del.clone().add_run(Run::new().add_delete_text("Kept")))
+            .add_delete(del.clone().add_run(Run::new().add_delete_text("Moved")));
+        removed.property.run_property = RunProperty::new().delete(del);
+        let mut added = Paragraph::new()
+            .add_insert(ins.clone().add_run(Run::new().add_text("New")))
+            .add_insert(
+                ins.clone()
+                    .add_delete(Delete::new().add_run(Run::new().add_delete_text("Old"))),
+            );
+        added.property.run_property = RunProperty::new().insert(ins);
+
+        assert_eq!(
+            docx.document.children,
+            vec![
+                DocumentChild::Paragraph(removed),
+                DocumentChild::Paragraph(added),
+            ]
+        );
+    }
+    #[test]
+    fn test_compare_ignores_rsids_and_run_properties() {
+        let original = Paragraph::new()
+            .rsids(Rsids::new().r("00A1"))
+            .add_run(Run::new().add_text("Hello ").rsids(Rsids::new().r("00A1")))
+            .add_run(Run::new().add_text("World"));
+        let revised = Paragraph::new()
+            .rsids(Rsids::new().r("00B2"))
+            .add_run(Run::new().add_text("Hello World").bold());
+        let docx = compare(
+            &Docx::new().add_paragraph(original),
+            &Docx::new().add_paragraph(revised.clone()),
+            CompareOptions::new(),
+        );
+        assert_eq!(
+            docx.document.children,
+            vec![DocumentChild::Paragraph(revised)]
+        );
+    }
+
+    #[test]
+    fn test_compare_options_default() {
+        let docx = compare(
+            &Docx::new().add_paragraph(paragraph("Before")),
+            &Docx::new().add_paragraph(paragraph("After")),
+            CompareOptions::new(),
+        );
+        let mut removed = Paragraph::new()
+            .add_delete(Delete::new().add_run(Run::new().add_delete_text("Before")));
+        removed.property.run_property = RunProperty::new().delete(Delete::new());
+        assert_eq!(docx.document.children[0], DocumentChild::Paragraph(removed));
+    }
+}
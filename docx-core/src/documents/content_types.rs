@@ -5,6 +5,7 @@ use xml::reader::{EventReader, XmlEvent};
 
 use crate::documents::BuildXML;
 use crate::reader::{FromXML, ReaderError};
+use crate::types::AltChunkType;
 use crate::xml_builder::*;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -12,6 +13,9 @@ pub struct ContentTypes {
     types: BTreeMap<String, String>,
     web_extension_count: usize,
     custom_xml_count: usize,
+    // Default content types by extension. Only recorded when reading a package.
+    #[serde(default)]
+    defaults: BTreeMap<String, String>,
 }
 
 impl ContentTypes {
@@ -19,6 +23,29 @@ impl ContentTypes {
         Default::default()
     }
 
+    // Overrides keyed by part name, e.g. "/word/document.xml".
+    pub fn types(&self) -> &BTreeMap<String, String> {
+        &self.types
+    }
+
+    pub fn defaults(&self) -> &BTreeMap<String, String> {
+        &self.defaults
+    }
+
+    // Resolve the content type of a part by its override or by the default of its extension.
+    pub fn content_type_of(&self, part_name: &str) -> Option<&str> {
+        if let Some(t) = self.types.get(part_name) {
+            return Some(t);
+        }
+        let ext = part_name.rsplit('.').next()?.to_lowercase();
+        self.defaults.get(&ext).map(|t| t.as_str())
+    }
+
+    pub fn add_default(mut self, ext: impl Into<String>, content_type: impl Into<String>) -> Self {
+        self.defaults.insert(ext.into(), content_type.into());
+        self
+    }
+
     pub fn add_content(mut self, path: impl Into<String>, namespace: impl Into<String>) -> Self {
         self.types.insert(path.into(), namespace.into());
         self
@@ -94,6 +121,60 @@ impl ContentTypes {
         self
     }
 
+    pub fn add_web_settings(mut self) -> Self {
+        self.types.insert(
+            "/word/webSettings.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.webSettings+xml"
+                .to_owned(),
+        );
+        self
+    }
+
+    pub fn add_header(mut self, id: usize) -> Self {
+        self.types.insert(
+            format!("/word/header{}.xml", id),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.header+xml".to_owned(),
+        );
+        self
+    }
+
+    pub fn add_footer(mut self, id: usize) -> Self {
+        self.types.insert(
+            format!("/word/footer{}.xml", id),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml".to_owned(),
+        );
+        self
+    }
+
+    pub fn add_alt_chunk(mut self, part_name: &str, chunk_type: AltChunkType) -> Self {
+        self.types.insert(
+            format!("/word/{}", part_name),
+            chunk_type.content_type().to_owned(),
+        );
+        self
+    }
+
+    // Marks the main document as macro-enabled and registers the VBA project part.
+    pub fn add_vba_project(mut self) -> Self {
+        self.types.insert(
+            "/word/document.xml".to_owned(),
+            "application/vnd.ms-word.document.macroEnabled.main+xml".to_owned(),
+        );
+        self.types.insert(
+            "/word/vbaProject.bin".to_owned(),
+            "application/vnd.ms-office.vbaProject".to_owned(),
+        );
+        self
+    }
+
+    pub fn add_theme(mut self) -> Self {
+        self.types.insert(
+            "/word/theme/theme1.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.theme+xml".to_owned(),
+        );
+        self
+    }
+
     pub fn add_web_extensions(mut self) -> Self {
         self.types.insert(
             format!(
@@ -122,6 +203,7 @@ impl Default for ContentTypes {
             types: BTreeMap::new(),
             web_extension_count: 1,
             custom_xml_count: 1,
+            defaults: BTreeMap::new(),
         }
     }
 }
@@ -156,19 +238,26 @@ impl FromXML for ContentTypes {
     fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
         let parser = EventReader::new(reader);
         let mut s = Self::default();
-        let mut depth = 0;
         for e in parser {
             match e {
-                Ok(XmlEvent::StartElement { attributes, .. }) => {
-                    if depth == 1 {
-                        let namespace = attributes[0].value.clone();
-                        let path = attributes[1].value.clone();
-                        s = s.add_content(path, namespace);
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let value = |target: &str| {
+                        attributes
+                            .iter()
+                            .find(|a| a.name.local_name == target)
+                            .map(|a| a.value.clone())
+                    };
+                    if name.local_name == "Override" {
+                        if let (Some(path), Some(t)) = (value("PartName"), value("ContentType")) {
+                            s = s.add_content(path, t);
+                        }
+                    } else if name.local_name == "Default" {
+                        if let (Some(ext), Some(t)) = (value("Extension"), value("ContentType")) {
+                            s = s.add_default(ext.to_lowercase(), t);
+                        }
                     }
-                    depth += 1;
-                }
-                Ok(XmlEvent::EndElement { .. }) => {
-                    depth -= 1;
                 }
                 Err(_) => {}
                 _ => {}
@@ -188,6 +277,7 @@ mod tests {
     #[test]
     fn test_from_xml() {
         let xml = r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+        <Default Extension="bin" ContentType="application/vnd.ms-office.vbaProject"/>
         <Override ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml" PartName="/word/document.xml"></Override></Types>"#;
         let c = ContentTypes::from_xml(xml.as_bytes()).unwrap();
         let mut types = BTreeMap::new();
@@ -196,13 +286,23 @@ mod tests {
             "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"
                 .to_owned(),
         );
+        let mut defaults = BTreeMap::new();
+        defaults.insert(
+            "bin".to_owned(),
+            "application/vnd.ms-office.vbaProject".to_owned(),
+        );
         assert_eq!(
             ContentTypes {
                 types,
                 web_extension_count: 1,
-                custom_xml_count: 1
+                custom_xml_count: 1,
+                defaults,
             },
             c
         );
+        assert_eq!(
+            c.content_type_of("/word/vbaProject.bin"),
+            Some("application/vnd.ms-office.vbaProject")
+        );
     }
 }
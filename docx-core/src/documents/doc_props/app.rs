@@ -1,32 +1,47 @@
 use serde::Serialize;
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, DocumentStatistics};
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AppProps {}
+pub struct AppProps {
+    pub statistics: Option<DocumentStatistics>,
+}
 
 impl AppProps {
     pub fn new() -> AppProps {
         Default::default()
     }
+
+    pub fn statistics(mut self, s: DocumentStatistics) -> AppProps {
+        self.statistics = Some(s);
+        self
+    }
 }
 
 impl Default for AppProps {
     fn default() -> Self {
-        Self {}
+        Self { statistics: None }
     }
 }
 
 impl BuildXML for AppProps {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        let base = b.declaration(Some(true)).open_properties(
+        let mut b = b.declaration(Some(true)).open_properties(
             "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
             "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
         );
-        base.close().build()
+        if let Some(s) = &self.statistics {
+            b = b
+                .pages(&format!("{}", s.pages))
+                .words(&format!("{}", s.words))
+                .characters(&format!("{}", s.characters))
+                .paragraphs(&format!("{}", s.paragraphs))
+                .characters_with_spaces(&format!("{}", s.characters_with_spaces));
+        }
+        b.close().build()
     }
 }
 
@@ -48,4 +63,27 @@ mod tests {
 <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes" />"#
         );
     }
+
+    #[test]
+    fn test_doc_props_app_build_with_statistics() {
+        let c = AppProps::new().statistics(DocumentStatistics {
+            words: 3,
+            characters: 15,
+            characters_with_spaces: 17,
+            paragraphs: 1,
+            pages: 1,
+        });
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+  <Pages>1</Pages>
+  <Words>3</Words>
+  <Characters>15</Characters>
+  <Paragraphs>1</Paragraphs>
+  <CharactersWithSpaces>17</CharactersWithSpaces>
+</Properties>"#
+        );
+    }
 }
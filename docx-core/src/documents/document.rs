@@ -3,6 +3,8 @@ use serde::Serialize;
 
 use super::*;
 use crate::documents::BuildXML;
+use crate::errors::DocxError;
+use crate::types::*;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -14,6 +16,7 @@ pub struct Document {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum DocumentChild {
     Paragraph(Paragraph),
     Table(Table),
@@ -21,6 +24,8 @@ pub enum DocumentChild {
     BookmarkEnd(BookmarkEnd),
     CommentStart(Box<CommentRangeStart>),
     CommentEnd(CommentRangeEnd),
+    Section(Box<Section>),
+    AltChunk(AltChunk),
 }
 
 impl Serialize for DocumentChild {
@@ -65,6 +70,18 @@ impl Serialize for DocumentChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            DocumentChild::Section(ref r) => {
+                let mut t = serializer.serialize_struct("Section", 2)?;
+                t.serialize_field("type", "section")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            DocumentChild::AltChunk(ref r) => {
+                let mut t = serializer.serialize_struct("AltChunk", 2)?;
+                t.serialize_field("type", "altChunk")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -112,6 +129,38 @@ impl Document {
         self
     }
 
+    // Ends the section which has the children added before this.
+    pub fn add_section(mut self, s: Section) -> Self {
+        self.children.push(DocumentChild::Section(Box::new(s)));
+        self
+    }
+
+    // Keeps the children in the range on one page by setting keepNext on all but the last paragraph.
+    pub fn keep_together(mut self, range: std::ops::Range<usize>) -> Self {
+        let end = range.end.min(self.children.len());
+        for i in range.start..end {
+            let last = i + 1 == end;
+            match &mut self.children[i] {
+                DocumentChild::Paragraph(p) if !last => p.property.keep_next = true,
+                DocumentChild::Table(t) => {
+                    let rows = if last {
+                        t.rows.len().saturating_sub(1)
+                    } else {
+                        t.rows.len()
+                    };
+                    t.set_keep_next(true, rows);
+                }
+                _ => {}
+            }
+        }
+        self
+    }
+
+    pub fn add_alt_chunk(mut self, c: AltChunk) -> Self {
+        self.children.push(DocumentChild::AltChunk(c));
+        self
+    }
+
     pub fn add_comment_start(mut self, comment: Comment) -> Self {
         self.children.push(DocumentChild::CommentStart(Box::new(
             CommentRangeStart::new(comment),
@@ -125,6 +174,24 @@ impl Document {
         self
     }
 
+    /// Replace the content of the bookmark named `name` with `children`.
+    /// BookmarkStart and BookmarkEnd are kept, so the bookmark wraps the inserted content.
+    /// The first bookmark of the name in document order is used, including those in table cells.
+    /// If the bookmark spans paragraphs, the paragraphs in between are removed and
+    /// the content is inserted into the paragraph which has the BookmarkStart.
+    /// The document is left unchanged if there is no such bookmark.
+    pub fn insert_at_bookmark(
+        &mut self,
+        name: &str,
+        children: Vec<ParagraphChild>,
+    ) -> Result<(), DocxError> {
+        if replace_bookmark_content(&mut self.children, name, &children) {
+            Ok(())
+        } else {
+            Err(DocxError::BookmarkNotFound(name.to_owned()))
+        }
+    }
+
     pub fn page_size(mut self, size: PageSize) -> Self {
         self.section_property = self.section_property.page_size(size);
         self
@@ -149,6 +216,47 @@ impl Document {
         self.section_property = property;
         self
     }
+
+    // Split the body into its sections.
+    // A section ends with a paragraph holding its properties, or with an added `Section`,
+    // and the last one uses the properties of the body.
+    pub fn sections(&self) -> Vec<SectionView<'_>> {
+        let mut sections = vec![];
+        let mut start = 0;
+        for (i, c) in self.children.iter().enumerate() {
+            match c {
+                DocumentChild::Paragraph(p) => {
+                    if let Some(property) = &p.property.section_property {
+                        sections.push(SectionView {
+                            property,
+                            children: &self.children[start..=i],
+                        });
+                        start = i + 1;
+                    }
+                }
+                DocumentChild::Section(s) => {
+                    sections.push(SectionView {
+                        property: &s.property,
+                        children: &self.children[start..i],
+                    });
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        sections.push(SectionView {
+            property: &self.section_property,
+            children: &self.children[start..],
+        });
+        sections
+    }
+}
+
+// The body content of a section with the properties of the section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionView<'a> {
+    pub property: &'a SectionProperty,
+    pub children: &'a [DocumentChild],
 }
 
 impl BuildXML for DocumentChild {
@@ -160,22 +268,161 @@ impl BuildXML for DocumentChild {
             DocumentChild::BookmarkEnd(v) => v.build(),
             DocumentChild::CommentStart(v) => v.build(),
             DocumentChild::CommentEnd(v) => v.build(),
+            DocumentChild::Section(v) => v.build(),
+            DocumentChild::AltChunk(v) => v.build(),
         }
     }
 }
 
 impl BuildXML for Document {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
+        let mut b = XMLBuilder::new()
             .declaration(Some(true))
             .open_document()
-            .open_body()
-            .add_children(&self.children)
-            .add_child(&self.section_property)
-            .close()
-            .close()
-            .build()
+            .open_body();
+
+        for (i, c) in self.children.iter().enumerate() {
+            b = b.add_child(c);
+            if let DocumentChild::Section(s) = c {
+                if s.balance_columns {
+                    // Word balances the columns when the next section starts with a
+                    // continuous break. If it does not, an empty continuous section
+                    // with the page setup of the next section is put in between.
+                    let next = self.children[i + 1..]
+                        .iter()
+                        .find_map(|c| match c {
+                            DocumentChild::Section(s) => Some(&s.property),
+                            _ => None,
+                        })
+                        .unwrap_or(&self.section_property);
+                    if !next.is_continuous() {
+                        let p = next.clone().section_type(SectionType::Continuous);
+                        b = b.add_child(&Section::new().property(p));
+                    }
+                }
+            }
+        }
+
+        b.add_child(&self.section_property).close().close().build()
+    }
+}
+
+// Replaces the content of the bookmark in the blocks, searching table cells too.
+// Returns false if the bookmark does not start, or does not end, in the same blocks.
+fn replace_bookmark_content(
+    blocks: &mut Vec<DocumentChild>,
+    name: &str,
+    children: &[ParagraphChild],
+) -> bool {
+    // (index of block, position in paragraph, bookmark id)
+    let mut start: Option<(usize, Option<usize>, usize)> = None;
+    for (i, c) in blocks.iter_mut().enumerate() {
+        match c {
+            DocumentChild::Paragraph(p) => {
+                if let Some((pos, id)) = p.bookmark_start_position(name) {
+                    start = Some((i, Some(pos), id));
+                    break;
+                }
+            }
+            DocumentChild::BookmarkStart(b) if b.name == name => {
+                start = Some((i, None, b.id));
+                break;
+            }
+            DocumentChild::Table(t) => {
+                let replaced = replace_table_bookmark_content(t, name, children);
+                if replaced {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    let (start_index, start_pos, id) = match start {
+        Some(s) => s,
+        None => return false,
+    };
+
+    let mut end: Option<(usize, Option<usize>)> = None;
+    for (i, c) in blocks.iter().enumerate().skip(start_index) {
+        match c {
+            DocumentChild::Paragraph(p) => {
+                // In the paragraph of the start, only the BookmarkEnd after it counts.
+                let from = if i == start_index {
+                    start_pos.map_or(0, |pos| pos + 1)
+                } else {
+                    0
+                };
+                if let Some(pos) = p.bookmark_end_position(id, from) {
+                    end = Some((i, Some(pos)));
+                    break;
+                }
+            }
+            DocumentChild::BookmarkEnd(b) if b.id == id => {
+                end = Some((i, None));
+                break;
+            }
+            _ => {}
+        }
+    }
+    let (end_index, end_pos) = match end {
+        Some(e) => e,
+        None => return false,
+    };
+
+    if let (Some(start), Some(end), DocumentChild::Paragraph(p)) =
+        (start_pos, end_pos, &mut blocks[start_index])
+    {
+        if start_index == end_index {
+            p.children.splice(start + 1..end, children.to_vec());
+            return true;
+        }
     }
+
+    // Remove the content which is wrapped by the bookmark.
+    if let (Some(pos), DocumentChild::Paragraph(p)) = (end_pos, &mut blocks[end_index]) {
+        p.children.drain(..pos);
+    }
+    blocks.drain(start_index + 1..end_index);
+    match (start_pos, &mut blocks[start_index]) {
+        (Some(pos), DocumentChild::Paragraph(p)) => {
+            p.children.truncate(pos + 1);
+            p.children.extend(children.to_vec());
+        }
+        _ => {
+            let mut p = Paragraph::new();
+            p.children = children.to_vec();
+            blocks.insert(start_index + 1, DocumentChild::Paragraph(p));
+        }
+    }
+    true
+}
+
+fn replace_table_bookmark_content(t: &mut Table, name: &str, children: &[ParagraphChild]) -> bool {
+    for row in t.rows.iter_mut() {
+        for cell in row.cells.iter_mut() {
+            let mut blocks = cell
+                .children
+                .drain(..)
+                .map(|c| match c {
+                    TableCellContent::Paragraph(p) => DocumentChild::Paragraph(p),
+                    TableCellContent::Table(t) => DocumentChild::Table(t),
+                })
+                .collect();
+            let replaced = replace_bookmark_content(&mut blocks, name, children);
+            cell.children = blocks
+                .into_iter()
+                .filter_map(|c| match c {
+                    DocumentChild::Paragraph(p) => Some(TableCellContent::Paragraph(p)),
+                    DocumentChild::Table(t) => Some(TableCellContent::Table(t)),
+                    _ => None,
+                })
+                .collect();
+            if replaced {
+                return true;
+            }
+        }
+    }
+    false
 }
 
 #[cfg(test)]
@@ -200,4 +447,194 @@ mod tests {
 </w:document>"#
         );
     }
+
+    #[test]
+    fn test_document_with_balanced_section() {
+        let b = Document::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            .add_section(Section::new().columns(2).balance_columns(true))
+            .build();
+        let s = str::from_utf8(&b).unwrap();
+        assert!(s.contains(
+            r#"<w:p w14:paraId="12345678">
+  <w:pPr><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:headerReference w:type="default" r:id="rId4" /><w:cols w:space="425" w:num="2" /><w:docGrid w:type="lines" w:linePitch="360" /></w:sectPr></w:pPr>
+</w:p>"#
+        ));
+        assert!(s.contains(
+            r#"<w:p w14:paraId="12345678">
+  <w:pPr><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:headerReference w:type="default" r:id="rId4" /><w:cols w:space="425" /><w:docGrid w:type="lines" w:linePitch="360" /><w:type w:val="continuous" />
+</w:sectPr></w:pPr>
+</w:p><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:headerReference w:type="default" r:id="rId4" /><w:cols w:space="425" /><w:docGrid w:type="lines" w:linePitch="360" /></w:sectPr></w:body>"#
+        ));
+    }
+
+    #[test]
+    fn test_document_with_balanced_section_followed_by_continuous_section() {
+        let b = Document::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            .add_section(Section::new().columns(2).balance_columns(true))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("World")))
+            .add_section(
+                Section::new()
+                    .property(SectionProperty::new().section_type(SectionType::Continuous)),
+            )
+            .build();
+        let s = str::from_utf8(&b).unwrap();
+        assert_eq!(s.matches("<w:sectPr>").count(), 3);
+        assert_eq!(s.matches(r#"<w:type w:val="continuous" />"#).count(), 1);
+    }
+
+    #[test]
+    fn test_insert_at_bookmark() {
+        let mut d = Document::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Dear "))
+                .add_bookmark_start(0, "name")
+                .add_bookmark_end(0)
+                .add_run(Run::new().add_text(",")),
+        );
+        d.insert_at_bookmark(
+            "name",
+            vec![ParagraphChild::Run(Box::new(Run::new().add_text("Alice")))],
+        )
+        .unwrap();
+        assert_eq!(
+            d.children,
+            vec![DocumentChild::Paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Dear "))
+                    .add_bookmark_start(0, "name")
+                    .add_run(Run::new().add_text("Alice"))
+                    .add_bookmark_end(0)
+                    .add_run(Run::new().add_text(","))
+            )]
+        );
+    }
+
+    #[test]
+    fn test_insert_at_bookmark_across_paragraphs() {
+        let mut d = Document::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("A"))
+                    .add_bookmark_start(1, "range")
+                    .add_run(Run::new().add_text("B")),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("C")))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("D"))
+                    .add_bookmark_end(1)
+                    .add_run(Run::new().add_text("E")),
+            );
+        d.insert_at_bookmark(
+            "range",
+            vec![ParagraphChild::Run(Box::new(Run::new().add_text("X")))],
+        )
+        .unwrap();
+        assert_eq!(
+            d.children,
+            vec![
+                DocumentChild::Paragraph(
+                    Paragraph::new()
+                        .add_run(Run::new().add_text("A"))
+                        .add_bookmark_start(1, "range")
+                        .add_run(Run::new().add_text("X"))
+                ),
+                DocumentChild::Paragraph(
+                    Paragraph::new()
+                        .add_bookmark_end(1)
+                        .add_run(Run::new().add_text("E"))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_at_bookmark_in_table_cell() {
+        let cell =
+            |p: Paragraph| Table::new(vec![TableRow::new(vec![TableCell::new().add_paragraph(p)])]);
+        let mut d = Document::new().add_table(cell(
+            Paragraph::new()
+                .add_bookmark_start(2, "cell")
+                .add_bookmark_end(3)
+                .add_run(Run::new().add_text("old"))
+                .add_bookmark_end(2),
+        ));
+        d.insert_at_bookmark(
+            "cell",
+            vec![ParagraphChild::Run(Box::new(Run::new().add_text("new")))],
+        )
+        .unwrap();
+        assert_eq!(
+            d.children,
+            vec![DocumentChild::Table(cell(
+                Paragraph::new()
+                    .add_bookmark_start(2, "cell")
+                    .add_run(Run::new().add_text("new"))
+                    .add_bookmark_end(2),
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_insert_at_missing_bookmark() {
+        let mut d = Document::new().add_paragraph(Paragraph::new().add_bookmark_end(0));
+        assert!(matches!(
+            d.insert_at_bookmark("missing", vec![]),
+            Err(DocxError::BookmarkNotFound(name)) if name == "missing"
+        ));
+        assert_eq!(d.children.len(), 1);
+    }
+
+    #[test]
+    fn test_sections() {
+        let mut last = Paragraph::new().add_run(Run::new().add_text("B"));
+        last.property.section_property = Some(SectionProperty::new().columns(2));
+        let d = Document::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("A")))
+            .add_section(Section::new())
+            .add_paragraph(last)
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("C")));
+        let sections = d.sections();
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].children, &d.children[0..1]);
+        assert_eq!(sections[1].children, &d.children[2..3]);
+        assert_eq!(sections[1].property, &SectionProperty::new().columns(2));
+        assert_eq!(sections[2].children, &d.children[3..]);
+        assert_eq!(sections[2].property, &d.section_property);
+    }
+
+    #[test]
+    fn test_keep_together() {
+        let table = Table::new(vec![
+            TableRow::new(vec![TableCell::new().add_paragraph(Paragraph::new())]),
+            TableRow::new(vec![TableCell::new().add_paragraph(Paragraph::new())]),
+        ]);
+        let doc = Document::new()
+            .add_paragraph(Paragraph::new())
+            .add_paragraph(Paragraph::new())
+            .add_table(table)
+            .add_paragraph(Paragraph::new())
+            .keep_together(1..3);
+        let keep_next: Vec<bool> = doc
+            .children
+            .iter()
+            .flat_map(|c| match c {
+                DocumentChild::Paragraph(p) => vec![p.property.keep_next],
+                DocumentChild::Table(t) => t
+                    .rows
+                    .iter()
+                    .flat_map(|r| &r.cells)
+                    .flat_map(|c| &c.children)
+                    .map(|c| match c {
+                        TableCellContent::Paragraph(p) => p.property.keep_next,
+                        _ => false,
+                    })
+                    .collect(),
+                _ => vec![],
+            })
+            .collect();
+        assert_eq!(keep_next, vec![false, true, true, false, false]);
+    }
 }
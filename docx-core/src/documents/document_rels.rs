@@ -2,6 +2,7 @@ use serde::Serialize;
 
 use super::*;
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -11,6 +12,39 @@ pub struct DocumentRels {
     pub has_numberings: bool,
     pub image_ids: Vec<usize>,
     pub custom_xml_count: usize,
+    pub has_web_settings: bool,
+    pub header_ids: Vec<usize>,
+    pub has_footer: bool,
+    pub footer_ids: Vec<usize>,
+    pub has_vba_project: bool,
+    pub has_theme: bool,
+    // Relationship id and part name of the altChunk parts.
+    pub alt_chunks: Vec<(String, String)>,
+    // Relationships read or added with Docx::add_relationship, i.e. hyperlink targets.
+    pub relationships: Vec<Relationship>,
+}
+
+// Types of the relationships to the parts written by the builder.
+const PART_TYPES: [&str; 14] = [
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/fontTable",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/settings",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/header",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments",
+    "http://schemas.microsoft.com/office/2011/relationships/commentsExtended",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/webSettings",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml",
+    "http://schemas.microsoft.com/office/2006/relationships/vbaProject",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/aFChunk",
+];
+
+// Whether the relationship is to a part which the builder writes with a relationship of its own.
+pub(crate) fn is_part_relationship(rel_type: &str) -> bool {
+    PART_TYPES.contains(&rel_type)
 }
 
 impl DocumentRels {
@@ -31,72 +65,167 @@ impl Default for DocumentRels {
             has_numberings: false,
             image_ids: vec![],
             custom_xml_count: 0,
+            has_web_settings: false,
+            header_ids: vec![],
+            has_footer: false,
+            footer_ids: vec![],
+            has_vba_project: false,
+            has_theme: false,
+            alt_chunks: vec![],
+            relationships: vec![],
         }
     }
 }
 
-impl BuildXML for DocumentRels {
-    fn build(&self) -> Vec<u8> {
-        let mut b = XMLBuilder::new();
-        b = b
-            .declaration(None)
-            .open_relationships("http://schemas.openxmlformats.org/package/2006/relationships")
-            .relationship(
+impl DocumentRels {
+    // The relationships of the parts written by the builder.
+    fn part_relationships(&self) -> Vec<Relationship> {
+        let mut rels = vec![
+            Relationship::new(
                 "rId1",
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles",
                 "styles.xml",
-            )
-            .relationship(
+            ),
+            Relationship::new(
                 "rId2",
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/fontTable",
                 "fontTable.xml",
-            )
-            .relationship(
+            ),
+            Relationship::new(
                 "rId3",
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/settings",
                 "settings.xml",
-            )
-            .relationship(
+            ),
+            Relationship::new(
                 "rId4",
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/header",
                 "header1.xml",
-            )
-            .relationship(
+            ),
+            Relationship::new(
                 "rId5",
                 "http://schemas.microsoft.com/office/2011/relationships/commentsExtended",
                 "commentsExtended.xml",
-            );
+            ),
+        ];
 
         if self.has_comments {
-            b = b.relationship(
+            rels.push(Relationship::new(
                 "rId6",
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments",
                 "comments.xml",
-            )
+            ));
         }
 
         if self.has_numberings {
-            b = b.relationship(
+            rels.push(Relationship::new(
                 "rId7",
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering",
                 "numbering.xml",
-            )
+            ));
+        }
+
+        if self.has_web_settings {
+            rels.push(Relationship::new(
+                "rIdWebSettings",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/webSettings",
+                "webSettings.xml",
+            ));
         }
 
         for i in 0..self.custom_xml_count {
-            b = b.relationship(
-                &format!("rId{}", i + 8),
+            rels.push(Relationship::new(
+                format!("rId{}", i + 8),
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml",
-                &format!("../customXml/item{}.xml", i + 1),
-            )
+                format!("../customXml/item{}.xml", i + 1),
+            ));
+        }
+
+        if self.has_footer {
+            rels.push(Relationship::new(
+                create_footer_rid(1),
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer",
+                "footer1.xml",
+            ));
+        }
+
+        if self.has_vba_project {
+            rels.push(Relationship::new(
+                "rIdVbaProject",
+                "http://schemas.microsoft.com/office/2006/relationships/vbaProject",
+                "vbaProject.bin",
+            ));
+        }
+
+        if self.has_theme {
+            rels.push(Relationship::new(
+                "rIdTheme",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme",
+                "theme/theme1.xml",
+            ));
+        }
+
+        for id in self.header_ids.iter() {
+            rels.push(Relationship::new(
+                create_header_rid(*id),
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/header",
+                format!("header{}.xml", *id),
+            ));
+        }
+
+        for id in self.footer_ids.iter() {
+            rels.push(Relationship::new(
+                create_footer_rid(*id),
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer",
+                format!("footer{}.xml", *id),
+            ));
         }
 
         for id in self.image_ids.iter() {
-            b = b.relationship(
-                &create_pic_rid(*id),
+            rels.push(Relationship::new(
+                create_pic_rid(*id),
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
-                &format!("media/image{}.jpg", *id),
-            )
+                format!("media/image{}.jpg", *id),
+            ));
+        }
+
+        for (rid, part_name) in self.alt_chunks.iter() {
+            rels.push(Relationship::new(
+                rid,
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/aFChunk",
+                part_name,
+            ));
+        }
+
+        rels
+    }
+}
+
+impl BuildXML for DocumentRels {
+    fn build(&self) -> Vec<u8> {
+        let parts = self.part_relationships();
+        let mut b = XMLBuilder::new();
+        b = b
+            .declaration(None)
+            .open_relationships("http://schemas.openxmlformats.org/package/2006/relationships");
+
+        for r in parts.iter() {
+            b = b.relationship(&r.id, &r.rel_type, &r.target);
+        }
+
+        // Relationships whose id is taken by a part are left out, as the ids must be unique.
+        for r in self
+            .relationships
+            .iter()
+            .filter(|r| parts.iter().all(|p| p.id != r.id))
+        {
+            // Targets are URLs or paths given by the user, i.e. with a query string.
+            let target = escape(&r.target);
+            // Word reports documents with external targets lacking TargetMode as corrupt.
+            b = if r.is_external() {
+                b.relationship_with_target_mode(&r.id, &r.rel_type, &target, "External")
+            } else {
+                b.relationship(&r.id, &r.rel_type, &target)
+            }
         }
 
         b.close().build()
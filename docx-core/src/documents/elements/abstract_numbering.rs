@@ -6,10 +6,10 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AbstractNumbering {
-    id: usize,
+    pub id: usize,
     style_link: Option<String>,
     num_style_link: Option<String>,
-    levels: Vec<Level>,
+    pub levels: Vec<Level>,
 }
 
 impl AbstractNumbering {
@@ -43,6 +43,12 @@ impl BuildXML for AbstractNumbering {
         let id = format!("{}", self.id);
         let mut b = XMLBuilder::new();
         b = b.open_abstract_num(&id);
+        if let Some(ref link) = self.style_link {
+            b = b.style_link(link);
+        }
+        if let Some(ref link) = self.num_style_link {
+            b = b.num_style_link(link);
+        }
         for l in &self.levels {
             b = b.add_child(l);
         }
@@ -76,6 +82,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_numbering_with_style_link() {
+        let c = AbstractNumbering::new(0).style_link("ListStyle");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:abstractNum w:abstractNumId="0">
+  <w:styleLink w:val="ListStyle" />
+</w:abstractNum>"#
+        );
+    }
+
+    #[test]
+    fn test_numbering_with_num_style_link() {
+        let c = AbstractNumbering::new(1).num_style_link("ListStyle");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:abstractNum w:abstractNumId="1">
+  <w:numStyleLink w:val="ListStyle" />
+</w:abstractNum>"#
+        );
+    }
+
     #[test]
     fn test_numbering_json() {
         let mut c = AbstractNumbering::new(0);
@@ -90,7 +120,7 @@ mod tests {
             .num_style_link("style1");
         assert_eq!(
             serde_json::to_string(&c).unwrap(),
-            r#"{"id":0,"styleLink":null,"numStyleLink":"style1","levels":[{"level":1,"start":1,"format":"decimal","text":"%4.","jc":"left","paragraphProperty":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"characterSpacing":null,"fonts":null,"textBorder":null,"del":null,"ins":null},"style":null,"numberingProperty":null,"alignment":null,"indent":null,"lineSpacing":null,"keepNext":false,"keepLines":false,"pageBreakBefore":false,"windowControl":false,"outlineLvl":null,"divId":null},"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"characterSpacing":null,"fonts":null,"textBorder":null,"del":null,"ins":null},"suffix":"tab","pstyle":null,"levelRestart":null}]}"#,
+            r#"{"id":0,"styleLink":null,"numStyleLink":"style1","levels":[{"level":1,"start":1,"format":"decimal","text":"%4.","jc":"left","paragraphProperty":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"specVanish":null,"characterSpacing":null,"position":null,"fonts":null,"textBorder":null,"shading":null,"del":null,"ins":null,"lang":null,"propertyChange":null},"style":null,"numberingProperty":null,"alignment":null,"indent":null,"lineSpacing":null,"contextualSpacing":null,"keepNext":false,"keepLines":false,"pageBreakBefore":false,"windowControl":false,"outlineLvl":null,"tabs":[],"shading":null,"divId":null,"sectionProperty":null},"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"specVanish":null,"characterSpacing":null,"position":null,"fonts":null,"textBorder":null,"shading":null,"del":null,"ins":null,"lang":null,"propertyChange":null},"suffix":"tab","pstyle":null,"levelRestart":null,"pictureBulletId":null}]}"#,
         );
     }
 }
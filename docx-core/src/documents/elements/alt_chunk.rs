@@ -0,0 +1,69 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// An HTML, RTF or other foreign fragment imported by Word when the document is opened.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AltChunk {
+    // Relationship id of the part. It is assigned when the document is built.
+    pub id: String,
+    pub chunk_type: AltChunkType,
+    #[serde(skip)]
+    pub data: Vec<u8>,
+}
+
+impl AltChunk {
+    pub fn new(chunk_type: AltChunkType, data: impl Into<Vec<u8>>) -> AltChunk {
+        AltChunk {
+            id: String::new(),
+            chunk_type,
+            data: data.into(),
+        }
+    }
+
+    pub fn html(html: impl Into<String>) -> AltChunk {
+        Self::new(AltChunkType::Html, html.into())
+    }
+
+    pub fn rtf(rtf: impl Into<String>) -> AltChunk {
+        Self::new(AltChunkType::Rtf, rtf.into())
+    }
+
+    pub(crate) fn part_name(&self, id: usize) -> String {
+        format!("afchunk{}.{}", id, self.chunk_type.extension())
+    }
+}
+
+pub fn create_alt_chunk_rid(id: usize) -> String {
+    format!("rIdAltChunk{}", id)
+}
+
+impl BuildXML for AltChunk {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new().alt_chunk(&self.id).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_alt_chunk() {
+        let mut c = AltChunk::html("<p>Hello</p>");
+        c.id = create_alt_chunk_rid(1);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:altChunk r:id="rIdAltChunk1" />"#
+        );
+        assert_eq!(c.part_name(1), "afchunk1.html");
+    }
+}
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::documents::BuildXML;
+use crate::documents::{reserve_bookmark_id, BuildXML};
 use crate::xml_builder::*;
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
@@ -10,7 +10,11 @@ pub struct BookmarkStart {
 }
 
 impl BookmarkStart {
+    // Pass as id to let Paragraph::add_bookmark pick an unused one.
+    pub const AUTO_ID: usize = usize::MAX;
+
     pub fn new(id: usize, name: impl Into<String>) -> BookmarkStart {
+        reserve_bookmark_id(id);
         BookmarkStart {
             id,
             name: name.into(),
@@ -7,19 +7,34 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct Break {
-    break_type: BreakType,
+    pub(crate) break_type: BreakType,
+    // Where the next line restarts after floating objects, for text wrapping breaks.
+    pub(crate) clear: Option<BreakClear>,
 }
 
 impl Break {
     pub fn new(t: BreakType) -> Break {
-        Break { break_type: t }
+        Break {
+            break_type: t,
+            clear: None,
+        }
+    }
+
+    pub fn clear(mut self, c: BreakClear) -> Break {
+        self.clear = Some(c);
+        self
     }
 }
 
 impl BuildXML for Break {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        b.br(&self.break_type.to_string()).build()
+        match self.clear {
+            Some(c) => b
+                .br_with_clear(&self.break_type.to_string(), &c.to_string())
+                .build(),
+            None => b.br(&self.break_type.to_string()).build(),
+        }
     }
 }
 
@@ -28,8 +43,9 @@ impl Serialize for Break {
     where
         S: Serializer,
     {
-        let mut t = serializer.serialize_struct("Break", 1)?;
+        let mut t = serializer.serialize_struct("Break", 2)?;
         t.serialize_field("breakType", &format!("{}", &self.break_type))?;
+        t.serialize_field("clear", &self.clear)?;
         t.end()
     }
 }
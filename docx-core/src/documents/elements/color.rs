@@ -1,22 +1,133 @@
-use serde::{Deserialize, Serialize, Serializer};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
 
 use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
-#[derive(Deserialize, Debug, Clone, PartialEq)]
-pub struct Color {
-    val: String,
+// A color is an explicit hex value, `auto`, which lets the consumer pick black or white
+// against the background, or a color of the document theme modified by tint or shade.
+// A theme color keeps the hex value which consumers without theme support fall back to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Color {
+    Auto,
+    Hex(String),
+    Theme {
+        theme_color: String,
+        tint: Option<u8>,
+        shade: Option<u8>,
+        fallback: Option<String>,
+    },
 }
 
 impl Color {
     pub fn new(val: impl Into<String>) -> Color {
-        Color { val: val.into() }
+        Color::from(val.into())
+    }
+
+    pub fn auto() -> Color {
+        Color::Auto
+    }
+
+    pub fn hex(val: impl Into<String>) -> Color {
+        Color::Hex(val.into())
+    }
+
+    // i.e. accent1, text1 or hyperlink. Please see ST_ThemeColor
+    pub fn theme(theme_color: impl Into<String>) -> Color {
+        Color::Theme {
+            theme_color: theme_color.into(),
+            tint: None,
+            shade: None,
+            fallback: None,
+        }
+    }
+
+    // Lightens a theme color, 0 is white and 255 is the theme color itself.
+    pub fn tint(mut self, v: u8) -> Color {
+        if let Color::Theme { ref mut tint, .. } = self {
+            *tint = Some(v);
+        }
+        self
+    }
+
+    // Darkens a theme color, 0 is black and 255 is the theme color itself.
+    pub fn shade(mut self, v: u8) -> Color {
+        if let Color::Theme { ref mut shade, .. } = self {
+            *shade = Some(v);
+        }
+        self
+    }
+
+    // The hex value written along with a theme color, i.e. the theme color as resolved by Word.
+    pub fn fallback(mut self, v: impl Into<String>) -> Color {
+        if let Color::Theme {
+            ref mut fallback, ..
+        } = self
+        {
+            *fallback = Some(v.into());
+        }
+        self
+    }
+
+    // The hex value or `auto`. Theme colors resolve to their fallback, or `auto` without one.
+    pub fn val(&self) -> &str {
+        match self {
+            Color::Hex(v) => v,
+            Color::Theme {
+                fallback: Some(v), ..
+            } => v,
+            _ => "auto",
+        }
+    }
+
+    // Attributes of the color, i.e. ("w:color", "w:themeColor", "w:themeTint", "w:themeShade").
+    pub(crate) fn attrs(&self, names: (&str, &str, &str, &str)) -> Vec<(String, String)> {
+        let mut attrs = vec![(names.0.to_owned(), self.val().to_owned())];
+        if let Color::Theme {
+            theme_color,
+            tint,
+            shade,
+            ..
+        } = self
+        {
+            attrs.push((names.1.to_owned(), theme_color.clone()));
+            if let Some(tint) = tint {
+                attrs.push((names.2.to_owned(), format!("{:02X}", tint)));
+            }
+            if let Some(shade) = shade {
+                attrs.push((names.3.to_owned(), format!("{:02X}", shade)));
+            }
+        }
+        attrs
+    }
+}
+
+impl From<&str> for Color {
+    fn from(val: &str) -> Self {
+        if val == "auto" {
+            Color::Auto
+        } else {
+            Color::Hex(val.to_owned())
+        }
+    }
+}
+
+impl From<String> for Color {
+    fn from(val: String) -> Self {
+        Color::from(val.as_str())
+    }
+}
+
+impl From<&String> for Color {
+    fn from(val: &String) -> Self {
+        Color::from(val.as_str())
     }
 }
 
 impl BuildXML for Color {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new().color(&self.val).build()
+        let attrs = self.attrs(("w:val", "w:themeColor", "w:themeTint", "w:themeShade"));
+        XMLBuilder::new().color(&attrs).build()
     }
 }
 
@@ -25,7 +136,22 @@ impl Serialize for Color {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.val)
+        match self {
+            Color::Theme {
+                theme_color,
+                tint,
+                shade,
+                fallback,
+            } => {
+                let mut t = serializer.serialize_struct("Color", 4)?;
+                t.serialize_field("themeColor", theme_color)?;
+                t.serialize_field("tint", tint)?;
+                t.serialize_field("shade", shade)?;
+                t.serialize_field("fallback", fallback)?;
+                t.end()
+            }
+            _ => serializer.serialize_str(self.val()),
+        }
     }
 }
 
@@ -43,4 +169,36 @@ mod tests {
         let b = c.build();
         assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:color w:val="FFFFFF" />"#);
     }
+
+    #[test]
+    fn test_build_auto() {
+        let c = Color::from("auto");
+        assert_eq!(c, Color::Auto);
+        let b = c.build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:color w:val="auto" />"#);
+    }
+
+    #[test]
+    fn test_build_theme() {
+        let c = Color::theme("accent1").tint(153);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:color w:val="auto" w:themeColor="accent1" w:themeTint="99" />"#
+        );
+        assert_eq!(
+            serde_json::to_string(&c).unwrap(),
+            r#"{"themeColor":"accent1","tint":153,"shade":null,"fallback":null}"#
+        );
+    }
+
+    #[test]
+    fn test_build_theme_with_fallback() {
+        let c = Color::theme("accent1").fallback("4472C4");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:color w:val="4472C4" w:themeColor="accent1" />"#
+        );
+    }
 }
@@ -9,12 +9,14 @@ use crate::xml_builder::*;
 pub struct Comment {
     pub id: usize,
     pub author: String,
+    pub initials: String,
     pub date: String,
     pub children: Vec<CommentChild>,
     pub parent_comment_id: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum CommentChild {
     Paragraph(Paragraph),
     Table(Table),
@@ -47,6 +49,7 @@ impl Default for Comment {
         Comment {
             id: 1,
             author: "unnamed".to_owned(),
+            initials: "".to_owned(),
             date: "1970-01-01T00:00:00Z".to_owned(),
             children: vec![],
             parent_comment_id: None,
@@ -67,11 +70,21 @@ impl Comment {
         self
     }
 
+    pub fn initials(mut self, initials: impl Into<String>) -> Comment {
+        self.initials = initials.into();
+        self
+    }
+
     pub fn date(mut self, date: impl Into<String>) -> Comment {
         self.date = date.into();
         self
     }
 
+    // Set the date from seconds since the unix epoch, e.g. "2021-03-04T05:06:07Z".
+    pub fn date_from_timestamp(self, secs: u64) -> Comment {
+        self.date(format_date(secs))
+    }
+
     pub fn add_paragraph(mut self, p: Paragraph) -> Self {
         self.children.push(CommentChild::Paragraph(p));
         self
@@ -104,13 +117,43 @@ impl BuildXML for CommentChild {
 impl BuildXML for Comment {
     fn build(&self) -> Vec<u8> {
         XMLBuilder::new()
-            .open_comment(&format!("{}", self.id), &self.author, &self.date, "")
+            .open_comment(
+                &format!("{}", self.id),
+                &self.author,
+                &self.date,
+                &self.initials,
+            )
             .add_children(&self.children)
             .close()
             .build()
     }
 }
 
+// ISO 8601 in UTC as Word writes it, without fractional seconds.
+fn format_date(secs: u64) -> String {
+    let days = secs / 86400;
+    let rest = secs % 86400;
+    // Civil date from the days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rest / 3600,
+        rest % 3600 / 60,
+        rest % 60
+    )
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -136,4 +179,24 @@ mod tests {
             r#"<w:comment w:id="1" w:author="unnamed" w:date="1970-01-01T00:00:00Z" w:initials=""><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:comment>"#
         );
     }
+
+    #[test]
+    fn test_comment_initials_and_date() {
+        let b = Comment::new(1)
+            .author("Jane Doe")
+            .initials("JD")
+            .date_from_timestamp(1_614_834_367)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:comment w:id="1" w:author="Jane Doe" w:date="2021-03-04T05:06:07Z" w:initials="JD" />"#
+        );
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_date(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_date(1_709_251_199), "2024-02-29T23:59:59Z");
+    }
 }
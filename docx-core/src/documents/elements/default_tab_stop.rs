@@ -5,7 +5,7 @@ use serde::{Serialize, Serializer};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DefaultTabStop {
-    val: usize,
+    pub(crate) val: usize,
 }
 
 impl DefaultTabStop {
@@ -13,7 +13,7 @@ pub struct Delete {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum DeleteChild {
-    Run(Run),
+    Run(Box<Run>),
     CommentStart(Box<CommentRangeStart>),
     CommentEnd(CommentRangeEnd),
 }
@@ -65,7 +65,7 @@ impl Delete {
     }
 
     pub fn add_run(mut self, run: Run) -> Delete {
-        self.children.push(DeleteChild::Run(run));
+        self.children.push(DeleteChild::Run(Box::new(run)));
         self
     }
 
@@ -101,7 +101,7 @@ impl BuildXML for Delete {
         let mut b = XMLBuilder::new().open_delete(&self.generate(), &self.author, &self.date);
         for c in &self.children {
             match c {
-                DeleteChild::Run(t) => b = b.add_child(t),
+                DeleteChild::Run(t) => b = b.add_child(t.as_ref()),
                 DeleteChild::CommentStart(c) => b = b.add_child(c),
                 DeleteChild::CommentEnd(c) => b = b.add_child(c),
             }
@@ -1,13 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, Text};
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DeleteText {
-    text: String,
-    preserve_space: bool,
+    pub(crate) text: String,
+    pub(crate) preserve_space: bool,
 }
 
 impl DeleteText {
@@ -19,6 +19,25 @@ impl DeleteText {
     }
 }
 
+// Both texts are written as is, so the content is carried over unchanged.
+impl From<Text> for DeleteText {
+    fn from(t: Text) -> DeleteText {
+        DeleteText {
+            text: t.text,
+            preserve_space: t.preserve_space,
+        }
+    }
+}
+
+impl From<DeleteText> for Text {
+    fn from(t: DeleteText) -> Text {
+        Text {
+            text: t.text,
+            preserve_space: t.preserve_space,
+        }
+    }
+}
+
 impl BuildXML for DeleteText {
     fn build(&self) -> Vec<u8> {
         XMLBuilder::new().delete_text(&self.text, true).build()
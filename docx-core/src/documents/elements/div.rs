@@ -1,5 +1,8 @@
 use serde::Serialize;
 
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Div {
@@ -57,3 +60,18 @@ impl Div {
         self
     }
 }
+
+impl BuildXML for Div {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new()
+            .open_div(&self.id)
+            .div_margin_left(self.margin_left)
+            .div_margin_right(self.margin_right)
+            .div_margin_top(self.margin_top)
+            .div_margin_bottom(self.margin_bottom);
+        if !self.divs_child.is_empty() {
+            b = b.open_divs_child().add_children(&self.divs_child).close();
+        }
+        b.close().build()
+    }
+}
@@ -1,15 +1,18 @@
 use serde::Serialize;
 
 use crate::xml_builder::*;
-use crate::{documents::BuildXML, RunProperty};
+use crate::{documents::BuildXML, ParagraphProperty, RunProperty, Twip};
 
+use super::paragraph_property_default::*;
 use super::run_property_default::*;
-use super::RunFonts;
+use super::{LangSettings, LineSpacing, RunFonts};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DocDefaults {
     run_property_default: RunPropertyDefault,
+    // Written only when set.
+    paragraph_property_default: Option<ParagraphPropertyDefault>,
 }
 
 impl DocDefaults {
@@ -22,7 +25,7 @@ impl DocDefaults {
         self
     }
 
-    pub fn spacing(mut self, spacing: i32) -> Self {
+    pub fn spacing(mut self, spacing: impl Into<Twip>) -> Self {
         self.run_property_default = self.run_property_default.spacing(spacing);
         self
     }
@@ -32,10 +35,43 @@ impl DocDefaults {
         self
     }
 
+    pub fn lang(mut self, l: LangSettings) -> Self {
+        self.run_property_default = self.run_property_default.lang(l);
+        self
+    }
+
     pub(crate) fn run_property(mut self, p: RunProperty) -> Self {
         self.run_property_default = self.run_property_default.run_property(p);
         self
     }
+
+    pub fn get_run_property(&self) -> &RunProperty {
+        self.run_property_default.get_run_property()
+    }
+
+    pub fn line_spacing(mut self, s: LineSpacing) -> Self {
+        self.paragraph_property_default = Some(
+            self.paragraph_property_default
+                .unwrap_or_default()
+                .line_spacing(s),
+        );
+        self
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.paragraph_property_default = Some(
+            self.paragraph_property_default
+                .unwrap_or_default()
+                .contextual_spacing(v),
+        );
+        self
+    }
+
+    pub fn get_paragraph_property(&self) -> Option<&ParagraphProperty> {
+        self.paragraph_property_default
+            .as_ref()
+            .map(|d| d.get_paragraph_property())
+    }
 }
 
 impl Default for DocDefaults {
@@ -43,6 +79,7 @@ impl Default for DocDefaults {
         let run_property_default = RunPropertyDefault::new();
         DocDefaults {
             run_property_default,
+            paragraph_property_default: None,
         }
     }
 }
@@ -52,6 +89,7 @@ impl BuildXML for DocDefaults {
         let b = XMLBuilder::new();
         b.open_doc_defaults()
             .add_child(&self.run_property_default)
+            .add_optional_child(&self.paragraph_property_default)
             .close()
             .build()
     }
@@ -5,7 +5,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct DocId {
-    id: String,
+    pub(crate) id: String,
 }
 
 impl DocId {
@@ -1,12 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DocVar {
-    name: String,
-    val: String,
+    pub(crate) name: String,
+    pub(crate) val: String,
 }
 
 impl DocVar {
@@ -21,6 +22,6 @@ impl DocVar {
 impl BuildXML for DocVar {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        b.doc_var(&self.name, &self.val).build()
+        b.doc_var(&escape(&self.name), &escape(&self.val)).build()
     }
 }
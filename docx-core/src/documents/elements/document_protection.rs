@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// Restricts the editing of the document. Passwords are not supported.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentProtection {
+    pub edit: DocumentProtectionType,
+    pub enforcement: bool,
+}
+
+impl DocumentProtection {
+    pub fn new(edit: DocumentProtectionType) -> DocumentProtection {
+        DocumentProtection {
+            edit,
+            enforcement: true,
+        }
+    }
+
+    pub fn enforcement(mut self, v: bool) -> DocumentProtection {
+        self.enforcement = v;
+        self
+    }
+}
+
+impl BuildXML for DocumentProtection {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        let enforcement = if self.enforcement { "1" } else { "0" };
+        b.document_protection(&self.edit.to_string(), enforcement)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_document_protection() {
+        let c = DocumentProtection::new(DocumentProtectionType::ReadOnly);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:documentProtection w:edit="readOnly" w:enforcement="1" />"#
+        );
+    }
+}
@@ -18,6 +18,8 @@ pub struct Drawing {
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub enum DrawingData {
     Pic(Pic),
+    TextBox(TextBox),
+    Shape(Shape),
 }
 
 // TODO: Old definition, remove later
@@ -69,6 +71,18 @@ impl Drawing {
         self
     }
 
+    pub fn text_box(mut self, t: TextBox) -> Drawing {
+        self.position_h = t.position_h;
+        self.position_v = t.position_v;
+        self.data = Some(DrawingData::TextBox(t));
+        self.floating()
+    }
+
+    pub fn shape(mut self, s: Shape) -> Drawing {
+        self.data = Some(DrawingData::Shape(s));
+        self
+    }
+
     pub fn floating(mut self) -> Drawing {
         self.position_type = DrawingPositionType::Anchor;
         self
@@ -110,8 +124,23 @@ impl BuildXML for Box<Drawing> {
         if let DrawingPositionType::Inline { .. } = self.position_type {
             b = b.open_wp_inline("0", "0", "0", "0")
         } else {
+            let (t, bottom, l, r) = match &self.data {
+                Some(DrawingData::Pic(p)) => p.wrap_distance,
+                _ => (0, 0, 0, 0),
+            };
             b = b
-                .open_wp_anchor("0", "0", "0", "0", "0", "1", "0", "0", "1", "1905000")
+                .open_wp_anchor(
+                    &format!("{}", t),
+                    &format!("{}", bottom),
+                    &format!("{}", l),
+                    &format!("{}", r),
+                    "0",
+                    "1",
+                    "0",
+                    "0",
+                    "1",
+                    "1905000",
+                )
                 .simple_pos("0", "0")
                 .open_position_h("page");
             if let DrawingPosition::Offset(x) = self.position_h {
@@ -128,14 +157,22 @@ impl BuildXML for Box<Drawing> {
         }
         match &self.data {
             Some(DrawingData::Pic(p)) => {
-                let w = format!("{}", crate::types::emu::from_px(p.size.0));
-                let h = format!("{}", crate::types::emu::from_px(p.size.1));
+                let w = format!("{}", p.size.0);
+                let h = format!("{}", p.size.1);
                 b = b
                     // Please see 20.4.2.7 extent (Drawing Object Size)
                     // One inch equates to 914400 EMUs and a centimeter is 360000
                     .wp_extent(&w, &h)
-                    .wp_effect_extent("0", "0", "0", "0")
-                    .wrap_none()
+                    .wp_effect_extent("0", "0", "0", "0");
+                // Inline pictures have no wrapping. Text keeps the wrap distance only when it wraps.
+                if let DrawingPositionType::Anchor = self.position_type {
+                    b = if p.wrap_distance == (0, 0, 0, 0) {
+                        b.wrap_none()
+                    } else {
+                        b.wrap_square("bothSides")
+                    };
+                }
+                b = b
                     .wp_doc_pr("1", "Figure")
                     .open_wp_c_nv_graphic_frame_pr()
                     .a_graphic_frame_locks(
@@ -149,6 +186,41 @@ impl BuildXML for Box<Drawing> {
                     .close()
                     .close();
             }
+            Some(DrawingData::TextBox(t)) => {
+                let w = format!("{}", crate::types::emu::from_px(t.size.0));
+                let h = format!("{}", crate::types::emu::from_px(t.size.1));
+                b = b
+                    .wp_extent(&w, &h)
+                    .wp_effect_extent("0", "0", "0", "0")
+                    .wrap_none()
+                    .wp_doc_pr(&format!("{}", t.id), "TextBox")
+                    .open_wp_c_nv_graphic_frame_pr()
+                    .close()
+                    .open_a_graphic("http://schemas.openxmlformats.org/drawingml/2006/main")
+                    .open_a_graphic_data(
+                        "http://schemas.microsoft.com/office/word/2010/wordprocessingShape",
+                    )
+                    .add_child(t)
+                    .close()
+                    .close();
+            }
+            Some(DrawingData::Shape(s)) => {
+                let w = format!("{}", crate::types::emu::from_px(s.size.0));
+                let h = format!("{}", crate::types::emu::from_px(s.size.1));
+                b = b
+                    .wp_extent(&w, &h)
+                    .wp_effect_extent("0", "0", "0", "0")
+                    .wp_doc_pr(&format!("{}", s.id), "Shape")
+                    .open_wp_c_nv_graphic_frame_pr()
+                    .close()
+                    .open_a_graphic("http://schemas.openxmlformats.org/drawingml/2006/main")
+                    .open_a_graphic_data(
+                        "http://schemas.microsoft.com/office/word/2010/wordprocessingShape",
+                    )
+                    .add_child(s)
+                    .close()
+                    .close();
+            }
             None => {}
         }
         b.close().close().build()
@@ -177,7 +249,6 @@ mod tests {
   <wp:inline distT="0" distB="0" distL="0" distR="0">
     <wp:extent cx="3048000" cy="2286000" />
     <wp:effectExtent b="0" l="0" r="0" t="0" />
-    <wp:wrapNone />
     <wp:docPr id="1" name="Figure" />
     <wp:cNvGraphicFramePr>
       <a:graphicFrameLocks xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" noChangeAspect="1" />
@@ -212,4 +283,24 @@ mod tests {
 </w:drawing>"#
         );
     }
+
+    #[test]
+    fn test_drawing_build_with_floating_pic_wrap_distance() {
+        use crate::types::Emu;
+        use std::io::Read;
+
+        let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+        let mut buf = Vec::new();
+        let _ = img.read_to_end(&mut buf).unwrap();
+        let pic = Pic::new(buf).floating().wrap_distance(
+            Emu::from_px(10),
+            Emu::from_px(10),
+            Emu::from_px(20),
+            Emu::from_pt(15.0),
+        );
+        let d = Box::new(Drawing::new().pic(pic).floating()).build();
+        let d = str::from_utf8(&d).unwrap();
+        assert!(d.contains(r#"<wp:anchor distT="95250" distB="95250" distL="190500" distR="190500""#));
+        assert!(d.contains(r#"<wp:wrapSquare wrapText="bothSides" />"#));
+    }
 }
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+/*
+  17.16.18 fldChar (Complex Field Character)
+  Marks the begin, the separator between instruction and result, and the end of a complex field.
+  A dirty field is recalculated by the consumer when the document is opened.
+*/
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChar {
+    pub field_char_type: FieldCharType,
+    pub dirty: bool,
+}
+
+impl FieldChar {
+    pub fn new(t: FieldCharType) -> FieldChar {
+        FieldChar {
+            field_char_type: t,
+            dirty: false,
+        }
+    }
+
+    pub fn dirty(mut self) -> FieldChar {
+        self.dirty = true;
+        self
+    }
+}
+
+impl BuildXML for FieldChar {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        let t = self.field_char_type.to_string();
+        if self.dirty {
+            b.field_character_with_dirty(&t, "true").build()
+        } else {
+            b.field_character(&t).build()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_field_char() {
+        let b = FieldChar::new(FieldCharType::Begin).dirty().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:fldChar w:fldCharType="begin" w:dirty="true" />"#
+        );
+    }
+}
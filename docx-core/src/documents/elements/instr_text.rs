@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::xml_builder::*;
+
+// The instruction is kept unescaped so that field codes and their switches can be inspected.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstrText {
+    pub(crate) instr: String,
+}
+
+impl InstrText {
+    pub fn new(instr: impl Into<String>) -> InstrText {
+        InstrText {
+            instr: instr.into(),
+        }
+    }
+
+    pub fn instr(&self) -> &str {
+        &self.instr
+    }
+
+    // i.e. "XE" for ` XE "term" \b `
+    pub fn field_type(&self) -> Option<String> {
+        self.tokens().into_iter().next().map(|t| t.to_uppercase())
+    }
+
+    // The first argument following the field type, without quotes.
+    pub fn argument(&self) -> Option<String> {
+        self.tokens()
+            .into_iter()
+            .nth(1)
+            .filter(|t| !t.starts_with('\\'))
+    }
+
+    // The value following a switch, e.g. `switch("\\@")` for a date picture.
+    pub fn switch(&self, name: &str) -> Option<String> {
+        let tokens = self.tokens();
+        let i = tokens.iter().position(|t| t == name)?;
+        tokens.get(i + 1).cloned()
+    }
+
+    pub fn has_switch(&self, name: &str) -> bool {
+        self.tokens().iter().any(|t| t == name)
+    }
+
+    // The picture of a DATE or TIME field, i.e. "MMMM d, yyyy".
+    pub fn date_format(&self) -> Option<String> {
+        match self.field_type().as_deref() {
+            Some("DATE") | Some("TIME") => self.switch("\\@"),
+            _ => None,
+        }
+    }
+
+    // Splits the instruction on whitespace, keeping quoted parts together.
+    // A quote inside a quoted part is written as \".
+    fn tokens(&self) -> Vec<String> {
+        let mut tokens = vec![];
+        let mut current = String::new();
+        let mut quoted = false;
+        let mut chars = self.instr.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' if quoted && chars.peek() == Some(&'"') => {
+                    current.push('"');
+                    chars.next();
+                }
+                '"' => {
+                    if quoted {
+                        tokens.push(std::mem::take(&mut current));
+                    }
+                    quoted = !quoted;
+                }
+                c if c.is_whitespace() && !quoted => {
+                    if !current.is_empty() {
+                        tokens.push(std::mem::take(&mut current));
+                    }
+                }
+                c => current.push(c),
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+        tokens
+    }
+}
+
+// Quotes a field argument, escaping embedded quotes.
+pub(crate) fn quote_field_argument(v: &str) -> String {
+    format!("\"{}\"", v.replace('"', "\\\""))
+}
+
+impl BuildXML for InstrText {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new().instr_text(&escape(&self.instr)).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_instr_text() {
+        let b = InstrText::new(r#" DOCVARIABLE "Client" "#).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:instrText xml:space="preserve"> DOCVARIABLE &quot;Client&quot; </w:instrText>"#
+        );
+    }
+
+    #[test]
+    fn test_instr_text_parse() {
+        let i = InstrText::new(r#" xe "Rust:\"Ownership\"" \b \t "See Borrowing" "#);
+        assert_eq!(i.field_type(), Some("XE".to_owned()));
+        assert_eq!(i.argument(), Some(r#"Rust:"Ownership""#.to_owned()));
+        assert_eq!(i.switch("\\t"), Some("See Borrowing".to_owned()));
+        assert!(i.has_switch("\\b"));
+        assert!(!i.has_switch("\\i"));
+    }
+
+    #[test]
+    fn test_instr_text_date_format() {
+        let i = InstrText::new(r#" DATE \@ "d 'de' MMMM 'de' yyyy" "#);
+        assert_eq!(i.date_format(), Some("d 'de' MMMM 'de' yyyy".to_owned()));
+        assert_eq!(InstrText::new(r#" SEQ "Figure" "#).date_format(), None);
+    }
+}
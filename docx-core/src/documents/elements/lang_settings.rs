@@ -0,0 +1,74 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// The languages of latin, East Asian and complex script text, i.e. en-GB, ja-JP and ar-SA.
+// Written as w:lang for proofing, and as w:themeFontLang in the settings to pick the theme fonts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LangSettings {
+    pub val: Option<String>,
+    pub east_asia: Option<String>,
+    pub bidi: Option<String>,
+}
+
+impl LangSettings {
+    pub fn new() -> LangSettings {
+        Default::default()
+    }
+
+    pub fn val(mut self, lang: impl Into<String>) -> Self {
+        self.val = Some(lang.into());
+        self
+    }
+
+    pub fn east_asia(mut self, lang: impl Into<String>) -> Self {
+        self.east_asia = Some(lang.into());
+        self
+    }
+
+    pub fn bidi(mut self, lang: impl Into<String>) -> Self {
+        self.bidi = Some(lang.into());
+        self
+    }
+
+    pub(crate) fn attrs(&self) -> Vec<(&str, &str)> {
+        let mut attrs = vec![];
+        if let Some(v) = &self.val {
+            attrs.push(("w:val", v.as_str()));
+        }
+        if let Some(v) = &self.east_asia {
+            attrs.push(("w:eastAsia", v.as_str()));
+        }
+        if let Some(v) = &self.bidi {
+            attrs.push(("w:bidi", v.as_str()));
+        }
+        attrs
+    }
+}
+
+impl BuildXML for LangSettings {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new().lang(&self.attrs()).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_lang_settings() {
+        let c = LangSettings::new().val("en-GB").bidi("ar-SA");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:lang w:val="en-GB" w:bidi="ar-SA" />"#
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Marks where Word broke the page when it last laid out the document.
+// It does not break the page, it is only a hint for consumers without a layout.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct LastRenderedPageBreak {}
+
+impl LastRenderedPageBreak {
+    pub fn new() -> LastRenderedPageBreak {
+        Default::default()
+    }
+}
+
+impl BuildXML for LastRenderedPageBreak {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.last_rendered_page_break().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let b = LastRenderedPageBreak::new().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:lastRenderedPageBreak />"#
+        );
+    }
+}
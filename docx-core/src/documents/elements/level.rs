@@ -17,6 +17,7 @@ pub struct Level {
     pub suffix: LevelSuffixType,
     pub pstyle: Option<String>,
     pub level_restart: Option<LevelRestart>,
+    pub picture_bullet_id: Option<usize>,
 }
 
 impl Level {
@@ -38,14 +39,15 @@ impl Level {
             suffix: LevelSuffixType::Tab,
             pstyle: None,
             level_restart: None,
+            picture_bullet_id: None,
         }
     }
 
     pub fn indent(
         mut self,
-        left: Option<i32>,
+        left: Option<Twip>,
         special_indent: Option<SpecialIndentType>,
-        end: Option<i32>,
+        end: Option<Twip>,
         start_chars: Option<i32>,
     ) -> Self {
         self.paragraph_property =
@@ -59,23 +61,35 @@ impl Level {
         self
     }
 
+    // Use the picture registered by Docx::add_numbering_picture_bullet as the bullet.
+    pub fn picture_bullet(mut self, id: usize) -> Self {
+        self.picture_bullet_id = Some(id);
+        self
+    }
+
     pub fn suffix(mut self, s: LevelSuffixType) -> Self {
         self.suffix = s;
         self
     }
 
+    // The tab stop the number jumps to when the suffix is a tab.
+    pub fn add_tab(mut self, t: TabStop) -> Self {
+        self.paragraph_property = self.paragraph_property.add_tab(t);
+        self
+    }
+
     // run property
     pub fn size(mut self, size: usize) -> Self {
         self.run_property = self.run_property.size(size);
         self
     }
 
-    pub fn spacing(mut self, v: i32) -> Self {
+    pub fn spacing(mut self, v: impl Into<Twip>) -> Self {
         self.run_property = self.run_property.spacing(v);
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Self {
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
         self.run_property = self.run_property.color(color);
         self
     }
@@ -122,16 +136,23 @@ impl BuildXML for Level {
             .open_level(&format!("{}", self.level))
             .add_child(&self.start)
             .add_child(&self.format)
-            .add_child(&self.text)
-            .add_child(&self.jc)
-            .add_child(&self.paragraph_property)
-            .add_child(&self.run_property)
             .add_optional_child(&self.level_restart);
 
         if self.suffix != LevelSuffixType::Tab {
             b = b.suffix(&self.suffix.to_string());
         }
 
+        b = b.add_child(&self.text);
+
+        if let Some(id) = self.picture_bullet_id {
+            b = b.level_picture_bullet_id(id);
+        }
+
+        b = b
+            .add_child(&self.jc)
+            .add_child(&self.paragraph_property)
+            .add_child(&self.run_property);
+
         b.close().build()
     }
 }
@@ -169,7 +190,12 @@ mod tests {
             LevelText::new("%4."),
             LevelJc::new("left"),
         )
-        .indent(Some(320), Some(SpecialIndentType::Hanging(200)), None, None)
+        .indent(
+            Some(Twip(320)),
+            Some(SpecialIndentType::Hanging(200)),
+            None,
+            None,
+        )
         .build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
@@ -189,8 +215,42 @@ mod tests {
         .build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
-            r#"<w:lvl w:ilvl="1"><w:start w:val="1" /><w:numFmt w:val="decimal" /><w:lvlText w:val="%4." /><w:lvlJc w:val="left" /><w:pPr><w:rPr /></w:pPr><w:rPr /><w:suff w:val="space" />
-</w:lvl>"#
+            r#"<w:lvl w:ilvl="1"><w:start w:val="1" /><w:numFmt w:val="decimal" /><w:suff w:val="space" /><w:lvlText w:val="%4." /><w:lvlJc w:val="left" /><w:pPr><w:rPr /></w:pPr><w:rPr /></w:lvl>"#
+        );
+    }
+
+    #[test]
+    fn test_level_with_picture_bullet() {
+        let b = Level::new(
+            0,
+            Start::new(1),
+            NumberFormat::new("bullet"),
+            LevelText::new(""),
+            LevelJc::new("left"),
+        )
+        .picture_bullet(0)
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:lvl w:ilvl="0"><w:start w:val="1" /><w:numFmt w:val="bullet" /><w:lvlText w:val="" /><w:lvlPicBulletId w:val="0" /><w:lvlJc w:val="left" /><w:pPr><w:rPr /></w:pPr><w:rPr /></w:lvl>"#
+        );
+    }
+
+    #[test]
+    fn test_level_with_tab() {
+        let b = Level::new(
+            0,
+            Start::new(1),
+            NumberFormat::new("decimal"),
+            LevelText::new("%1."),
+            LevelJc::new("left"),
+        )
+        .add_tab(TabStop::new(TabValueType::Num, 420))
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:lvl w:ilvl="0"><w:start w:val="1" /><w:numFmt w:val="decimal" /><w:lvlText w:val="%1." /><w:lvlJc w:val="left" /><w:pPr><w:rPr /><w:tabs><w:tab w:val="num" w:pos="420" /></w:tabs>
+</w:pPr><w:rPr /></w:lvl>"#
         );
     }
 }
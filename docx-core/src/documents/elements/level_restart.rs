@@ -5,7 +5,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LevelRestart {
-    val: u32,
+    pub(crate) val: u32,
 }
 
 impl LevelRestart {
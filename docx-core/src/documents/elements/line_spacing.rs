@@ -2,9 +2,10 @@ use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
 use crate::line_spacing_type::LineSpacingType;
+use crate::types::Twip;
 use serde::*;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LineSpacing {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -26,18 +27,44 @@ impl LineSpacing {
             line: None,
         }
     }
-    pub fn before(mut self, before: Option<u32>) -> Self {
-        self.before = before;
+    pub fn before(mut self, before: Option<Twip>) -> Self {
+        self.before = before.map(Twip::unsigned);
         self
     }
-    pub fn after(mut self, after: Option<u32>) -> Self {
-        self.after = after;
+    pub fn after(mut self, after: Option<Twip>) -> Self {
+        self.after = after.map(Twip::unsigned);
         self
     }
     pub fn line(mut self, line: Option<u32>) -> Self {
         self.line = line;
         self
     }
+
+    pub fn get_before(&self) -> Option<u32> {
+        self.before
+    }
+
+    pub fn get_after(&self) -> Option<u32> {
+        self.after
+    }
+
+    pub fn get_line(&self) -> Option<u32> {
+        self.line
+    }
+
+    pub fn get_line_rule(&self) -> Option<LineSpacingType> {
+        self.line_rule
+    }
+
+    // Values set on the other spacing take precedence, i.e. a style over its base style.
+    pub(crate) fn merge(&self, other: &LineSpacing) -> LineSpacing {
+        LineSpacing {
+            line_rule: other.line_rule.or(self.line_rule),
+            before: other.before.or(self.before),
+            after: other.after.or(self.after),
+            line: other.line.or(self.line),
+        }
+    }
 }
 
 impl BuildXML for LineSpacing {
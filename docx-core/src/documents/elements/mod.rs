@@ -1,6 +1,7 @@
 mod a_graphic;
 mod a_graphic_data;
 mod abstract_numbering;
+mod alt_chunk;
 mod based_on;
 mod bold;
 mod bold_cs;
@@ -21,17 +22,23 @@ mod doc_defaults;
 mod doc_grid;
 mod doc_id;
 mod doc_var;
+mod document_protection;
 mod drawing;
+mod field_char;
 mod font;
+mod footer_reference;
 mod grid_span;
 mod header_reference;
 mod highlight;
 mod indent;
 mod indent_level;
 mod insert;
+mod instr_text;
 mod italic;
 mod italic_cs;
 mod justification;
+mod lang_settings;
+mod last_rendered_page_break;
 mod level;
 mod level_jc;
 mod level_override;
@@ -39,52 +46,71 @@ mod level_restart;
 mod level_text;
 mod line_spacing;
 mod mc_fallback;
+mod move_from;
+mod move_to;
 mod name;
 mod next;
+mod note_properties;
 mod number_format;
 mod numbering;
 mod numbering_id;
+mod numbering_picture_bullet;
 mod numbering_property;
 mod outline_lvl;
 mod page_margin;
 mod page_size;
 mod paragraph;
 mod paragraph_property;
+mod paragraph_property_default;
 mod paragraph_style;
 mod pic;
+mod position;
 mod q_format;
+mod raw_xml;
+mod rsids;
 mod run;
 mod run_fonts;
 mod run_property;
+mod run_property_change;
 mod run_property_default;
 mod section;
 mod section_property;
+mod semi_hidden;
 mod shading;
+mod shape;
+mod spec_vanish;
 mod start;
 mod style;
 mod sz;
 mod sz_cs;
 mod tab;
+mod tab_stop;
 mod table;
 mod table_borders;
+mod table_caption;
 mod table_cell;
 mod table_cell_borders;
 mod table_cell_margins;
 mod table_cell_property;
 mod table_cell_width;
+mod table_description;
 mod table_grid;
 mod table_indent;
 mod table_layout;
 mod table_property;
+mod table_property_exceptions;
 mod table_row;
 mod table_row_property;
 mod table_style;
 mod table_width;
 mod text;
 mod text_border;
+mod text_box;
 mod text_box_content;
 mod text_direction;
+mod ui_priority;
 mod underline;
+mod unhide_when_used;
 mod v_align;
 mod vanish;
 mod vert_align;
@@ -97,6 +123,7 @@ mod zoom;
 pub use a_graphic::*;
 pub use a_graphic_data::*;
 pub use abstract_numbering::*;
+pub use alt_chunk::*;
 pub use based_on::*;
 pub use bold::*;
 pub use bold_cs::*;
@@ -117,17 +144,23 @@ pub use doc_defaults::*;
 pub use doc_grid::*;
 pub use doc_id::*;
 pub use doc_var::*;
+pub use document_protection::*;
 pub use drawing::*;
+pub use field_char::*;
 pub use font::*;
+pub use footer_reference::*;
 pub use grid_span::*;
 pub use header_reference::*;
 pub use highlight::*;
 pub use indent::*;
 pub use indent_level::*;
 pub use insert::*;
+pub use instr_text::*;
 pub use italic::*;
 pub use italic_cs::*;
 pub use justification::*;
+pub use lang_settings::*;
+pub use last_rendered_page_break::*;
 pub use level::*;
 pub use level_jc::*;
 pub use level_override::*;
@@ -135,52 +168,71 @@ pub use level_restart::*;
 pub use level_text::*;
 pub use line_spacing::*;
 pub use mc_fallback::*;
+pub use move_from::*;
+pub use move_to::*;
 pub use name::*;
 pub use next::*;
+pub use note_properties::*;
 pub use number_format::*;
 pub use numbering::*;
 pub use numbering_id::*;
+pub use numbering_picture_bullet::*;
 pub use numbering_property::*;
 pub use outline_lvl::*;
 pub use page_margin::*;
 pub use page_size::*;
 pub use paragraph::*;
 pub use paragraph_property::*;
+pub use paragraph_property_default::*;
 pub use paragraph_style::*;
 pub use pic::*;
+pub use position::*;
 pub use q_format::*;
+pub(crate) use raw_xml::*;
+pub use rsids::*;
 pub use run::*;
 pub use run_fonts::*;
 pub use run_property::*;
+pub use run_property_change::*;
 pub use run_property_default::*;
 pub use section::*;
 pub use section_property::*;
+pub use semi_hidden::*;
 pub use shading::*;
+pub use shape::*;
+pub use spec_vanish::*;
 pub use start::*;
 pub use style::*;
 pub use sz::*;
 pub use sz_cs::*;
 pub use tab::*;
+pub use tab_stop::*;
 pub use table::*;
 pub use table_borders::*;
+pub use table_caption::*;
 pub use table_cell::*;
 pub use table_cell_borders::*;
 pub use table_cell_margins::*;
 pub use table_cell_property::*;
 pub use table_cell_width::*;
+pub use table_description::*;
 pub use table_grid::*;
 pub use table_indent::*;
 pub use table_layout::*;
 pub use table_property::*;
+pub use table_property_exceptions::*;
 pub use table_row::*;
 pub use table_row_property::*;
 pub use table_style::*;
 pub use table_width::*;
 pub use text::*;
 pub use text_border::*;
+pub use text_box::*;
 pub use text_box_content::*;
 pub use text_direction::*;
+pub use ui_priority::*;
 pub use underline::*;
+pub use unhide_when_used::*;
 pub use v_align::*;
 pub use vanish::*;
 pub use vert_align::*;
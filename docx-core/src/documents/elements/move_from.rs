@@ -0,0 +1,76 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::xml_builder::*;
+
+// Runs moved away to a MoveTo elsewhere in the document. Accepting drops them like a deletion.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveFrom {
+    pub author: String,
+    pub date: String,
+    pub children: Vec<Run>,
+}
+
+impl Default for MoveFrom {
+    fn default() -> MoveFrom {
+        MoveFrom {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            children: vec![],
+        }
+    }
+}
+
+impl MoveFrom {
+    pub fn new() -> MoveFrom {
+        Default::default()
+    }
+
+    pub fn add_run(mut self, run: Run) -> MoveFrom {
+        self.children.push(run);
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> MoveFrom {
+        self.author = author.into();
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> MoveFrom {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for MoveFrom {}
+
+impl BuildXML for MoveFrom {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_move_from(&self.generate(), &self.author, &self.date);
+        for r in &self.children {
+            b = b.add_child(r);
+        }
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_from() {
+        let b = MoveFrom::new()
+            .author("bokuweb")
+            .add_run(Run::new().add_text("moved"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveFrom w:id="123" w:author="bokuweb" w:date="1970-01-01T00:00:00Z"><w:r><w:rPr /><w:t xml:space="preserve">moved</w:t></w:r></w:moveFrom>"#
+        );
+    }
+}
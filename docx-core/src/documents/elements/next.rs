@@ -1,6 +1,9 @@
+use serde::{Serialize, Serializer};
+
 use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Next {
     val: String,
 }
@@ -11,6 +14,15 @@ impl Next {
     }
 }
 
+impl Serialize for Next {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val)
+    }
+}
+
 impl BuildXML for Next {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
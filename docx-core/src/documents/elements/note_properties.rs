@@ -0,0 +1,127 @@
+use serde::Serialize;
+use std::fmt;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+use super::*;
+
+// 17.11.11 footnotePr (Document-Wide Footnote Properties)
+// 17.11.4 endnotePr (Document-Wide Endnote Properties)
+// Both share CT_FtnDocProps / CT_EdnDocProps except for the allowed positions.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteProperties<P> {
+    pub position: Option<P>,
+    pub number_format: Option<NumberFormat>,
+    pub start: Option<usize>,
+    pub restart: Option<NoteRestartType>,
+}
+
+pub type FootnoteProperties = NoteProperties<FootnotePositionType>;
+pub type EndnoteProperties = NoteProperties<EndnotePositionType>;
+
+impl<P> NoteProperties<P> {
+    pub fn new() -> NoteProperties<P> {
+        NoteProperties {
+            position: None,
+            number_format: None,
+            start: None,
+            restart: None,
+        }
+    }
+
+    pub fn position(mut self, p: P) -> Self {
+        self.position = Some(p);
+        self
+    }
+
+    pub fn number_format(mut self, f: impl Into<String>) -> Self {
+        self.number_format = Some(NumberFormat::new(f));
+        self
+    }
+
+    pub fn start(mut self, s: usize) -> Self {
+        self.start = Some(s);
+        self
+    }
+
+    pub fn restart(mut self, r: NoteRestartType) -> Self {
+        self.restart = Some(r);
+        self
+    }
+}
+
+impl<P: fmt::Display> NoteProperties<P> {
+    fn build_children(&self, mut b: XMLBuilder) -> XMLBuilder {
+        if let Some(p) = &self.position {
+            b = b.note_position(&p.to_string());
+        }
+        b = b.add_optional_child(&self.number_format);
+        if let Some(s) = self.start {
+            b = b.note_number_start(s);
+        }
+        if let Some(r) = self.restart {
+            b = b.note_number_restart(&r.to_string());
+        }
+        b
+    }
+}
+
+impl BuildXML for FootnoteProperties {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new().open_footnote_property();
+        self.build_children(b).close().build()
+    }
+}
+
+impl BuildXML for EndnoteProperties {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new().open_endnote_property();
+        self.build_children(b).close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_footnote_properties() {
+        let c = FootnoteProperties::new()
+            .position(FootnotePositionType::BeneathText)
+            .number_format("lowerRoman")
+            .start(1)
+            .restart(NoteRestartType::EachPage);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:footnotePr>
+  <w:pos w:val="beneathText" /><w:numFmt w:val="lowerRoman" /><w:numStart w:val="1" />
+  <w:numRestart w:val="eachPage" />
+</w:footnotePr>"#
+        );
+    }
+
+    #[test]
+    fn test_endnote_properties() {
+        let c = EndnoteProperties::new()
+            .position(EndnotePositionType::SectEnd)
+            .number_format("lowerRoman")
+            .start(1)
+            .restart(NoteRestartType::EachSection);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:endnotePr>
+  <w:pos w:val="sectEnd" /><w:numFmt w:val="lowerRoman" /><w:numStart w:val="1" />
+  <w:numRestart w:val="eachSect" />
+</w:endnotePr>"#
+        );
+    }
+}
@@ -1,17 +1,29 @@
 use serde::{Serialize, Serializer};
 
+use std::str::FromStr;
+
 use crate::documents::BuildXML;
+use crate::types::NumberFormatType;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct NumberFormat {
-    val: String,
+    pub(crate) val: String,
 }
 
 impl NumberFormat {
     pub fn new(val: impl Into<String>) -> Self {
         Self { val: val.into() }
     }
+
+    pub fn with_type(t: NumberFormatType) -> Self {
+        Self::new(t.to_string())
+    }
+
+    // None for a value which is not in ST_NumberFormat.
+    pub fn format_type(&self) -> Option<NumberFormatType> {
+        NumberFormatType::from_str(&self.val).ok()
+    }
 }
 
 impl BuildXML for NumberFormat {
@@ -47,4 +59,22 @@ mod tests {
             r#"<w:numFmt w:val="decimal" />"#
         );
     }
+
+    #[test]
+    fn test_number_format_type() {
+        let b = NumberFormat::with_type(NumberFormatType::DecimalEnclosedCircle).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:numFmt w:val="decimalEnclosedCircle" />"#
+        );
+    }
+
+    #[test]
+    fn test_format_type() {
+        assert_eq!(
+            NumberFormat::new("ordinalText").format_type(),
+            Some(NumberFormatType::OrdinalText)
+        );
+        assert_eq!(NumberFormat::new("unknown").format_type(), None);
+    }
 }
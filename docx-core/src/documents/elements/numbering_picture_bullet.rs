@@ -0,0 +1,77 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::xml_builder::*;
+
+// A picture which is used as a bullet of list levels.
+// The level refers the bullet by `numPicBulletId`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NumberingPictureBullet {
+    pub id: usize,
+    pub pic: Pic,
+    // The relationship id of the image. It is used while reading.
+    #[serde(skip)]
+    pub(crate) image_rid: Option<String>,
+}
+
+impl NumberingPictureBullet {
+    pub fn new(id: usize, pic: Pic) -> NumberingPictureBullet {
+        Self {
+            id,
+            pic,
+            image_rid: None,
+        }
+    }
+}
+
+impl BuildXML for NumberingPictureBullet {
+    fn build(&self) -> Vec<u8> {
+        // VML uses pt. 1pt equals to 12700 EMU.
+        let style = format!(
+            "width:{}pt;height:{}pt",
+            self.pic.size.0 as f32 / 12700.0,
+            self.pic.size.1 as f32 / 12700.0
+        );
+        XMLBuilder::new()
+            .open_numbering_picture_bullet(&format!("{}", self.id))
+            .open_pict()
+            .open_vml_shape(
+                &format!("_x0000_i{}", 1025 + self.id),
+                "#_x0000_t75",
+                &style,
+                "t",
+            )
+            .vml_image_data(&create_pic_rid(self.pic.id), "")
+            .close()
+            .close()
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_numbering_picture_bullet() {
+        let buf = std::fs::read("../images/cat_min.jpg").unwrap();
+        let pic = Pic::new(buf).size(12, 12);
+        let b = NumberingPictureBullet::new(0, pic).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r##"<w:numPicBullet w:numPicBulletId="0">
+  <w:pict>
+    <v:shape id="_x0000_i1025" type="#_x0000_t75" style="width:9pt;height:9pt" o:bullet="t">
+      <v:imagedata r:id="rIdImage123" o:title="" />
+    </v:shape>
+  </w:pict>
+</w:numPicBullet>"##
+        );
+    }
+}
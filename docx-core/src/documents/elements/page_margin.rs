@@ -1,5 +1,5 @@
 use crate::documents::BuildXML;
-use crate::types::PageMargin;
+use crate::types::{PageMargin, Twip};
 use crate::xml_builder::*;
 
 // These values were based on microsoft office word2019 windows edition.
@@ -23,32 +23,53 @@ impl PageMargin {
         Default::default()
     }
 
-    pub fn top(self, v: u32) -> PageMargin {
-        PageMargin { top: v, ..self }
+    pub fn top(self, v: impl Into<Twip>) -> PageMargin {
+        PageMargin {
+            top: v.into().unsigned(),
+            ..self
+        }
     }
 
-    pub fn left(self, v: u32) -> PageMargin {
-        PageMargin { left: v, ..self }
+    pub fn left(self, v: impl Into<Twip>) -> PageMargin {
+        PageMargin {
+            left: v.into().unsigned(),
+            ..self
+        }
     }
 
-    pub fn bottom(self, v: u32) -> PageMargin {
-        PageMargin { bottom: v, ..self }
+    pub fn bottom(self, v: impl Into<Twip>) -> PageMargin {
+        PageMargin {
+            bottom: v.into().unsigned(),
+            ..self
+        }
     }
 
-    pub fn right(self, v: u32) -> PageMargin {
-        PageMargin { right: v, ..self }
+    pub fn right(self, v: impl Into<Twip>) -> PageMargin {
+        PageMargin {
+            right: v.into().unsigned(),
+            ..self
+        }
     }
 
-    pub fn header(self, v: u32) -> PageMargin {
-        PageMargin { header: v, ..self }
+    pub fn header(self, v: impl Into<Twip>) -> PageMargin {
+        PageMargin {
+            header: v.into().unsigned(),
+            ..self
+        }
     }
 
-    pub fn footer(self, v: u32) -> PageMargin {
-        PageMargin { footer: v, ..self }
+    pub fn footer(self, v: impl Into<Twip>) -> PageMargin {
+        PageMargin {
+            footer: v.into().unsigned(),
+            ..self
+        }
     }
 
-    pub fn gutter(self, v: u32) -> PageMargin {
-        PageMargin { gutter: v, ..self }
+    pub fn gutter(self, v: impl Into<Twip>) -> PageMargin {
+        PageMargin {
+            gutter: v.into().unsigned(),
+            ..self
+        }
     }
 }
 
@@ -84,4 +105,21 @@ mod tests {
             r#"<w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" />"#
         );
     }
+
+    #[test]
+    fn test_page_margin_with_units() {
+        let b = PageMargin::new()
+            .top(Twip::from_cm(2.0))
+            .left(Twip::from_inch(1.0))
+            .bottom(Twip::from_mm(15.0))
+            .right(Twip::from_pt(36.0))
+            .header(Twip::from_inch(0.5) + Twip(20))
+            .footer(Twip(-10))
+            .gutter(0)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:pgMar w:top="1134" w:right="720" w:bottom="850" w:left="1440" w:header="740" w:footer="0" w:gutter="0" />"#
+        );
+    }
 }
@@ -7,8 +7,8 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PageSize {
-    w: u32,
-    h: u32,
+    pub(crate) w: u32,
+    pub(crate) h: u32,
     orient: Option<PageOrientationType>,
 }
 
@@ -29,21 +29,21 @@ impl PageSize {
         Default::default()
     }
 
-    pub fn size(self, w: u32, h: u32) -> PageSize {
+    pub fn size(self, w: impl Into<Twip>, h: impl Into<Twip>) -> PageSize {
         PageSize {
-            w,
-            h,
+            w: w.into().unsigned(),
+            h: h.into().unsigned(),
             orient: self.orient,
         }
     }
 
-    pub fn width(mut self, w: u32) -> PageSize {
-        self.w = w;
+    pub fn width(mut self, w: impl Into<Twip>) -> PageSize {
+        self.w = w.into().unsigned();
         self
     }
 
-    pub fn height(mut self, h: u32) -> PageSize {
-        self.h = h;
+    pub fn height(mut self, h: impl Into<Twip>) -> PageSize {
+        self.h = h.into().unsigned();
         self
     }
 
@@ -2,7 +2,11 @@ use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 
 use super::*;
-use crate::documents::BuildXML;
+use crate::documents::{
+    generate_bookmark_id, numbering_of, resolve_numbering, resolve_spacing, BuildXML, Docx,
+    ResolvedNumbering, SeqField,
+};
+use crate::errors::DocxError;
 use crate::types::*;
 use crate::xml_builder::*;
 
@@ -13,6 +17,9 @@ pub struct Paragraph {
     pub children: Vec<ParagraphChild>,
     pub property: ParagraphProperty,
     pub has_numbering: bool,
+    // The id is taken from the counter rather than set or read, so a deterministic build may renumber it.
+    #[serde(skip)]
+    pub(crate) auto_id: bool,
 }
 
 impl Default for Paragraph {
@@ -22,6 +29,7 @@ impl Default for Paragraph {
             children: Vec::new(),
             property: ParagraphProperty::new(),
             has_numbering: false,
+            auto_id: true,
         }
     }
 }
@@ -31,10 +39,14 @@ pub enum ParagraphChild {
     Run(Box<Run>),
     Insert(Insert),
     Delete(Delete),
+    MoveFrom(MoveFrom),
+    MoveTo(MoveTo),
     BookmarkStart(BookmarkStart),
     BookmarkEnd(BookmarkEnd),
     CommentStart(Box<CommentRangeStart>),
     CommentEnd(CommentRangeEnd),
+    // Written as it is, see add_raw_xml.
+    RawXml(String),
 }
 
 impl BuildXML for ParagraphChild {
@@ -43,10 +55,13 @@ impl BuildXML for ParagraphChild {
             ParagraphChild::Run(v) => v.build(),
             ParagraphChild::Insert(v) => v.build(),
             ParagraphChild::Delete(v) => v.build(),
+            ParagraphChild::MoveFrom(v) => v.build(),
+            ParagraphChild::MoveTo(v) => v.build(),
             ParagraphChild::BookmarkStart(v) => v.build(),
             ParagraphChild::BookmarkEnd(v) => v.build(),
             ParagraphChild::CommentStart(v) => v.build(),
             ParagraphChild::CommentEnd(v) => v.build(),
+            ParagraphChild::RawXml(v) => XMLBuilder::new().plain_text(v).build(),
         }
     }
 }
@@ -75,6 +90,18 @@ impl Serialize for ParagraphChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            ParagraphChild::MoveFrom(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFrom", 2)?;
+                t.serialize_field("type", "moveFrom")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveTo(ref r) => {
+                let mut t = serializer.serialize_struct("MoveTo", 2)?;
+                t.serialize_field("type", "moveTo")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
             ParagraphChild::BookmarkStart(ref r) => {
                 let mut t = serializer.serialize_struct("BookmarkStart", 2)?;
                 t.serialize_field("type", "bookmarkStart")?;
@@ -99,6 +126,12 @@ impl Serialize for ParagraphChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            ParagraphChild::RawXml(ref r) => {
+                let mut t = serializer.serialize_struct("RawXml", 2)?;
+                t.serialize_field("type", "rawXml")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -110,6 +143,7 @@ impl Paragraph {
 
     pub fn id(mut self, id: impl Into<String>) -> Self {
         self.id = id.into();
+        self.auto_id = false;
         self
     }
 
@@ -122,6 +156,17 @@ impl Paragraph {
         self
     }
 
+    pub fn add_text_box(self, t: TextBox) -> Paragraph {
+        self.add_run(Run::new().add_drawing(Drawing::new().text_box(t)))
+    }
+
+    // Adds the label followed by its SEQ number, i.e. "Figure 1".
+    pub fn add_caption(self, label: impl Into<String>, seq: SeqField) -> Paragraph {
+        let label = label.into();
+        self.add_run(Run::new().add_text(format!("{} ", label)))
+            .add_run(Run::new().add_field(seq.instr(&label)))
+    }
+
     pub fn add_insert(mut self, insert: Insert) -> Paragraph {
         self.children.push(ParagraphChild::Insert(insert));
         self
@@ -132,6 +177,16 @@ impl Paragraph {
         self
     }
 
+    pub fn add_move_from(mut self, m: MoveFrom) -> Paragraph {
+        self.children.push(ParagraphChild::MoveFrom(m));
+        self
+    }
+
+    pub fn add_move_to(mut self, m: MoveTo) -> Paragraph {
+        self.children.push(ParagraphChild::MoveTo(m));
+        self
+    }
+
     pub fn add_bookmark_start(mut self, id: usize, name: impl Into<String>) -> Paragraph {
         self.children
             .push(ParagraphChild::BookmarkStart(BookmarkStart::new(id, name)));
@@ -144,6 +199,76 @@ impl Paragraph {
         self
     }
 
+    // Writes an XML fragment into the paragraph as it is, i.e. an equation or a w14 extension
+    // which is not modeled. The fragment is checked to be well formed and to use the prefixes
+    // declared on the part, others must be declared in the fragment. Block-level elements
+    // such as w:p or w:tbl are rejected, and the paragraph is left unchanged on error.
+    pub fn add_raw_xml(&mut self, xml: impl Into<String>) -> Result<(), DocxError> {
+        let xml = xml.into();
+        validate_raw_xml(&xml)?;
+        self.children.push(ParagraphChild::RawXml(xml));
+        Ok(())
+    }
+
+    // Merge adjacent runs of the same formatting, i.e. the fragments Word leaves after editing.
+    // Bookmarks, comments and tracked changes in between keep the runs apart.
+    pub fn optimize(mut self) -> Paragraph {
+        self.merge_runs();
+        self
+    }
+
+    pub(crate) fn merge_runs(&mut self) {
+        let mut children: Vec<ParagraphChild> = vec![];
+        for c in self.children.drain(..) {
+            match c {
+                ParagraphChild::Run(run) => {
+                    if let Some(ParagraphChild::Run(prev)) = children.last_mut() {
+                        if prev.can_merge(&run) {
+                            prev.merge(*run);
+                            continue;
+                        }
+                    }
+                    children.push(ParagraphChild::Run(run));
+                }
+                ParagraphChild::Insert(mut ins) => {
+                    let mut inserted: Vec<InsertChild> = vec![];
+                    for c in ins.children.drain(..) {
+                        match c {
+                            InsertChild::Run(run) => {
+                                if let Some(InsertChild::Run(prev)) = inserted.last_mut() {
+                                    if prev.can_merge(&run) {
+                                        prev.merge(*run);
+                                        continue;
+                                    }
+                                }
+                                inserted.push(InsertChild::Run(run));
+                            }
+                            c => inserted.push(c),
+                        }
+                    }
+                    ins.children = inserted;
+                    children.push(ParagraphChild::Insert(ins));
+                }
+                c => children.push(c),
+            }
+        }
+        self.children = children;
+    }
+
+    // Wrap the runs in a bookmark, with matching start and end ids.
+    pub fn add_bookmark(self, id: usize, name: impl Into<String>, runs: Vec<Run>) -> Paragraph {
+        let id = if id == BookmarkStart::AUTO_ID {
+            generate_bookmark_id()
+        } else {
+            id
+        };
+        let mut p = self.add_bookmark_start(id, name);
+        for run in runs {
+            p = p.add_run(run);
+        }
+        p.add_bookmark_end(id)
+    }
+
     pub fn add_comment_start(mut self, comment: Comment) -> Paragraph {
         self.children.push(ParagraphChild::CommentStart(Box::new(
             CommentRangeStart::new(comment),
@@ -167,6 +292,16 @@ impl Paragraph {
         self
     }
 
+    pub fn add_tab(mut self, t: TabStop) -> Self {
+        self.property = self.property.add_tab(t);
+        self
+    }
+
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.property = self.property.shading(s);
+        self
+    }
+
     pub fn keep_next(mut self, v: bool) -> Self {
         self.property = self.property.keep_next(v);
         self
@@ -194,9 +329,9 @@ impl Paragraph {
 
     pub fn indent(
         mut self,
-        left: Option<i32>,
+        left: Option<Twip>,
         special_indent: Option<SpecialIndentType>,
-        end: Option<i32>,
+        end: Option<Twip>,
         start_chars: Option<i32>,
     ) -> Paragraph {
         self.property = self.property.indent(left, special_indent, end, start_chars);
@@ -219,6 +354,34 @@ impl Paragraph {
         self
     }
 
+    // The numbering definitions of the paragraph, following numId to its abstract numbering
+    // and applying the overrides of the numbering instance. numId and ilvl which are not set
+    // directly are taken from the style chain.
+    pub fn numbering_info(&self, docx: &Docx) -> Option<ResolvedNumbering> {
+        let (id, level) = numbering_of(Some(&docx.styles), self)?;
+        // numId 0 removes the numbering.
+        if id == 0 {
+            return None;
+        }
+        resolve_numbering(&docx.numberings, id, level)
+    }
+
+    // The spacing as laid out, following docDefaults, the style chain and contextual spacing.
+    // The paragraph is looked up by id in the document body to find its neighbors.
+    pub fn effective_spacing(&self, docx: &Docx) -> LineSpacing {
+        resolve_spacing(docx, self)
+    }
+
+    pub fn spacing(mut self, s: LineSpacing) -> Self {
+        self.property = self.property.spacing(s);
+        self
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.property = self.property.contextual_spacing(v);
+        self
+    }
+
     pub fn size(mut self, size: usize) -> Self {
         self.property.run_property = self.property.run_property.size(size);
         self
@@ -239,15 +402,39 @@ impl Paragraph {
         self
     }
 
+    pub(crate) fn bookmark_start_position(&self, name: &str) -> Option<(usize, usize)> {
+        self.children.iter().enumerate().find_map(|(i, c)| match c {
+            ParagraphChild::BookmarkStart(b) if b.name == name => Some((i, b.id)),
+            _ => None,
+        })
+    }
+
+    // The position of the BookmarkEnd of the id, searching the children from `from` on.
+    pub(crate) fn bookmark_end_position(&self, id: usize, from: usize) -> Option<usize> {
+        self.children
+            .iter()
+            .enumerate()
+            .skip(from)
+            .find_map(|(i, c)| match c {
+                ParagraphChild::BookmarkEnd(b) if b.id == id => Some(i),
+                _ => None,
+            })
+    }
+
     pub(crate) fn run_property(mut self, p: RunProperty) -> Self {
         self.property.run_property = p;
         self
     }
 
+    pub fn rsids(mut self, r: Rsids) -> Self {
+        self.property.rsids = r;
+        self
+    }
+
     pub fn line_spacing(
         mut self,
-        before: Option<u32>,
-        after: Option<u32>,
+        before: Option<Twip>,
+        after: Option<Twip>,
         line: Option<u32>,
         spacing_type: Option<LineSpacingType>,
     ) -> Self {
@@ -260,8 +447,9 @@ impl Paragraph {
 
 impl BuildXML for Paragraph {
     fn build(&self) -> Vec<u8> {
+        let rsids = self.property.rsids.attrs();
         XMLBuilder::new()
-            .open_paragraph(&self.id)
+            .open_paragraph_with_attrs(&self.id, &rsids)
             .add_child(&self.property)
             .add_children(&self.children)
             .close()
@@ -288,6 +476,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_paragraph_rsids() {
+        let b = Paragraph::new()
+            .rsids(Rsids::new().r("00A1").r_default("00A1"))
+            .add_run(
+                Run::new()
+                    .rsids(Rsids::new().r_pr("00B2"))
+                    .add_text("Hello"),
+            )
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:p w14:paraId="12345678" w:rsidR="00A1" w:rsidRDefault="00A1"><w:pPr><w:rPr /></w:pPr><w:r w:rsidRPr="00B2"><w:rPr /><w:t xml:space="preserve">Hello</w:t></w:r></w:p>"#
+        );
+    }
+
+    #[test]
+    fn test_raw_xml() {
+        let mut run = Run::new();
+        run.add_raw_xml(r#"<w:sym w:font="Wingdings" w:char="F04A" />"#)
+            .unwrap();
+        let mut p = Paragraph::new();
+        p.add_raw_xml(r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math"><m:r><m:t>x</m:t></m:r></m:oMath>"#)
+            .unwrap();
+        let b = p.add_run(run).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math"><m:r><m:t>x</m:t></m:r></m:oMath><w:r><w:rPr /><w:sym w:font="Wingdings" w:char="F04A" /></w:r></w:p>"#
+        );
+        let mut p = Paragraph::new();
+        assert!(p.add_raw_xml("<x:unknown />").is_err());
+        assert!(p.add_raw_xml("<w:p />").is_err());
+        assert!(p.children.is_empty());
+    }
+
+    #[test]
+    fn test_optimize() {
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("Hello, "))
+            .add_run(
+                Run::new()
+                    .add_text("World")
+                    .rsids(Rsids::new().r("00A1B2C3")),
+            )
+            .add_run(Run::new().add_tab().add_text("!"))
+            .add_run(Run::new().add_text("Bold").bold())
+            .add_run(Run::new().add_text(" text").bold())
+            .add_bookmark_start(1, "mark")
+            .add_run(Run::new().add_text("after").bold())
+            .add_run(Run::new().add_field_char(FieldCharType::Begin, false))
+            .add_run(Run::new().add_text("end"))
+            .optimize();
+        assert_eq!(
+            p.children,
+            vec![
+                ParagraphChild::Run(Box::new(
+                    Run::new().add_text("Hello, World").add_tab().add_text("!")
+                )),
+                ParagraphChild::Run(Box::new(Run::new().add_text("Bold text").bold())),
+                ParagraphChild::BookmarkStart(BookmarkStart::new(1, "mark")),
+                ParagraphChild::Run(Box::new(Run::new().add_text("after").bold())),
+                ParagraphChild::Run(Box::new(
+                    Run::new().add_field_char(FieldCharType::Begin, false)
+                )),
+                ParagraphChild::Run(Box::new(Run::new().add_text("end"))),
+            ]
+        );
+    }
+
     #[test]
     fn test_bookmark() {
         let b = Paragraph::new()
@@ -301,6 +558,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_bookmark() {
+        let p = Paragraph::new().add_bookmark(
+            BookmarkStart::AUTO_ID,
+            "article",
+            vec![Run::new().add_text("Hello"), Run::new().add_text(" World")],
+        );
+        assert_eq!(
+            p,
+            Paragraph::new()
+                .add_bookmark_start(123, "article")
+                .add_run(Run::new().add_text("Hello"))
+                .add_run(Run::new().add_text(" World"))
+                .add_bookmark_end(123)
+        );
+    }
+
     #[test]
     fn test_comment() {
         let b = Paragraph::new()
@@ -335,7 +609,12 @@ mod tests {
     #[test]
     fn test_line_spacing_and_character_spacing() {
         let b = Paragraph::new()
-            .line_spacing(Some(20), Some(30), Some(200), Some(LineSpacingType::Auto))
+            .line_spacing(
+                Some(Twip(20)),
+                Some(Twip(30)),
+                Some(200),
+                Some(LineSpacingType::Auto),
+            )
             .add_run(Run::new().add_text("Hello"))
             .build();
         assert_eq!(
@@ -350,7 +629,7 @@ mod tests {
         let p = Paragraph::new().add_run(run);
         assert_eq!(
             serde_json::to_string(&p).unwrap(),
-            r#"{"id":"12345678","children":[{"type":"run","data":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"characterSpacing":null,"fonts":null,"textBorder":null,"del":null,"ins":null},"children":[{"type":"text","data":{"preserveSpace":true,"text":"Hello"}}]}}],"property":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"characterSpacing":null,"fonts":null,"textBorder":null,"del":null,"ins":null},"style":null,"numberingProperty":null,"alignment":null,"indent":null,"lineSpacing":null,"keepNext":false,"keepLines":false,"pageBreakBefore":false,"windowControl":false,"outlineLvl":null,"divId":null},"hasNumbering":false}"#,
+            r#"{"id":"12345678","children":[{"type":"run","data":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"specVanish":null,"characterSpacing":null,"position":null,"fonts":null,"textBorder":null,"shading":null,"del":null,"ins":null,"lang":null,"propertyChange":null},"children":[{"type":"text","data":{"preserveSpace":true,"text":"Hello"}}]}}],"property":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"specVanish":null,"characterSpacing":null,"position":null,"fonts":null,"textBorder":null,"shading":null,"del":null,"ins":null,"lang":null,"propertyChange":null},"style":null,"numberingProperty":null,"alignment":null,"indent":null,"lineSpacing":null,"contextualSpacing":null,"keepNext":false,"keepLines":false,"pageBreakBefore":false,"windowControl":false,"outlineLvl":null,"tabs":[],"shading":null,"divId":null,"sectionProperty":null},"hasNumbering":false}"#,
         );
     }
 
@@ -361,7 +640,7 @@ mod tests {
         let p = Paragraph::new().add_insert(ins);
         assert_eq!(
             serde_json::to_string(&p).unwrap(),
-            r#"{"id":"12345678","children":[{"type":"insert","data":{"children":[{"type":"run","data":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"characterSpacing":null,"fonts":null,"textBorder":null,"del":null,"ins":null},"children":[{"type":"text","data":{"preserveSpace":true,"text":"Hello"}}]}}],"author":"unnamed","date":"1970-01-01T00:00:00Z"}}],"property":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"characterSpacing":null,"fonts":null,"textBorder":null,"del":null,"ins":null},"style":null,"numberingProperty":null,"alignment":null,"indent":null,"lineSpacing":null,"keepNext":false,"keepLines":false,"pageBreakBefore":false,"windowControl":false,"outlineLvl":null,"divId":null},"hasNumbering":false}"#
+            r#"{"id":"12345678","children":[{"type":"insert","data":{"children":[{"type":"run","data":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"specVanish":null,"characterSpacing":null,"position":null,"fonts":null,"textBorder":null,"shading":null,"del":null,"ins":null,"lang":null,"propertyChange":null},"children":[{"type":"text","data":{"preserveSpace":true,"text":"Hello"}}]}}],"author":"unnamed","date":"1970-01-01T00:00:00Z"}}],"property":{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"specVanish":null,"characterSpacing":null,"position":null,"fonts":null,"textBorder":null,"shading":null,"del":null,"ins":null,"lang":null,"propertyChange":null},"style":null,"numberingProperty":null,"alignment":null,"indent":null,"lineSpacing":null,"contextualSpacing":null,"keepNext":false,"keepLines":false,"pageBreakBefore":false,"windowControl":false,"outlineLvl":null,"tabs":[],"shading":null,"divId":null,"sectionProperty":null},"hasNumbering":false}"#
         );
     }
 }
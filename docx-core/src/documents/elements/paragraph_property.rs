@@ -2,7 +2,7 @@ use serde::Serialize;
 
 use super::*;
 use crate::documents::BuildXML;
-use crate::types::{AlignmentType, LineSpacingType, SpecialIndentType};
+use crate::types::{AlignmentType, LineSpacingType, SpecialIndentType, Twip};
 use crate::xml_builder::*;
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
@@ -14,13 +14,22 @@ pub struct ParagraphProperty {
     pub alignment: Option<Justification>,
     pub indent: Option<Indent>,
     pub line_spacing: Option<LineSpacing>,
+    // Ignore the spacing between paragraphs of the same style.
+    pub contextual_spacing: Option<bool>,
     pub keep_next: bool,
     pub keep_lines: bool,
     pub page_break_before: bool,
     pub window_control: bool,
     pub outline_lvl: Option<OutlineLvl>,
+    pub tabs: Vec<TabStop>,
+    pub shading: Option<Shading>,
     // read only
     pub(crate) div_id: Option<String>,
+    // Set on the last paragraph of a section when read.
+    pub section_property: Option<SectionProperty>,
+    // Written on the paragraph itself rather than in pPr.
+    #[serde(skip)]
+    pub rsids: Rsids,
 }
 
 impl Default for ParagraphProperty {
@@ -32,12 +41,17 @@ impl Default for ParagraphProperty {
             alignment: None,
             indent: None,
             line_spacing: None,
+            contextual_spacing: None,
             keep_next: false,
             keep_lines: false,
             page_break_before: false,
             window_control: false,
             outline_lvl: None,
+            tabs: vec![],
+            shading: None,
             div_id: None,
+            section_property: None,
+            rsids: Rsids::new(),
         }
     }
 }
@@ -64,12 +78,17 @@ impl ParagraphProperty {
 
     pub fn indent(
         mut self,
-        left: Option<i32>,
+        left: Option<Twip>,
         special_indent: Option<SpecialIndentType>,
-        end: Option<i32>,
+        end: Option<Twip>,
         start_chars: Option<i32>,
     ) -> Self {
-        self.indent = Some(Indent::new(left, special_indent, end, start_chars));
+        self.indent = Some(Indent::new(
+            left.map(i32::from),
+            special_indent,
+            end.map(i32::from),
+            start_chars,
+        ));
         self
     }
 
@@ -78,10 +97,12 @@ impl ParagraphProperty {
         self
     }
 
+    // before and after are in twips, line is in 240ths of a line for the auto rule and in
+    // twips otherwise.
     pub fn line_spacing(
         mut self,
-        before: Option<u32>,
-        after: Option<u32>,
+        before: Option<Twip>,
+        after: Option<Twip>,
         line: Option<u32>,
         spacing_type: Option<LineSpacingType>,
     ) -> Self {
@@ -94,6 +115,21 @@ impl ParagraphProperty {
         self
     }
 
+    pub fn spacing(mut self, s: LineSpacing) -> Self {
+        self.line_spacing = Some(s);
+        self
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.contextual_spacing = Some(v);
+        self
+    }
+
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.shading = Some(s);
+        self
+    }
+
     pub fn keep_next(mut self, v: bool) -> Self {
         self.keep_next = v;
         self
@@ -119,6 +155,11 @@ impl ParagraphProperty {
         self
     }
 
+    pub fn add_tab(mut self, t: TabStop) -> Self {
+        self.tabs.push(t);
+        self
+    }
+
     pub(crate) fn hanging_chars(mut self, chars: i32) -> Self {
         if let Some(indent) = self.indent {
             self.indent = Some(indent.hanging_chars(chars));
@@ -141,11 +182,25 @@ impl BuildXML for ParagraphProperty {
             .add_child(&self.run_property)
             .add_optional_child(&self.style)
             .add_optional_child(&self.numbering_property)
+            .add_optional_child(&self.shading);
+
+        if !self.tabs.is_empty() {
+            b = b.open_tabs().add_children(&self.tabs).close();
+        }
+
+        b = b
             .add_optional_child(&self.alignment)
             .add_optional_child(&self.indent)
             .add_optional_child(&self.line_spacing)
             .add_optional_child(&self.outline_lvl);
 
+        // false is written too, as it overrides contextual spacing of the style.
+        match self.contextual_spacing {
+            Some(true) => b = b.contextual_spacing(),
+            Some(false) => b = b.contextual_spacing_with_val("false"),
+            None => {}
+        }
+
         if self.keep_next {
             b = b.keep_next()
         }
@@ -162,7 +217,7 @@ impl BuildXML for ParagraphProperty {
             b = b.window_control()
         }
 
-        b.close().build()
+        b.add_optional_child(&self.section_property).close().build()
     }
 }
 
@@ -172,6 +227,7 @@ mod tests {
     use super::*;
     #[cfg(test)]
     use pretty_assertions::assert_eq;
+    use crate::types::TabValueType;
     use std::str;
 
     #[test]
@@ -191,10 +247,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_contextual_spacing_off() {
+        let b = ParagraphProperty::new().contextual_spacing(false).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:pPr><w:rPr /><w:contextualSpacing w:val="false" />
+</w:pPr>"#
+        );
+    }
+
+    #[test]
+    fn test_tabs() {
+        let c = ParagraphProperty::new().add_tab(TabStop::new(TabValueType::Num, 720));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:pPr><w:rPr /><w:tabs><w:tab w:val="num" w:pos="720" /></w:tabs>
+</w:pPr>"#
+        );
+    }
+
     #[test]
     fn test_indent() {
         let c = ParagraphProperty::new();
-        let b = c.indent(Some(20), None, None, None).build();
+        let b = c.indent(Some(Twip(20)), None, None, None).build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
             r#"<w:pPr><w:rPr /><w:ind w:left="20" w:right="0" /></w:pPr>"#
@@ -225,10 +302,15 @@ mod tests {
     #[test]
     fn test_indent_json() {
         let c = ParagraphProperty::new();
-        let b = c.indent(Some(20), Some(SpecialIndentType::FirstLine(10)), None, None);
+        let b = c.indent(
+            Some(Twip(20)),
+            Some(SpecialIndentType::FirstLine(10)),
+            None,
+            None,
+        );
         assert_eq!(
             serde_json::to_string(&b).unwrap(),
-            r#"{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"characterSpacing":null,"fonts":null,"textBorder":null,"del":null,"ins":null},"style":null,"numberingProperty":null,"alignment":null,"indent":{"start":20,"startChars":null,"end":null,"specialIndent":{"type":"firstLine","val":10},"hangingChars":null,"firstLineChars":null},"lineSpacing":null,"keepNext":false,"keepLines":false,"pageBreakBefore":false,"windowControl":false,"outlineLvl":null,"divId":null}"#
+            r#"{"runProperty":{"sz":null,"szCs":null,"color":null,"highlight":null,"vertAlign":null,"underline":null,"bold":null,"boldCs":null,"italic":null,"italicCs":null,"vanish":null,"specVanish":null,"characterSpacing":null,"position":null,"fonts":null,"textBorder":null,"shading":null,"del":null,"ins":null,"lang":null,"propertyChange":null},"style":null,"numberingProperty":null,"alignment":null,"indent":{"start":20,"startChars":null,"end":null,"specialIndent":{"type":"firstLine","val":10},"hangingChars":null,"firstLineChars":null},"lineSpacing":null,"contextualSpacing":null,"keepNext":false,"keepLines":false,"pageBreakBefore":false,"windowControl":false,"outlineLvl":null,"tabs":[],"shading":null,"divId":null,"sectionProperty":null}"#
         );
     }
 
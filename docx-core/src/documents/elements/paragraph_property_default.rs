@@ -0,0 +1,69 @@
+use serde::Serialize;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParagraphPropertyDefault {
+    paragraph_property: ParagraphProperty,
+}
+
+impl ParagraphPropertyDefault {
+    pub fn new() -> ParagraphPropertyDefault {
+        Default::default()
+    }
+
+    pub fn line_spacing(mut self, s: LineSpacing) -> Self {
+        self.paragraph_property = self.paragraph_property.spacing(s);
+        self
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.contextual_spacing(v);
+        self
+    }
+
+    pub(crate) fn get_paragraph_property(&self) -> &ParagraphProperty {
+        &self.paragraph_property
+    }
+}
+
+impl Default for ParagraphPropertyDefault {
+    fn default() -> Self {
+        let paragraph_property = ParagraphProperty::new();
+        ParagraphPropertyDefault { paragraph_property }
+    }
+}
+
+impl BuildXML for ParagraphPropertyDefault {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.open_paragraph_property_default()
+            .add_child(&self.paragraph_property)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::Twip;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let c = ParagraphPropertyDefault::new()
+            .line_spacing(LineSpacing::new(None).after(Some(Twip(160))));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:pPrDefault><w:pPr><w:rPr /><w:spacing w:after="160" /></w:pPr></w:pPrDefault>"#
+        );
+    }
+}
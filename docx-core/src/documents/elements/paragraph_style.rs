@@ -30,6 +30,10 @@ impl ParagraphStyle {
             Default::default()
         }
     }
+
+    pub(crate) fn val(&self) -> &str {
+        &self.val
+    }
 }
 
 impl BuildXML for ParagraphStyle {
@@ -3,6 +3,8 @@ use image::*;
 use serde::Serialize;
 
 use crate::documents::*;
+use crate::types::emu::from_px;
+use crate::types::Emu;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Copy, Serialize, PartialEq)]
@@ -24,17 +26,20 @@ pub enum DrawingPosition {
 pub struct Pic {
   pub id: usize,
   pub image: Vec<u8>,
+  // Width and height in EMU.
   pub size: (u32, u32),
   pub position_type: DrawingPositionType,
   pub position_h: DrawingPosition,
   pub position_v: DrawingPosition,
+  // Distance between the floating picture and the surrounding text, top, bottom, left and right in EMU.
+  pub wrap_distance: (u32, u32, u32, u32),
 }
 
 impl Pic {
   pub fn new(buf: Vec<u8>) -> Pic {
     let id = generate_pic_id();
     let dimg = image::load_from_memory(&buf).unwrap();
-    let size = dimg.dimensions();
+    let (w, h) = dimg.dimensions();
     let mut image = vec![];
     dimg
       .write_to(&mut image, ImageFormat::Png)
@@ -42,7 +47,7 @@ impl Pic {
     Self {
       id,
       image,
-      size,
+      size: (from_px(w), from_px(h)),
       position_type: DrawingPositionType::Inline {
         dist_t: 0,
         dist_b: 0,
@@ -51,11 +56,37 @@ impl Pic {
       },
       position_h: DrawingPosition::Offset(0),
       position_v: DrawingPosition::Offset(0),
+      wrap_distance: (0, 0, 0, 0),
+    }
+  }
+
+  // Create a pic without decoding the image, i.e. for the images which are read from docx.
+  // The dimensions are in EMU.
+  pub(crate) fn new_with_dimensions(buf: Vec<u8>, w: u32, h: u32) -> Pic {
+    Self {
+      id: generate_pic_id(),
+      image: buf,
+      size: (w, h),
+      position_type: DrawingPositionType::Inline {
+        dist_t: 0,
+        dist_b: 0,
+        dist_l: 0,
+        dist_r: 0,
+      },
+      position_h: DrawingPosition::Offset(0),
+      position_v: DrawingPosition::Offset(0),
+      wrap_distance: (0, 0, 0, 0),
     }
   }
 
   pub fn size(mut self, w_px: u32, h_px: u32) -> Pic {
-    self.size = (w_px, h_px);
+    self.size = (from_px(w_px), from_px(h_px));
+    self
+  }
+
+  // The extent in any unit, i.e. Emu::from_mm(30.0).
+  pub fn extent(mut self, w: impl Into<Emu>, h: impl Into<Emu>) -> Pic {
+    self.size = (emu_u32(w.into()), emu_u32(h.into()));
     self
   }
 
@@ -73,13 +104,34 @@ impl Pic {
     self.position_v = DrawingPosition::Offset(y);
     self
   }
+
+  // The distances in any unit, i.e. Emu::from_mm(3.0). Text wraps around the picture when set.
+  pub fn wrap_distance(
+    mut self,
+    top: impl Into<Emu>,
+    bottom: impl Into<Emu>,
+    left: impl Into<Emu>,
+    right: impl Into<Emu>,
+  ) -> Pic {
+    self.wrap_distance = (
+      emu_u32(top.into()),
+      emu_u32(bottom.into()),
+      emu_u32(left.into()),
+      emu_u32(right.into()),
+    );
+    self
+  }
+}
+
+fn emu_u32(v: Emu) -> u32 {
+  v.0.max(0).min(i64::from(u32::MAX)) as u32
 }
 
 impl BuildXML for Pic {
   fn build(&self) -> Vec<u8> {
     let b = XMLBuilder::new();
-    let w = format!("{}", crate::types::emu::from_px(self.size.0));
-    let h = format!("{}", crate::types::emu::from_px(self.size.1));
+    let w = format!("{}", self.size.0);
+    let h = format!("{}", self.size.1);
     b.open_pic("http://schemas.openxmlformats.org/drawingml/2006/picture")
       .open_pic_nv_pic_pr()
       .pic_c_nv_pr("0", "")
@@ -152,4 +204,18 @@ mod tests {
 </pic:pic>"#
     );
   }
+
+  #[test]
+  fn test_pic_extent() {
+    use std::io::Read;
+
+    let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+    let mut buf = Vec::new();
+    let _ = img.read_to_end(&mut buf).unwrap();
+    let h = Emu::from(crate::types::Twip::from_cm(2.54));
+    let pic = Pic::new(buf).extent(Emu::from_inch(2.0), h);
+    assert_eq!(pic.size, (1828800, 914400));
+    let pic = pic.extent(Emu::from_mm(30.0), Emu::from_mm(20.5));
+    assert_eq!(pic.size, (1080000, 738000));
+  }
 }
@@ -0,0 +1,54 @@
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+use serde::*;
+
+// Raise or lower the run's baseline in half-points.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    value: i32,
+}
+
+impl Position {
+    pub fn new(s: i32) -> Position {
+        Self { value: s }
+    }
+}
+
+impl BuildXML for Position {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.position(self.value).build()
+    }
+}
+
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_position() {
+        let b = Position::new(-6).build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:position w:val="-6" />"#);
+    }
+
+    #[test]
+    fn test_position_json() {
+        let s = Position { value: 6 };
+        assert_eq!(serde_json::to_string(&s).unwrap(), r#"6"#);
+    }
+}
@@ -1,3 +1,5 @@
+use serde::{Serialize, Serializer};
+
 use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
@@ -7,6 +9,7 @@ use crate::xml_builder::*;
 // application. If this element is set, then this style has been designated as being particularly important for the
 // current document, and this information can be used by an application in any means desired. [Note: This setting
 // 637ECMA-376 Part 1 does not imply any behavior for the style, only that the style is of particular significance for this document. end note]
+#[derive(Debug, Clone, PartialEq)]
 pub struct QFormat {}
 
 impl QFormat {
@@ -21,6 +24,15 @@ impl Default for QFormat {
     }
 }
 
+impl Serialize for QFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(true)
+    }
+}
+
 impl BuildXML for QFormat {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
@@ -0,0 +1,121 @@
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::errors::DocxError;
+
+// Prefixes declared on the root of every part a paragraph is written to.
+// Any other namespace must be declared in the fragment itself.
+const DECLARED_NAMESPACES: [(&str, &str); 11] = [
+    ("o", "urn:schemas-microsoft-com:office:office"),
+    (
+        "r",
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+    ),
+    ("v", "urn:schemas-microsoft-com:vml"),
+    (
+        "w",
+        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+    ),
+    ("w10", "urn:schemas-microsoft-com:office:word"),
+    (
+        "wp",
+        "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing",
+    ),
+    (
+        "wps",
+        "http://schemas.microsoft.com/office/word/2010/wordprocessingShape",
+    ),
+    (
+        "wpg",
+        "http://schemas.microsoft.com/office/word/2010/wordprocessingGroup",
+    ),
+    (
+        "mc",
+        "http://schemas.openxmlformats.org/markup-compatibility/2006",
+    ),
+    (
+        "wp14",
+        "http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing",
+    ),
+    (
+        "w14",
+        "http://schemas.microsoft.com/office/word/2010/wordml",
+    ),
+];
+
+const W_NAMESPACE: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+// Block-level elements of the main namespace, which can't be written into a paragraph or a run.
+const BLOCK_LEVEL_ELEMENTS: [&str; 5] = ["p", "tbl", "sectPr", "altChunk", "body"];
+
+// Checks that the fragment is well formed, consists of elements only and uses bound prefixes,
+// so it can be written into the part as it is. Block-level elements are rejected.
+pub(crate) fn validate_raw_xml(xml: &str) -> Result<(), DocxError> {
+    let declarations: Vec<String> = DECLARED_NAMESPACES
+        .iter()
+        .map(|(prefix, uri)| format!(r#"xmlns:{}="{}""#, prefix, uri))
+        .collect();
+    let wrapped = format!("<root {}>{}</root>", declarations.join(" "), xml);
+    let parser = EventReader::new(wrapped.as_bytes());
+    let mut depth = 0;
+    let mut elements = 0;
+    for e in parser {
+        match e {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                depth += 1;
+                if depth == 2 {
+                    elements += 1;
+                    if name.namespace.as_deref() == Some(W_NAMESPACE)
+                        && BLOCK_LEVEL_ELEMENTS.contains(&name.local_name.as_str())
+                    {
+                        return Err(DocxError::RawXmlError(format!(
+                            "block-level element w:{}",
+                            name.local_name
+                        )));
+                    }
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => depth -= 1,
+            Ok(XmlEvent::Characters(_)) | Ok(XmlEvent::CData(_)) if depth == 1 => {
+                return Err(DocxError::RawXmlError(
+                    "text outside of an element".to_owned(),
+                ));
+            }
+            Ok(XmlEvent::ProcessingInstruction { .. }) => {
+                return Err(DocxError::RawXmlError(
+                    "processing instructions are not allowed".to_owned(),
+                ));
+            }
+            Err(e) => return Err(DocxError::RawXmlError(e.to_string())),
+            _ => {}
+        }
+    }
+    if elements == 0 {
+        return Err(DocxError::RawXmlError("no element".to_owned()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_validate_raw_xml() {
+        assert!(validate_raw_xml(r#"<w:sym w:font="Wingdings" w:char="F04A" />"#).is_ok());
+        assert!(validate_raw_xml(
+            r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math"><m:r><m:t>x</m:t></m:r></m:oMath>"#
+        )
+        .is_ok());
+        assert!(validate_raw_xml("<m:oMath />").is_err());
+        assert!(validate_raw_xml("<w:t>unclosed").is_err());
+        assert!(validate_raw_xml("text").is_err());
+        assert!(validate_raw_xml("").is_err());
+        assert!(validate_raw_xml("<w:p />").is_err());
+        assert!(validate_raw_xml("<w:tbl><w:tr /></w:tbl>").is_err());
+        assert!(validate_raw_xml(
+            r#"<x:p xmlns:x="http://schemas.openxmlformats.org/wordprocessingml/2006/main" />"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/*
+  Revision save ids of a paragraph or run, i.e. w:rsidR.
+  They identify the editing session which added or last formatted the content
+  and are kept as read so merge tools can still follow them.
+*/
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Rsids {
+    pub r: Option<String>,
+    pub r_default: Option<String>,
+    pub r_pr: Option<String>,
+    pub p: Option<String>,
+    pub del: Option<String>,
+}
+
+impl Rsids {
+    pub fn new() -> Rsids {
+        Default::default()
+    }
+
+    pub fn r(mut self, v: impl Into<String>) -> Self {
+        self.r = Some(v.into());
+        self
+    }
+
+    pub fn r_default(mut self, v: impl Into<String>) -> Self {
+        self.r_default = Some(v.into());
+        self
+    }
+
+    pub fn r_pr(mut self, v: impl Into<String>) -> Self {
+        self.r_pr = Some(v.into());
+        self
+    }
+
+    pub fn p(mut self, v: impl Into<String>) -> Self {
+        self.p = Some(v.into());
+        self
+    }
+
+    pub fn del(mut self, v: impl Into<String>) -> Self {
+        self.del = Some(v.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values().is_empty()
+    }
+
+    pub(crate) fn attrs(&self) -> Vec<(&str, &str)> {
+        [
+            ("w:rsidR", &self.r),
+            ("w:rsidRDefault", &self.r_default),
+            ("w:rsidRPr", &self.r_pr),
+            ("w:rsidP", &self.p),
+            ("w:rsidDel", &self.del),
+        ]
+        .iter()
+        .filter_map(|(k, v)| v.as_ref().map(|v| (*k, v.as_str())))
+        .collect()
+    }
+
+    pub(crate) fn values(&self) -> Vec<&str> {
+        self.attrs().into_iter().map(|(_, v)| v).collect()
+    }
+}
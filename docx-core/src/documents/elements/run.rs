@@ -2,8 +2,9 @@ use super::*;
 use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 
-use crate::documents::BuildXML;
-use crate::types::BreakType;
+use crate::documents::{BuildXML, IndexEntryOptions};
+use crate::errors::DocxError;
+use crate::types::{BreakClear, BreakType, FieldCharType};
 use crate::xml_builder::*;
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
@@ -32,6 +33,11 @@ pub enum RunChild {
     Drawing(Box<Drawing>),
     CommentStart(Box<CommentRangeStart>),
     CommentEnd(CommentRangeEnd),
+    FieldChar(FieldChar),
+    InstrText(InstrText),
+    LastRenderedPageBreak(LastRenderedPageBreak),
+    // Written as it is, see add_raw_xml.
+    RawXml(String),
 }
 
 impl Serialize for RunChild {
@@ -81,6 +87,29 @@ impl Serialize for RunChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            RunChild::FieldChar(ref f) => {
+                let mut t = serializer.serialize_struct("FieldChar", 2)?;
+                t.serialize_field("type", "fieldChar")?;
+                t.serialize_field("data", f)?;
+                t.end()
+            }
+            RunChild::InstrText(ref i) => {
+                let mut t = serializer.serialize_struct("InstrText", 2)?;
+                t.serialize_field("type", "instrText")?;
+                t.serialize_field("data", i)?;
+                t.end()
+            }
+            RunChild::LastRenderedPageBreak(_) => {
+                let mut t = serializer.serialize_struct("LastRenderedPageBreak", 1)?;
+                t.serialize_field("type", "lastRenderedPageBreak")?;
+                t.end()
+            }
+            RunChild::RawXml(ref x) => {
+                let mut t = serializer.serialize_struct("RawXml", 2)?;
+                t.serialize_field("type", "rawXml")?;
+                t.serialize_field("data", x)?;
+                t.end()
+            }
         }
     }
 }
@@ -108,6 +137,15 @@ impl Run {
         self
     }
 
+    // Writes an XML fragment into the run as it is, i.e. a w:sym or a w14 extension.
+    // The fragment is checked like Paragraph::add_raw_xml.
+    pub fn add_raw_xml(&mut self, xml: impl Into<String>) -> Result<(), DocxError> {
+        let xml = xml.into();
+        validate_raw_xml(&xml)?;
+        self.children.push(RunChild::RawXml(xml));
+        Ok(())
+    }
+
     pub fn add_image(mut self, pic: Pic) -> Run {
         if pic.position_type == DrawingPositionType::Anchor {
             let pos_h = pic.position_h;
@@ -126,6 +164,12 @@ impl Run {
         self
     }
 
+    pub fn add_shape(mut self, s: Shape) -> Run {
+        self.children
+            .push(RunChild::Drawing(Box::new(Drawing::new().shape(s))));
+        self
+    }
+
     // TODO: Remove later
     pub fn add_drawing(mut self, d: Drawing) -> Run {
         self.children.push(RunChild::Drawing(Box::new(d)));
@@ -137,17 +181,91 @@ impl Run {
         self
     }
 
+    // Text wrapping break which restarts the text below floating objects on the given side.
+    pub fn add_break_clear(mut self, clear: BreakClear) -> Run {
+        self.children.push(RunChild::Break(
+            Break::new(BreakType::TextWrapping).clear(clear),
+        ));
+        self
+    }
+
+    pub fn add_last_rendered_page_break(mut self) -> Run {
+        self.children
+            .push(RunChild::LastRenderedPageBreak(LastRenderedPageBreak::new()));
+        self
+    }
+
+    pub fn add_field_char(mut self, t: FieldCharType, dirty: bool) -> Run {
+        let mut f = FieldChar::new(t);
+        if dirty {
+            f = f.dirty();
+        }
+        self.children.push(RunChild::FieldChar(f));
+        self
+    }
+
+    pub fn add_instr_text(mut self, instr: impl Into<String>) -> Run {
+        self.children
+            .push(RunChild::InstrText(InstrText::new(instr)));
+        self
+    }
+
+    // Adds a complete field without a cached result, marked dirty so that it is updated on open.
+    pub fn add_field(self, instr: impl Into<String>) -> Run {
+        self.add_field_with_dirty(instr, true)
+    }
+
+    fn add_field_with_dirty(self, instr: impl Into<String>, dirty: bool) -> Run {
+        self.add_field_char(FieldCharType::Begin, dirty)
+            .add_instr_text(instr)
+            .add_field_char(FieldCharType::Separate, false)
+            .add_field_char(FieldCharType::End, false)
+    }
+
+    // The current date with a date picture such as "MMMM d, yyyy" or "d. MMMM yyyy".
+    // The picture is written as it is, so literal text must be in single quotes as in Word.
+    pub fn add_date_field(self, format: &str, update_on_open: bool) -> Run {
+        self.add_field_with_dirty(date_time_instr("DATE", format), update_on_open)
+    }
+
+    // The current time with a time picture such as "HH:mm" or "h:mm am/pm".
+    pub fn add_time_field(self, format: &str, update_on_open: bool) -> Run {
+        self.add_field_with_dirty(date_time_instr("TIME", format), update_on_open)
+    }
+
+    pub fn add_doc_variable_field(self, name: impl Into<String>) -> Run {
+        self.add_field(format!(
+            " DOCVARIABLE {} ",
+            quote_field_argument(&name.into())
+        ))
+    }
+
+    // XE fields are hidden like Word does, so the entry should get a run of its own.
+    pub fn add_index_entry(self, text: impl Into<String>, options: IndexEntryOptions) -> Run {
+        self.vanish().add_field(options.instr(&text.into()))
+    }
+
     pub fn size(mut self, size: usize) -> Run {
         self.run_property = self.run_property.size(size);
         self
     }
 
+    pub fn size_cs(mut self, size: usize) -> Run {
+        self.run_property = self.run_property.size_cs(size);
+        self
+    }
+
     pub fn character_spacing(mut self, v: i32) -> Run {
         self.run_property = self.run_property.spacing(v);
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Run {
+    pub fn position(mut self, half_points: i32) -> Run {
+        self.run_property = self.run_property.position(half_points);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Run {
         self.run_property = self.run_property.color(color);
         self
     }
@@ -177,6 +295,11 @@ impl Run {
         self
     }
 
+    pub fn shading(mut self, s: Shading) -> Run {
+        self.run_property = self.run_property.shading(s);
+        self
+    }
+
     pub fn disable_italic(mut self) -> Run {
         self.run_property = self.run_property.disable_italic();
         self
@@ -192,21 +315,82 @@ impl Run {
         self
     }
 
+    pub fn disable_vanish(mut self) -> Run {
+        self.run_property = self.run_property.disable_vanish();
+        self
+    }
+
+    pub fn spec_vanish(mut self) -> Run {
+        self.run_property = self.run_property.spec_vanish();
+        self
+    }
+
     pub fn fonts(mut self, f: RunFonts) -> Run {
         self.run_property = self.run_property.fonts(f);
         self
     }
 
+    // Proofing language of the run, overriding the default language of the document.
+    pub fn lang(mut self, l: LangSettings) -> Run {
+        self.run_property = self.run_property.lang(l);
+        self
+    }
+
+    pub fn rsids(mut self, r: Rsids) -> Run {
+        self.run_property.rsids = r;
+        self
+    }
+
     pub(crate) fn set_property(mut self, p: RunProperty) -> Run {
         self.run_property = p;
         self
     }
+
+    // Whether the next run can be appended to this one without changing the document.
+    // The formatting must be identical, only rsids may differ. Runs holding anything
+    // but text, tabs and breaks (i.e. fields, comments or drawings) are never merged.
+    pub(crate) fn can_merge(&self, next: &Run) -> bool {
+        let plain = |r: &Run| {
+            r.children.iter().all(|c| {
+                matches!(
+                    c,
+                    RunChild::Text(_)
+                        | RunChild::Tab(_)
+                        | RunChild::Break(_)
+                        | RunChild::LastRenderedPageBreak(_)
+                )
+            })
+        };
+        let next_property = RunProperty {
+            rsids: self.run_property.rsids.clone(),
+            ..next.run_property.clone()
+        };
+        plain(self) && plain(next) && self.run_property == next_property
+    }
+
+    // Append the children of the next run, joining adjacent texts. Keeps the rsids of this run.
+    pub(crate) fn merge(&mut self, next: Run) {
+        for c in next.children {
+            match (self.children.last_mut(), c) {
+                (Some(RunChild::Text(prev)), RunChild::Text(t)) => {
+                    prev.text.push_str(&t.text);
+                    prev.preserve_space |= t.preserve_space;
+                }
+                (_, c) => self.children.push(c),
+            }
+        }
+    }
+}
+
+fn date_time_instr(field_type: &str, format: &str) -> String {
+    format!(" {} \\@ \"{}\" ", field_type, format)
 }
 
 impl BuildXML for Run {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        let mut b = b.open_run().add_child(&self.run_property);
+        let rsids = self.run_property.rsids.attrs();
+        let mut b = b.open_run_with_attrs(&rsids).add_child(&self.run_property);
         for c in &self.children {
             match c {
                 RunChild::Text(t) => b = b.add_child(t),
@@ -216,6 +400,10 @@ impl BuildXML for Run {
                 RunChild::Drawing(t) => b = b.add_child(t),
                 RunChild::CommentStart(c) => b = b.add_child(c),
                 RunChild::CommentEnd(c) => b = b.add_child(c),
+                RunChild::FieldChar(c) => b = b.add_child(c),
+                RunChild::InstrText(c) => b = b.add_child(c),
+                RunChild::LastRenderedPageBreak(c) => b = b.add_child(c),
+                RunChild::RawXml(x) => b = b.plain_text(x),
             }
         }
         b.close().build()
@@ -248,6 +436,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_break_clear() {
+        let b = Run::new().add_break_clear(BreakClear::All).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:r><w:rPr /><w:br w:type="textWrapping" w:clear="all" /></w:r>"#
+        );
+    }
+
+    #[test]
+    fn test_doc_variable_field() {
+        let b = Run::new().add_doc_variable_field("Client").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:r><w:rPr /><w:fldChar w:fldCharType="begin" w:dirty="true" /><w:instrText xml:space="preserve"> DOCVARIABLE &quot;Client&quot; </w:instrText><w:fldChar w:fldCharType="separate" /><w:fldChar w:fldCharType="end" /></w:r>"#
+        );
+    }
+
+    #[test]
+    fn test_date_field() {
+        let b = Run::new().add_date_field("MMMM d, yyyy", false).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:r><w:rPr /><w:fldChar w:fldCharType="begin" /><w:instrText xml:space="preserve"> DATE \@ &quot;MMMM d, yyyy&quot; </w:instrText><w:fldChar w:fldCharType="separate" /><w:fldChar w:fldCharType="end" /></w:r>"#
+        );
+    }
+
     #[test]
     fn test_child_json() {
         let c = RunChild::Text(Text::new("Hello"));
@@ -283,7 +498,7 @@ mod tests {
         };
         assert_eq!(
             serde_json::to_string(&run).unwrap(),
-            r#"{"runProperty":{"sz":30,"szCs":30,"color":"C9211E","highlight":"yellow","vertAlign":null,"underline":"single","bold":true,"boldCs":true,"italic":true,"italicCs":true,"vanish":true,"characterSpacing":100,"fonts":null,"textBorder":null,"del":null,"ins":null},"children":[{"type":"tab"},{"type":"text","data":{"preserveSpace":true,"text":"Hello"}},{"type":"break","data":{"breakType":"page"}},{"type":"deleteText","data":{"text":"deleted","preserveSpace":true}}]}"#,
+            r#"{"runProperty":{"sz":30,"szCs":30,"color":"C9211E","highlight":"yellow","vertAlign":null,"underline":"single","bold":true,"boldCs":true,"italic":true,"italicCs":true,"vanish":true,"specVanish":null,"characterSpacing":100,"position":null,"fonts":null,"textBorder":null,"shading":null,"del":null,"ins":null,"lang":null,"propertyChange":null},"children":[{"type":"tab"},{"type":"text","data":{"preserveSpace":true,"text":"Hello"}},{"type":"break","data":{"breakType":"page","clear":null}},{"type":"deleteText","data":{"text":"deleted","preserveSpace":true}}]}"#,
         );
     }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::documents::BuildXML;
+use crate::types::FontHintType;
 use crate::xml_builder::*;
 
 /*
@@ -10,6 +11,9 @@ use crate::xml_builder::*;
   - ASCII (i.e., the first 128 Unicode code points)
   - High ANSI
   - Complex Script
+  - East Asian
+  Each slot may name a theme font (e.g. minorHAnsi) instead of an explicit font,
+  and the hint selects the slot used for characters which could belong to several of them.
 */
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct RunFonts {
@@ -17,6 +21,11 @@ pub struct RunFonts {
     hi_ansi: Option<String>,
     east_asia: Option<String>,
     cs: Option<String>,
+    ascii_theme: Option<String>,
+    hi_ansi_theme: Option<String>,
+    east_asia_theme: Option<String>,
+    cs_theme: Option<String>,
+    hint: Option<FontHintType>,
 }
 
 impl RunFonts {
@@ -43,18 +52,51 @@ impl RunFonts {
         self.cs = Some(f.into());
         self
     }
+
+    pub fn ascii_theme(mut self, f: impl Into<String>) -> Self {
+        self.ascii_theme = Some(f.into());
+        self
+    }
+
+    pub fn hi_ansi_theme(mut self, f: impl Into<String>) -> Self {
+        self.hi_ansi_theme = Some(f.into());
+        self
+    }
+
+    pub fn east_asia_theme(mut self, f: impl Into<String>) -> Self {
+        self.east_asia_theme = Some(f.into());
+        self
+    }
+
+    pub fn cs_theme(mut self, f: impl Into<String>) -> Self {
+        self.cs_theme = Some(f.into());
+        self
+    }
+
+    pub fn hint(mut self, h: FontHintType) -> Self {
+        self.hint = Some(h);
+        self
+    }
 }
 
 impl BuildXML for RunFonts {
     fn build(&self) -> Vec<u8> {
-        let b = XMLBuilder::new();
-        b.run_fonts(
-            self.ascii.as_ref(),
-            self.hi_ansi.as_ref(),
-            self.cs.as_ref(),
-            self.east_asia.as_ref(),
-        )
-        .build()
+        let hint = self.hint.map(|h| h.to_string());
+        let attrs: Vec<(&str, &str)> = [
+            ("w:ascii", &self.ascii),
+            ("w:hAnsi", &self.hi_ansi),
+            ("w:cs", &self.cs),
+            ("w:eastAsia", &self.east_asia),
+            ("w:asciiTheme", &self.ascii_theme),
+            ("w:hAnsiTheme", &self.hi_ansi_theme),
+            ("w:cstheme", &self.cs_theme),
+            ("w:eastAsiaTheme", &self.east_asia_theme),
+            ("w:hint", &hint),
+        ]
+        .iter()
+        .filter_map(|(k, v)| v.as_ref().map(|v| (*k, v.as_str())))
+        .collect();
+        XMLBuilder::new().run_fonts(&attrs).build()
     }
 }
 
@@ -100,9 +142,20 @@ mod tests {
     fn test_run_fonts_cs_build() {
         let c = RunFonts::new().cs("Arial");
         let b = c.build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:rFonts w:cs="Arial" />"#);
+    }
+
+    #[test]
+    fn test_run_fonts_hint_and_theme_build() {
+        let c = RunFonts::new()
+            .cs("Arial")
+            .ascii_theme("minorHAnsi")
+            .cs_theme("minorBidi")
+            .hint(FontHintType::Cs);
+        let b = c.build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
-            r#"<w:rFonts w:cs="Arial" />"#
+            r#"<w:rFonts w:cs="Arial" w:asciiTheme="minorHAnsi" w:cstheme="minorBidi" w:hint="cs" />"#
         );
     }
 }
@@ -19,11 +19,19 @@ pub struct RunProperty {
     pub italic: Option<Italic>,
     pub italic_cs: Option<ItalicCs>,
     pub vanish: Option<Vanish>,
+    pub spec_vanish: Option<SpecVanish>,
     pub character_spacing: Option<CharacterSpacing>,
+    pub position: Option<Position>,
     pub fonts: Option<RunFonts>,
     pub text_border: Option<TextBorder>,
+    pub shading: Option<Shading>,
     pub del: Option<Delete>,
     pub ins: Option<Insert>,
+    pub lang: Option<LangSettings>,
+    pub property_change: Option<RunPropertyChange>,
+    // Written on the run itself rather than in rPr.
+    #[serde(skip)]
+    pub rsids: Rsids,
 }
 
 impl RunProperty {
@@ -37,13 +45,24 @@ impl RunProperty {
         self
     }
 
-    pub fn spacing(mut self, spacing: i32) -> RunProperty {
-        self.character_spacing = Some(CharacterSpacing::new(spacing));
+    // Size of complex script text, overriding the one set by size.
+    pub fn size_cs(mut self, size: usize) -> RunProperty {
+        self.sz_cs = Some(SzCs::new(size));
+        self
+    }
+
+    pub fn spacing(mut self, spacing: impl Into<Twip>) -> RunProperty {
+        self.character_spacing = Some(CharacterSpacing::new(spacing.into().0));
+        self
+    }
+
+    pub fn position(mut self, half_points: i32) -> RunProperty {
+        self.position = Some(Position::new(half_points));
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> RunProperty {
-        self.color = Some(Color::new(color));
+    pub fn color(mut self, color: impl Into<Color>) -> RunProperty {
+        self.color = Some(color.into());
         self
     }
 
@@ -91,16 +110,41 @@ impl RunProperty {
         self
     }
 
+    pub fn disable_vanish(mut self) -> RunProperty {
+        self.vanish = Some(Vanish::new().disable());
+        self
+    }
+
+    pub fn spec_vanish(mut self) -> RunProperty {
+        self.spec_vanish = Some(SpecVanish::new());
+        self
+    }
+
+    pub fn disable_spec_vanish(mut self) -> RunProperty {
+        self.spec_vanish = Some(SpecVanish::new().disable());
+        self
+    }
+
     pub fn fonts(mut self, font: RunFonts) -> RunProperty {
         self.fonts = Some(font);
         self
     }
 
+    pub fn lang(mut self, l: LangSettings) -> RunProperty {
+        self.lang = Some(l);
+        self
+    }
+
     pub fn text_border(mut self, b: TextBorder) -> Self {
         self.text_border = Some(b);
         self
     }
 
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.shading = Some(s);
+        self
+    }
+
     pub fn delete(mut self, d: Delete) -> Self {
         self.del = Some(d);
         self
@@ -110,6 +154,12 @@ impl RunProperty {
         self.ins = Some(i);
         self
     }
+
+    // Tracks a formatting change, c holding the property before it.
+    pub fn property_change(mut self, c: RunPropertyChange) -> Self {
+        self.property_change = Some(c);
+        self
+    }
 }
 
 impl Default for RunProperty {
@@ -126,11 +176,17 @@ impl Default for RunProperty {
             italic: None,
             italic_cs: None,
             vanish: None,
+            spec_vanish: None,
             fonts: None,
             character_spacing: None,
+            position: None,
             text_border: None,
+            shading: None,
             del: None,
             ins: None,
+            lang: None,
+            property_change: None,
+            rsids: Rsids::new(),
         }
     }
 }
@@ -151,10 +207,15 @@ impl BuildXML for RunProperty {
             .add_optional_child(&self.vanish)
             .add_optional_child(&self.fonts)
             .add_optional_child(&self.text_border)
+            .add_optional_child(&self.shading)
             .add_optional_child(&self.ins)
             .add_optional_child(&self.del)
             .add_optional_child(&self.vert_align)
             .add_optional_child(&self.character_spacing)
+            .add_optional_child(&self.position)
+            .add_optional_child(&self.lang)
+            .add_optional_child(&self.spec_vanish)
+            .add_optional_child(&self.property_change)
             .close()
             .build()
     }
@@ -178,6 +239,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_size_cs() {
+        let c = RunProperty::new().size(20).size_cs(28);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:sz w:val="20" /><w:szCs w:val="28" /></w:rPr>"#
+        );
+    }
+
     #[test]
     fn test_highlight() {
         let c = RunProperty::new().highlight("FFFFFF");
@@ -218,6 +289,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_spec_vanish() {
+        let c = RunProperty::new().disable_vanish().spec_vanish();
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:vanish w:val="false" /><w:specVanish /></w:rPr>"#
+        );
+    }
+
     #[test]
     fn test_run_fonts() {
         let c = RunProperty::new().fonts(RunFonts::new().east_asia("Hiragino"));
@@ -236,4 +317,14 @@ mod tests {
             r#"<w:rPr><w:spacing w:val="20" /></w:rPr>"#
         );
     }
+
+    #[test]
+    fn test_position() {
+        let c = RunProperty::new().position(-4);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:position w:val="-4" /></w:rPr>"#
+        );
+    }
 }
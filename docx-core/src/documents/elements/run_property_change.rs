@@ -0,0 +1,65 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::xml_builder::*;
+
+// A tracked formatting change, recording the run property before the change.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RunPropertyChange {
+    pub author: String,
+    pub date: String,
+    pub property: Box<RunProperty>,
+}
+
+impl RunPropertyChange {
+    pub fn new(property: RunProperty) -> RunPropertyChange {
+        RunPropertyChange {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            property: Box::new(property),
+        }
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> RunPropertyChange {
+        self.author = author.into();
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> RunPropertyChange {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for RunPropertyChange {}
+
+impl BuildXML for RunPropertyChange {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .open_run_property_change(&self.generate(), &self.author, &self.date)
+            .add_child(self.property.as_ref())
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_run_property_change() {
+        let b = RunProperty::new()
+            .bold()
+            .property_change(RunPropertyChange::new(RunProperty::new().italic()).author("bokuweb"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:b /><w:bCs /><w:rPrChange w:id="123" w:author="bokuweb" w:date="1970-01-01T00:00:00Z"><w:rPr><w:i /><w:iCs /></w:rPr></w:rPrChange></w:rPr>"#
+        );
+    }
+}
@@ -2,6 +2,7 @@ use serde::Serialize;
 
 use super::*;
 use crate::documents::BuildXML;
+use crate::types::Twip;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -20,7 +21,7 @@ impl RunPropertyDefault {
         self
     }
 
-    pub fn spacing(mut self, spacing: i32) -> Self {
+    pub fn spacing(mut self, spacing: impl Into<Twip>) -> Self {
         self.run_property = self.run_property.spacing(spacing);
         self
     }
@@ -30,10 +31,19 @@ impl RunPropertyDefault {
         self
     }
 
+    pub fn lang(mut self, l: LangSettings) -> Self {
+        self.run_property = self.run_property.lang(l);
+        self
+    }
+
     pub(crate) fn run_property(mut self, p: RunProperty) -> Self {
         self.run_property = p;
         self
     }
+
+    pub(crate) fn get_run_property(&self) -> &RunProperty {
+        &self.run_property
+    }
 }
 
 impl Default for RunPropertyDefault {
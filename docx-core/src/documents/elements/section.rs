@@ -1,5 +1,6 @@
 use super::*;
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, Footer, Header};
+use crate::types::*;
 use crate::xml_builder::*;
 
 use serde::Serialize;
@@ -7,19 +8,104 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Section {
-    property: SectionProperty,
+    pub(crate) property: SectionProperty,
+    pub(crate) balance_columns: bool,
+    pub(crate) header_links: Vec<(HeaderFooterReferenceType, bool)>,
+    pub(crate) headers: Vec<(HeaderFooterReferenceType, Header)>,
+    pub(crate) footer_links: Vec<(HeaderFooterReferenceType, bool)>,
+    pub(crate) footers: Vec<(HeaderFooterReferenceType, Footer)>,
 }
 
 impl Section {
     pub fn new() -> Section {
         Default::default()
     }
+
+    pub fn property(mut self, p: SectionProperty) -> Self {
+        self.property = p;
+        self
+    }
+
+    pub fn columns(mut self, num: usize) -> Self {
+        self.property = self.property.columns(num);
+        self
+    }
+
+    // Balance the columns on the last page of this section.
+    // Unless the following section starts continuously, an empty continuous section is
+    // inserted after this one to do so.
+    pub fn balance_columns(mut self, v: bool) -> Self {
+        self.balance_columns = v;
+        self
+    }
+
+    // Link or unlink both the header and the footer of the type.
+    pub fn link_to_previous(self, t: HeaderFooterReferenceType, linked: bool) -> Self {
+        self.link_header_to_previous(t, linked)
+            .link_footer_to_previous(t, linked)
+    }
+
+    // A linked section omits the header reference so Word reuses the one of the previous section.
+    // An unlinked section gets its own header part, which is empty unless set with `header`.
+    pub fn link_header_to_previous(mut self, t: HeaderFooterReferenceType, linked: bool) -> Self {
+        self.clear_blank_first_page(t);
+        self.header_links.retain(|(l, _)| *l != t);
+        self.header_links.push((t, linked));
+        self
+    }
+
+    // Same as `link_header_to_previous` for the footer, which is set with `footer`.
+    pub fn link_footer_to_previous(mut self, t: HeaderFooterReferenceType, linked: bool) -> Self {
+        self.clear_blank_first_page(t);
+        self.footer_links.retain(|(l, _)| *l != t);
+        self.footer_links.push((t, linked));
+        self
+    }
+
+    // The first page is no longer blank once its header or footer is linked or set.
+    fn clear_blank_first_page(&mut self, t: HeaderFooterReferenceType) {
+        if t == HeaderFooterReferenceType::First && self.property.has_blank_first_page() {
+            self.property = std::mem::take(&mut self.property).blank_first_page(false);
+        }
+    }
+
+    // Blank header and footer on the first page, i.e. for cover pages. The first page refers to
+    // empty header and footer parts, so it does not inherit those of the previous section.
+    pub fn no_first_page_header(mut self) -> Self {
+        self.headers
+            .retain(|(l, _)| *l != HeaderFooterReferenceType::First);
+        self.header_links
+            .retain(|(l, _)| *l != HeaderFooterReferenceType::First);
+        self.footers
+            .retain(|(l, _)| *l != HeaderFooterReferenceType::First);
+        self.footer_links
+            .retain(|(l, _)| *l != HeaderFooterReferenceType::First);
+        self.property = self.property.blank_first_page(true);
+        self
+    }
+
+    pub fn header(mut self, t: HeaderFooterReferenceType, h: Header) -> Self {
+        self.headers.retain(|(l, _)| *l != t);
+        self.headers.push((t, h));
+        self.link_header_to_previous(t, false)
+    }
+
+    pub fn footer(mut self, t: HeaderFooterReferenceType, f: Footer) -> Self {
+        self.footers.retain(|(l, _)| *l != t);
+        self.footers.push((t, f));
+        self.link_footer_to_previous(t, false)
+    }
 }
 
 impl Default for Section {
     fn default() -> Self {
         Self {
             property: SectionProperty::new(),
+            balance_columns: false,
+            header_links: vec![],
+            headers: vec![],
+            footer_links: vec![],
+            footers: vec![],
         }
     }
 }
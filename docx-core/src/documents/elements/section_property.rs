@@ -11,9 +11,19 @@ pub struct SectionProperty {
     page_size: PageSize,
     page_margin: PageMargin,
     columns: usize,
+    column_num: usize,
     doc_grid: DocGrid,
-    header_reference: HeaderReference,
+    header_references: Vec<HeaderReference>,
+    footer_references: Vec<FooterReference>,
     section_type: Option<SectionType>,
+    // The first page uses the first page header and footer. It is also set by a first page reference.
+    title_pg: bool,
+    // The first page refers to empty first page header and footer parts.
+    blank_first_page: bool,
+    // First page header and footer references of a read section, which are
+    // only kept to recognize a blank first page.
+    #[serde(skip)]
+    pub(crate) first_page_rids: Vec<String>,
 }
 
 impl SectionProperty {
@@ -40,6 +50,92 @@ impl SectionProperty {
         self.doc_grid = doc_grid;
         self
     }
+
+    pub fn columns(mut self, num: usize) -> Self {
+        self.column_num = num;
+        self
+    }
+
+    pub fn column_space(mut self, space: usize) -> Self {
+        self.columns = space;
+        self
+    }
+
+    pub fn section_type(mut self, t: SectionType) -> Self {
+        self.section_type = Some(t);
+        self
+    }
+
+    pub fn title_pg(mut self, v: bool) -> Self {
+        self.title_pg = v;
+        self
+    }
+
+    // A first page header or footer is only shown with titlePg, so a first page reference implies it.
+    pub fn has_title_pg(&self) -> bool {
+        let first = HeaderFooterReferenceType::First.to_string();
+        self.title_pg
+            || self
+                .header_references
+                .iter()
+                .any(|h| h.header_type == first)
+            || self
+                .footer_references
+                .iter()
+                .any(|f| f.footer_type == first)
+    }
+
+    // Neither header nor footer on the first page, i.e. for cover pages.
+    // Building writes empty first page parts, so nothing is inherited from the previous section.
+    pub fn blank_first_page(mut self, v: bool) -> Self {
+        self.title_pg = v;
+        self.blank_first_page = v;
+        self
+    }
+
+    pub fn has_blank_first_page(&self) -> bool {
+        self.blank_first_page
+    }
+
+    // Whether the section starts on a new page, which it does unless the type says otherwise.
+    pub(crate) fn starts_new_page(&self) -> bool {
+        !matches!(
+            self.section_type,
+            Some(SectionType::Continuous) | Some(SectionType::NextColumn)
+        )
+    }
+
+    pub(crate) fn is_continuous(&self) -> bool {
+        self.section_type == Some(SectionType::Continuous)
+    }
+
+    // Replace the reference of the same type, if any.
+    pub(crate) fn header_reference(mut self, r: HeaderReference) -> Self {
+        self.header_references
+            .retain(|h| h.header_type != r.header_type);
+        self.header_references.push(r);
+        self
+    }
+
+    pub(crate) fn remove_header_reference(mut self, t: HeaderFooterReferenceType) -> Self {
+        let t = t.to_string();
+        self.header_references.retain(|h| h.header_type != t);
+        self
+    }
+
+    // Replace the reference of the same type, if any.
+    pub(crate) fn footer_reference(mut self, r: FooterReference) -> Self {
+        self.footer_references
+            .retain(|f| f.footer_type != r.footer_type);
+        self.footer_references.push(r);
+        self
+    }
+
+    pub(crate) fn remove_footer_reference(mut self, t: HeaderFooterReferenceType) -> Self {
+        let t = t.to_string();
+        self.footer_references.retain(|f| f.footer_type != t);
+        self
+    }
 }
 
 impl Default for SectionProperty {
@@ -48,9 +144,14 @@ impl Default for SectionProperty {
             page_size: PageSize::new(),
             page_margin: PageMargin::new(),
             columns: 425,
+            column_num: 1,
             doc_grid: DocGrid::default(),
-            header_reference: HeaderReference::default(),
+            header_references: vec![HeaderReference::default()],
+            footer_references: vec![],
             section_type: None,
+            title_pg: false,
+            blank_first_page: false,
+            first_page_rids: vec![],
         }
     }
 }
@@ -62,9 +163,23 @@ impl BuildXML for SectionProperty {
             .open_section_property()
             .add_child(&self.page_size)
             .add_child(&self.page_margin)
-            .add_child(&self.header_reference)
-            .columns(&format!("{}", &self.columns))
-            .add_child(&self.doc_grid);
+            .add_children(&self.header_references)
+            .add_children(&self.footer_references);
+
+        if self.column_num > 1 {
+            b = b.columns_with_num(
+                &format!("{}", &self.columns),
+                &format!("{}", &self.column_num),
+            );
+        } else {
+            b = b.columns(&format!("{}", &self.columns));
+        }
+
+        if self.has_title_pg() {
+            b = b.title_pg();
+        }
+
+        b = b.add_child(&self.doc_grid);
 
         if let Some(t) = self.section_type {
             b = b.type_tag(&t.to_string());
@@ -90,4 +205,36 @@ mod tests {
             r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:headerReference w:type="default" r:id="rId4" /><w:cols w:space="425" /><w:docGrid w:type="lines" w:linePitch="360" /></w:sectPr>"#
         );
     }
+
+    #[test]
+    fn test_section_property_with_columns() {
+        let c = SectionProperty::new()
+            .columns(2)
+            .section_type(SectionType::Continuous);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:headerReference w:type="default" r:id="rId4" /><w:cols w:space="425" w:num="2" /><w:docGrid w:type="lines" w:linePitch="360" /><w:type w:val="continuous" />
+</w:sectPr>"#
+        );
+    }
+
+    #[test]
+    fn test_title_pg_from_first_reference() {
+        let first = HeaderFooterReferenceType::First.to_string();
+        let c = SectionProperty::new().header_reference(HeaderReference::new(first, "rIdHeader2"));
+        assert!(c.has_title_pg());
+        assert!(str::from_utf8(&c.build())
+            .unwrap()
+            .contains("<w:titlePg />"));
+    }
+
+    #[test]
+    fn test_clear_blank_first_page() {
+        let c = SectionProperty::new()
+            .blank_first_page(true)
+            .blank_first_page(false);
+        assert!(!c.has_title_pg());
+        assert!(!str::from_utf8(&c.build()).unwrap().contains("titlePg"));
+    }
 }
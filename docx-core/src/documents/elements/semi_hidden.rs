@@ -0,0 +1,49 @@
+use serde::{Serialize, Serializer};
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// 17.7.4.16
+// semiHidden (Hide Style From Main User Interface)
+// This element specifies whether this style shall be hidden from the main user interface when
+// this document is loaded by an application.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SemiHidden {}
+
+impl SemiHidden {
+    pub fn new() -> SemiHidden {
+        Default::default()
+    }
+}
+
+impl Serialize for SemiHidden {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(true)
+    }
+}
+
+impl BuildXML for SemiHidden {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.semi_hidden().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_semi_hidden() {
+        let c = SemiHidden::new();
+        let b = c.build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:semiHidden />"#);
+    }
+}
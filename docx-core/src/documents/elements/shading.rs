@@ -1,24 +1,24 @@
 use serde::Serialize;
 
+use super::Color;
 use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
 
-// INFO: Theme is not supported now.
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Shading {
     pub shd_type: ShdType,
-    pub color: String,
-    pub fill: String,
+    pub color: Color,
+    pub fill: Color,
 }
 
 impl Default for Shading {
     fn default() -> Self {
         Shading {
             shd_type: ShdType::Clear,
-            color: "auto".to_owned(),
-            fill: "FFFFFF".to_owned(),
+            color: Color::Auto,
+            fill: Color::hex("FFFFFF"),
         }
     }
 }
@@ -28,12 +28,12 @@ impl Shading {
         Shading::default()
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Shading {
+    pub fn color(mut self, color: impl Into<Color>) -> Shading {
         self.color = color.into();
         self
     }
 
-    pub fn fill(mut self, fill: impl Into<String>) -> Shading {
+    pub fn fill(mut self, fill: impl Into<Color>) -> Shading {
         self.fill = fill.into();
         self
     }
@@ -46,8 +46,17 @@ impl Shading {
 
 impl BuildXML for Shading {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
-            .shd(&self.shd_type.to_string(), &self.color, &self.fill)
-            .build()
+        let mut attrs = vec![("w:val".to_owned(), self.shd_type.to_string())];
+        attrs.extend(
+            self.color
+                .attrs(("w:color", "w:themeColor", "w:themeTint", "w:themeShade")),
+        );
+        attrs.extend(self.fill.attrs((
+            "w:fill",
+            "w:themeFill",
+            "w:themeFillTint",
+            "w:themeFillShade",
+        )));
+        XMLBuilder::new().shd(&attrs).build()
     }
 }
@@ -0,0 +1,161 @@
+use super::*;
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::types::{emu, ShapeType};
+use crate::xml_builder::*;
+
+/*
+  An inline preset shape such as a rectangle, an ellipse or an arrow,
+  optionally holding text. Used to draw flowcharts and callouts.
+*/
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Shape {
+    pub id: usize,
+    pub geometry: ShapeType,
+    // (w, h) in px
+    pub size: (u32, u32),
+    pub fill: Option<String>,
+    // (color, width in px)
+    pub line: Option<(String, u32)>,
+    pub content: TextBoxContent,
+}
+
+impl Shape {
+    pub fn new(geometry: ShapeType, w: u32, h: u32) -> Shape {
+        Shape {
+            id: generate_pic_id(),
+            geometry,
+            size: (w, h),
+            fill: None,
+            line: None,
+            content: TextBoxContent::new(),
+        }
+    }
+
+    pub fn fill(mut self, color: impl Into<String>) -> Shape {
+        self.fill = Some(color.into());
+        self
+    }
+
+    pub fn line(mut self, color: impl Into<String>, width: u32) -> Shape {
+        self.line = Some((color.into(), width));
+        self
+    }
+
+    pub fn add_paragraph(mut self, p: Paragraph) -> Shape {
+        self.content = self.content.add_paragraph(p);
+        self
+    }
+}
+
+// Writes the wps:spPr shared by preset shapes and text boxes.
+pub(crate) fn build_shape_property(
+    b: XMLBuilder,
+    geometry: ShapeType,
+    size: (u32, u32),
+    fill: &Option<String>,
+    line: &Option<(String, u32)>,
+) -> XMLBuilder {
+    let w = format!("{}", emu::from_px(size.0));
+    let h = format!("{}", emu::from_px(size.1));
+    let mut b = b
+        .open_wps_sp_pr()
+        .open_a_xfrm()
+        .a_off("0", "0")
+        .a_ext(&w, &h)
+        .close()
+        .open_a_prst_geom(&geometry.to_string())
+        .a_av_lst()
+        .close();
+    if let Some(fill) = fill {
+        b = b.open_a_solid_fill().a_srgb_clr(fill).close();
+    }
+    if let Some((color, width)) = line {
+        let width = format!("{}", emu::from_px(*width));
+        b = b
+            .open_a_ln(&width)
+            .open_a_solid_fill()
+            .a_srgb_clr(color)
+            .close()
+            .close();
+    }
+    b.close()
+}
+
+impl BuildXML for Shape {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new().open_wp_shape().wps_shape_c_nv_sp_pr();
+        let mut b = build_shape_property(b, self.geometry, self.size, &self.fill, &self.line);
+        if !self.content.children.is_empty() {
+            b = b.open_wp_text_box().add_child(&self.content).close();
+        }
+        b.wps_body_pr("square", "ctr").close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_shape_build() {
+        let b = Shape::new(ShapeType::RoundRect, 100, 50)
+            .fill("4472C4")
+            .line("2F528F", 1)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<wps:wsp>
+  <wps:cNvSpPr />
+  <wps:spPr>
+    <a:xfrm>
+      <a:off x="0" y="0" />
+      <a:ext cx="952500" cy="476250" />
+    </a:xfrm>
+    <a:prstGeom prst="roundRect">
+      <a:avLst />
+    </a:prstGeom>
+    <a:solidFill>
+      <a:srgbClr val="4472C4" />
+    </a:solidFill>
+    <a:ln w="9525">
+      <a:solidFill>
+        <a:srgbClr val="2F528F" />
+      </a:solidFill>
+    </a:ln>
+  </wps:spPr>
+  <wps:bodyPr wrap="square" anchor="ctr" />
+</wps:wsp>"#
+        );
+    }
+
+    #[test]
+    fn test_shape_with_text_build() {
+        let b = Shape::new(ShapeType::RightArrow, 100, 50)
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Next")))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<wps:wsp>
+  <wps:cNvSpPr />
+  <wps:spPr>
+    <a:xfrm>
+      <a:off x="0" y="0" />
+      <a:ext cx="952500" cy="476250" />
+    </a:xfrm>
+    <a:prstGeom prst="rightArrow">
+      <a:avLst />
+    </a:prstGeom>
+  </wps:spPr>
+  <wps:txbx><w:txbxContent><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">Next</w:t></w:r></w:p></w:txbxContent></wps:txbx>
+  <wps:bodyPr wrap="square" anchor="ctr" />
+</wps:wsp>"#
+        );
+    }
+}
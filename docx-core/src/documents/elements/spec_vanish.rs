@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+/*
+  17.3.2.36 specVanish (Paragraph Mark Is Always Hidden)
+  Hides the run even when hidden text is displayed, e.g. to join a heading with the following paragraph.
+*/
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SpecVanish {
+    val: bool,
+}
+
+impl SpecVanish {
+    pub fn new() -> SpecVanish {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> SpecVanish {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for SpecVanish {
+    fn default() -> Self {
+        SpecVanish { val: true }
+    }
+}
+
+impl BuildXML for SpecVanish {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        if self.val {
+            b.spec_vanish().build()
+        } else {
+            b.spec_vanish_with_val("false").build()
+        }
+    }
+}
+
+impl Serialize for SpecVanish {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let c = SpecVanish::new();
+        let b = c.build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:specVanish />"#);
+    }
+
+    #[test]
+    fn test_build_disabled() {
+        let c = SpecVanish::new().disable();
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:specVanish w:val="false" />"#
+        );
+    }
+}
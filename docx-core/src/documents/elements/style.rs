@@ -18,6 +18,11 @@ pub struct Style {
     pub table_property: TableProperty,
     pub table_cell_property: TableCellProperty,
     pub based_on: Option<BasedOn>,
+    pub next: Option<Next>,
+    pub ui_priority: Option<UiPriority>,
+    pub semi_hidden: Option<SemiHidden>,
+    pub unhide_when_used: Option<UnhideWhenUsed>,
+    pub q_format: Option<QFormat>,
 }
 
 impl Default for Style {
@@ -34,6 +39,11 @@ impl Default for Style {
             table_property: TableProperty::new(),
             table_cell_property: TableCellProperty::new(),
             based_on: None,
+            next: None,
+            ui_priority: None,
+            semi_hidden: None,
+            unhide_when_used: None,
+            q_format: None,
         }
     }
 }
@@ -58,12 +68,42 @@ impl Style {
         self
     }
 
+    pub fn next(mut self, next: impl Into<String>) -> Self {
+        self.next = Some(Next::new(next));
+        self
+    }
+
+    pub fn ui_priority(mut self, priority: usize) -> Self {
+        self.ui_priority = Some(UiPriority::new(priority));
+        self
+    }
+
+    pub fn semi_hidden(mut self) -> Self {
+        self.semi_hidden = Some(SemiHidden::new());
+        self
+    }
+
+    pub fn unhide_when_used(mut self) -> Self {
+        self.unhide_when_used = Some(UnhideWhenUsed::new());
+        self
+    }
+
+    pub fn q_format(mut self) -> Self {
+        self.q_format = Some(QFormat::new());
+        self
+    }
+
     pub fn size(mut self, size: usize) -> Self {
         self.run_property = self.run_property.size(size);
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Self {
+    pub fn size_cs(mut self, size: usize) -> Self {
+        self.run_property = self.run_property.size_cs(size);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
         self.run_property = self.run_property.color(color);
         self
     }
@@ -93,6 +133,16 @@ impl Style {
         self
     }
 
+    pub fn line_spacing(mut self, s: LineSpacing) -> Self {
+        self.paragraph_property = self.paragraph_property.spacing(s);
+        self
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.contextual_spacing(v);
+        self
+    }
+
     pub fn align(mut self, alignment_type: AlignmentType) -> Self {
         self.paragraph_property = self.paragraph_property.align(alignment_type);
         self
@@ -100,9 +150,9 @@ impl Style {
 
     pub fn indent(
         mut self,
-        left: Option<i32>,
+        left: Option<Twip>,
         special_indent: Option<SpecialIndentType>,
-        end: Option<i32>,
+        end: Option<Twip>,
         start_chars: Option<i32>,
     ) -> Self {
         self.paragraph_property =
@@ -121,6 +171,13 @@ impl Style {
         self
     }
 
+    // Link a numbering style to the numbering instance whose abstract numbering has the style link.
+    pub fn numbering(mut self, id: usize) -> Self {
+        self.paragraph_property.numbering_property =
+            Some(NumberingProperty::new().id(NumberingId::new(id)));
+        self
+    }
+
     pub fn table_property(mut self, p: TableProperty) -> Self {
         self.table_property = p;
         self
@@ -135,15 +192,16 @@ impl Style {
 impl BuildXML for Style {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        // Set "Normal" as default if you need change these values please fix it
         b.open_style(self.style_type, &self.style_id)
             .add_child(&self.name)
-            .add_child(&self.run_property)
-            .add_child(&self.paragraph_property)
-            .add_child(&BasedOn::new("Normal"))
-            .add_child(&Next::new("Normal"))
-            .add_child(&QFormat::new())
             .add_optional_child(&self.based_on)
+            .add_optional_child(&self.next)
+            .add_optional_child(&self.ui_priority)
+            .add_optional_child(&self.semi_hidden)
+            .add_optional_child(&self.unhide_when_used)
+            .add_optional_child(&self.q_format)
+            .add_child(&self.paragraph_property)
+            .add_child(&self.run_property)
             .close()
             .build()
     }
@@ -163,7 +221,36 @@ mod tests {
         let b = c.build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
-            r#"<w:style w:type="paragraph" w:styleId="Heading"><w:name w:val="Heading1" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:basedOn w:val="Normal" /><w:next w:val="Normal" /><w:qFormat /></w:style>"#
+            r#"<w:style w:type="paragraph" w:styleId="Heading"><w:name w:val="Heading1" /><w:pPr><w:rPr /></w:pPr><w:rPr /></w:style>"#
+        );
+    }
+
+    #[test]
+    fn test_build_numbering_style() {
+        let c = Style::new("ListStyle", StyleType::Numbering)
+            .name("List Style")
+            .numbering(1);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:style w:type="numbering" w:styleId="ListStyle"><w:name w:val="List Style" /><w:pPr><w:rPr /><w:numPr><w:numId w:val="1" /></w:numPr></w:pPr><w:rPr /></w:style>"#
+        );
+    }
+
+    #[test]
+    fn test_build_with_ui_metadata() {
+        let c = Style::new("Heading1", StyleType::Paragraph)
+            .name("Heading 1")
+            .based_on("Normal")
+            .next("Normal")
+            .ui_priority(9)
+            .semi_hidden()
+            .unhide_when_used()
+            .q_format();
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="Heading 1" /><w:basedOn w:val="Normal" /><w:next w:val="Normal" /><w:uiPriority w:val="9" /><w:semiHidden /><w:unhideWhenUsed /><w:qFormat /><w:pPr><w:rPr /></w:pPr><w:rPr /></w:style>"#
         );
     }
 }
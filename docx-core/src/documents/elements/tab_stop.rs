@@ -0,0 +1,71 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// A custom tab stop in <w:tabs> of paragraph properties.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TabStop {
+    pub val: TabValueType,
+    pub pos: usize,
+    pub leader: Option<TabLeaderType>,
+}
+
+impl TabStop {
+    pub fn new(val: TabValueType, pos: usize) -> TabStop {
+        Self {
+            val,
+            pos,
+            leader: None,
+        }
+    }
+
+    pub fn leader(mut self, l: TabLeaderType) -> Self {
+        self.leader = Some(l);
+        self
+    }
+}
+
+impl BuildXML for TabStop {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        let pos = format!("{}", self.pos);
+        if let Some(leader) = self.leader {
+            b.tab_stop_with_leader(&self.val.to_string(), &leader.to_string(), &pos)
+                .build()
+        } else {
+            b.tab_stop(&self.val.to_string(), &pos).build()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_tab_stop() {
+        let b = TabStop::new(TabValueType::Num, 720).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tab w:val="num" w:pos="720" />"#
+        );
+    }
+
+    #[test]
+    fn test_tab_stop_with_leader() {
+        let b = TabStop::new(TabValueType::Right, 9000)
+            .leader(TabLeaderType::Dot)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tab w:val="right" w:leader="dot" w:pos="9000" />"#
+        );
+    }
+}
@@ -49,7 +49,7 @@ impl Table {
         self
     }
 
-    pub fn indent(mut self, v: i32) -> Table {
+    pub fn indent(mut self, v: impl Into<Twip>) -> Table {
         self.property = self.property.indent(v);
         self
     }
@@ -69,6 +69,16 @@ impl Table {
         self
     }
 
+    pub fn caption(mut self, v: impl Into<String>) -> Table {
+        self.property = self.property.caption(v);
+        self
+    }
+
+    pub fn description(mut self, v: impl Into<String>) -> Table {
+        self.property = self.property.description(v);
+        self
+    }
+
     pub fn width(mut self, w: usize, t: WidthType) -> Table {
         self.property = self.property.width(w, t);
         self
@@ -98,11 +108,77 @@ impl Table {
         self.property = self.property.clear_all_border();
         self
     }
+
+    // Keeps the whole table on the page of the paragraph that follows it.
+    pub fn keep_with_next(mut self, v: bool) -> Self {
+        let rows = self.rows.len();
+        self.set_keep_next(v, rows);
+        self
+    }
+
+    // Keeps the rows of the table on one page. The last row may still be followed by a page break.
+    pub fn keep_together(mut self) -> Self {
+        let rows = self.rows.len().saturating_sub(1);
+        self.set_keep_next(true, rows);
+        self
+    }
+}
+
+impl Table {
+    // Sets keepNext on every paragraph of the first `rows` rows, including nested tables.
+    pub(crate) fn set_keep_next(&mut self, v: bool, rows: usize) {
+        for row in self.rows.iter_mut().take(rows) {
+            for cell in row.cells.iter_mut() {
+                for content in cell.children.iter_mut() {
+                    match content {
+                        TableCellContent::Paragraph(p) => p.property.keep_next = v,
+                        TableCellContent::Table(t) => {
+                            let rows = t.rows.len();
+                            t.set_keep_next(v, rows);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Columns of the first row, used when no grid is set.
+    // Percentages (fiftieths of a percent) are resolved against a table width in twips,
+    // any other width leaves the grid empty.
+    fn derived_grid(&self) -> Vec<usize> {
+        let row = match self.rows.first() {
+            Some(row) => row,
+            None => return vec![],
+        };
+        let table_width = &self.property.width;
+        let mut grid = vec![];
+        for cell in &row.cells {
+            let span = cell.property.grid_span.as_ref().map_or(1, |s| s.val.max(1));
+            let width = match &cell.property.width {
+                Some(w) if w.width_type == WidthType::DXA => w.width,
+                Some(w)
+                    if w.width_type == WidthType::Pct
+                        && table_width.width_type == WidthType::DXA =>
+                {
+                    table_width.width * w.width / 5000
+                }
+                _ => return vec![],
+            };
+            for _ in 0..span {
+                grid.push(width / span);
+            }
+        }
+        grid
+    }
 }
 
 impl BuildXML for Table {
     fn build(&self) -> Vec<u8> {
-        let grid = TableGrid::new(self.grid.clone());
+        let grid = if self.grid.is_empty() {
+            TableGrid::new(self.derived_grid())
+        } else {
+            TableGrid::new(self.grid.clone())
+        };
         let b = XMLBuilder::new()
             .open_table()
             .add_child(&self.property)
@@ -134,6 +210,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_table_grid_from_cell_widths() {
+        let t = Table::new(vec![TableRow::new(vec![
+            TableCell::new().width(2500, WidthType::Pct),
+            TableCell::new().width(2500, WidthType::Pct).grid_span(2),
+        ])])
+        .width(8000, WidthType::DXA);
+        assert_eq!(t.derived_grid(), vec![4000, 2000, 2000]);
+    }
+
+    #[test]
+    fn test_table_grid_with_auto_cell_width() {
+        let t = Table::new(vec![TableRow::new(vec![
+            TableCell::new().width(1000, WidthType::DXA),
+            TableCell::new().width(0, WidthType::Auto),
+        ])]);
+        assert_eq!(t.derived_grid(), Vec::<usize>::new());
+    }
+
     #[test]
     fn test_table_grid() {
         let b = Table::new(vec![TableRow::new(vec![])])
@@ -158,7 +253,22 @@ mod tests {
         let t = Table::new(vec![]).set_grid(vec![100, 200, 300]);
         assert_eq!(
             serde_json::to_string(&t).unwrap(),
-            r#"{"rows":[],"grid":[100,200,300],"hasNumbering":false,"property":{"width":{"width":0,"widthType":"Auto"},"justification":"left","borders":{"top":{"borderType":"single","size":2,"color":"000000","position":"top","space":0},"left":{"borderType":"single","size":2,"color":"000000","position":"left","space":0},"bottom":{"borderType":"single","size":2,"color":"000000","position":"bottom","space":0},"right":{"borderType":"single","size":2,"color":"000000","position":"right","space":0},"insideH":{"borderType":"single","size":2,"color":"000000","position":"insideH","space":0},"insideV":{"borderType":"single","size":2,"color":"000000","position":"insideV","space":0}},"margins":{"top":{"val":0,"widthType":"DXA"},"left":{"val":55,"widthType":"DXA"},"bottom":{"val":0,"widthType":"DXA"},"right":{"val":55,"widthType":"DXA"}},"indent":null,"style":null,"layout":null}}"#
+            r#"{"rows":[],"grid":[100,200,300],"hasNumbering":false,"property":{"width":{"width":0,"widthType":"Auto"},"justification":"left","borders":{"top":{"borderType":"single","size":2,"color":"000000","position":"top","space":0},"left":{"borderType":"single","size":2,"color":"000000","position":"left","space":0},"bottom":{"borderType":"single","size":2,"color":"000000","position":"bottom","space":0},"right":{"borderType":"single","size":2,"color":"000000","position":"right","space":0},"insideH":{"borderType":"single","size":2,"color":"000000","position":"insideH","space":0},"insideV":{"borderType":"single","size":2,"color":"000000","position":"insideV","space":0}},"margins":{"top":{"val":0,"widthType":"DXA"},"left":{"val":55,"widthType":"DXA"},"bottom":{"val":0,"widthType":"DXA"},"right":{"val":55,"widthType":"DXA"}},"indent":null,"style":null,"layout":null,"caption":null,"description":null}}"#
         );
     }
+
+    #[test]
+    fn test_table_keep_together() {
+        let row = || TableRow::new(vec![TableCell::new().add_paragraph(Paragraph::new())]);
+        let t = Table::new(vec![row(), row()]).keep_together();
+        let keep_next: Vec<bool> = t
+            .rows
+            .iter()
+            .map(|r| match &r.cells[0].children[0] {
+                TableCellContent::Paragraph(p) => p.property.keep_next,
+                _ => false,
+            })
+            .collect();
+        assert_eq!(keep_next, vec![true, false]);
+    }
 }
@@ -0,0 +1,32 @@
+use serde::{Serialize, Serializer};
+
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableCaption {
+    val: String,
+}
+
+impl TableCaption {
+    pub fn new(val: impl Into<String>) -> TableCaption {
+        TableCaption { val: val.into() }
+    }
+}
+
+impl BuildXML for TableCaption {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.table_caption(&escape(&self.val)).build()
+    }
+}
+
+impl Serialize for TableCaption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val)
+    }
+}
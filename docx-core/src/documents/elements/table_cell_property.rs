@@ -8,13 +8,15 @@ use crate::xml_builder::*;
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TableCellProperty {
-    width: Option<TableCellWidth>,
+    pub(crate) width: Option<TableCellWidth>,
     borders: Option<TableCellBorders>,
-    grid_span: Option<GridSpan>,
+    pub(crate) grid_span: Option<GridSpan>,
     vertical_merge: Option<VMerge>,
     vertical_align: Option<VAlign>,
     text_direction: Option<TextDirection>,
     shading: Option<Shading>,
+    no_wrap: bool,
+    fit_text: bool,
 }
 
 impl TableCellProperty {
@@ -52,6 +54,17 @@ impl TableCellProperty {
         self
     }
 
+    pub fn no_wrap(mut self, v: bool) -> Self {
+        self.no_wrap = v;
+        self
+    }
+
+    // Shrink the text so that it fits the cell width.
+    pub fn fit_text(mut self, v: bool) -> Self {
+        self.fit_text = v;
+        self
+    }
+
     pub fn set_borders(mut self, borders: TableCellBorders) -> Self {
         self.borders = Some(borders);
         self
@@ -83,23 +96,30 @@ impl Default for TableCellProperty {
             vertical_align: None,
             text_direction: None,
             shading: None,
+            no_wrap: false,
+            fit_text: false,
         }
     }
 }
 
 impl BuildXML for TableCellProperty {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
+        // The order of CT_TcPr.
+        let mut b = XMLBuilder::new()
             .open_table_cell_property()
             .add_optional_child(&self.width)
-            .add_optional_child(&self.borders)
             .add_optional_child(&self.grid_span)
             .add_optional_child(&self.vertical_merge)
-            .add_optional_child(&self.vertical_align)
-            .add_optional_child(&self.text_direction)
-            .add_optional_child(&self.shading)
-            .close()
-            .build()
+            .add_optional_child(&self.borders)
+            .add_optional_child(&self.shading);
+        if self.no_wrap {
+            b = b.no_wrap()
+        }
+        b = b.add_optional_child(&self.text_direction);
+        if self.fit_text {
+            b = b.table_cell_fit_text()
+        }
+        b.add_optional_child(&self.vertical_align).close().build()
     }
 }
 
@@ -118,6 +138,34 @@ mod tests {
         assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:tcPr />"#);
     }
 
+    #[test]
+    fn test_no_wrap_and_fit_text() {
+        let c = TableCellProperty::new().no_wrap(true).fit_text(true);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tcPr>
+  <w:noWrap />
+  <w:tcFitText />
+</w:tcPr>"#
+        );
+    }
+
+    #[test]
+    fn test_no_wrap_and_fit_text_order() {
+        let c = TableCellProperty::new()
+            .vertical_align(VAlignType::Center)
+            .fit_text(true)
+            .no_wrap(true)
+            .shading(Shading::new().shd_type(ShdType::Clear).fill("FF0000"));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tcPr><w:shd w:val="clear" w:color="auto" w:fill="FF0000" /><w:noWrap />
+  <w:tcFitText /><w:vAlign w:val="center" /></w:tcPr>"#
+        );
+    }
+
     #[test]
     fn test_grid_span() {
         let c = TableCellProperty::new().grid_span(3);
@@ -167,7 +215,7 @@ mod tests {
             .width(200, WidthType::DXA);
         assert_eq!(
             serde_json::to_string(&c).unwrap(),
-            r#"{"width":{"width":200,"widthType":"DXA"},"borders":null,"gridSpan":3,"verticalMerge":"continue","verticalAlign":null,"textDirection":null,"shading":null}"#
+            r#"{"width":{"width":200,"widthType":"DXA"},"borders":null,"gridSpan":3,"verticalMerge":"continue","verticalAlign":null,"textDirection":null,"shading":null,"noWrap":false,"fitText":false}"#
         );
     }
 
@@ -176,7 +224,7 @@ mod tests {
         let c = TableCellProperty::new().vertical_align(VAlignType::Center);
         assert_eq!(
             serde_json::to_string(&c).unwrap(),
-            r#"{"width":null,"borders":null,"gridSpan":null,"verticalMerge":null,"verticalAlign":"center","textDirection":null,"shading":null}"#
+            r#"{"width":null,"borders":null,"gridSpan":null,"verticalMerge":null,"verticalAlign":"center","textDirection":null,"shading":null,"noWrap":false,"fitText":false}"#
         );
     }
 }
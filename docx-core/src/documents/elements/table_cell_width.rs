@@ -7,8 +7,8 @@ use crate::xml_builder::*;
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TableCellWidth {
-    width: usize,
-    width_type: WidthType,
+    pub(crate) width: usize,
+    pub(crate) width_type: WidthType,
 }
 
 impl TableCellWidth {
@@ -20,7 +20,7 @@ impl TableCellWidth {
 impl BuildXML for TableCellWidth {
     fn build(&self) -> Vec<u8> {
         XMLBuilder::new()
-            .table_cell_width(self.width as i32, WidthType::DXA)
+            .table_cell_width(self.width as i32, self.width_type)
             .build()
     }
 }
@@ -41,4 +41,13 @@ mod tests {
             r#"<w:tcW w:w="20" w:type="dxa" />"#
         );
     }
+
+    #[test]
+    fn test_table_width_pct() {
+        let b = TableCellWidth::new(2500, WidthType::Pct).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tcW w:w="2500" w:type="pct" />"#
+        );
+    }
 }
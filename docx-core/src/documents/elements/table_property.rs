@@ -8,13 +8,16 @@ use crate::xml_builder::*;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TableProperty {
-    width: TableWidth,
+    pub(crate) width: TableWidth,
     justification: Justification,
     borders: TableBorders,
     margins: TableCellMargins,
     indent: Option<TableIndent>,
     style: Option<TableStyle>,
     layout: Option<TableLayout>,
+    // Alternative text of the table, announced by screen readers.
+    caption: Option<TableCaption>,
+    description: Option<TableDescription>,
 }
 
 impl Default for TableProperty {
@@ -27,6 +30,8 @@ impl Default for TableProperty {
             indent: None,
             style: None,
             layout: None,
+            caption: None,
+            description: None,
         }
     }
 }
@@ -43,8 +48,8 @@ impl TableProperty {
         }
     }
 
-    pub fn indent(mut self, v: i32) -> TableProperty {
-        self.indent = Some(TableIndent::new(v, WidthType::DXA));
+    pub fn indent(mut self, v: impl Into<Twip>) -> TableProperty {
+        self.indent = Some(TableIndent::new(v.into().0, WidthType::DXA));
         self
     }
 
@@ -112,6 +117,16 @@ impl TableProperty {
         self.layout = Some(TableLayout::new(t));
         self
     }
+
+    pub fn caption(mut self, v: impl Into<String>) -> Self {
+        self.caption = Some(TableCaption::new(v));
+        self
+    }
+
+    pub fn description(mut self, v: impl Into<String>) -> Self {
+        self.description = Some(TableDescription::new(v));
+        self
+    }
 }
 
 impl BuildXML for TableProperty {
@@ -125,6 +140,8 @@ impl BuildXML for TableProperty {
             .add_optional_child(&self.indent)
             .add_optional_child(&self.style)
             .add_optional_child(&self.layout)
+            .add_optional_child(&self.caption)
+            .add_optional_child(&self.description)
             .close()
             .build()
     }
@@ -153,12 +170,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_caption_and_description() {
+        let c = TableProperty::new()
+            .caption("Sales")
+            .description("Quarterly sales by region");
+        let b = c.build();
+        assert!(str::from_utf8(&b).unwrap().ends_with(
+            r#"<w:tblCaption w:val="Sales" /><w:tblDescription w:val="Quarterly sales by region" /></w:tblPr>"#
+        ));
+    }
+
+    #[test]
+    fn test_caption_with_special_characters() {
+        let b = TableProperty::new()
+            .caption(r#"Q&A "summary""#)
+            .description("<draft>")
+            .build();
+        assert!(str::from_utf8(&b).unwrap().ends_with(
+            r#"<w:tblCaption w:val="Q&amp;A &quot;summary&quot;" /><w:tblDescription w:val="&lt;draft&gt;" /></w:tblPr>"#
+        ));
+    }
+
     #[test]
     fn test_table_property_json() {
         let p = TableProperty::new().indent(100);
         assert_eq!(
             serde_json::to_string(&p).unwrap(),
-            r#"{"width":{"width":0,"widthType":"Auto"},"justification":"left","borders":{"top":{"borderType":"single","size":2,"color":"000000","position":"top","space":0},"left":{"borderType":"single","size":2,"color":"000000","position":"left","space":0},"bottom":{"borderType":"single","size":2,"color":"000000","position":"bottom","space":0},"right":{"borderType":"single","size":2,"color":"000000","position":"right","space":0},"insideH":{"borderType":"single","size":2,"color":"000000","position":"insideH","space":0},"insideV":{"borderType":"single","size":2,"color":"000000","position":"insideV","space":0}},"margins":{"top":{"val":0,"widthType":"DXA"},"left":{"val":55,"widthType":"DXA"},"bottom":{"val":0,"widthType":"DXA"},"right":{"val":55,"widthType":"DXA"}},"indent":{"width":100,"widthType":"DXA"},"style":null,"layout":null}"#
+            r#"{"width":{"width":0,"widthType":"Auto"},"justification":"left","borders":{"top":{"borderType":"single","size":2,"color":"000000","position":"top","space":0},"left":{"borderType":"single","size":2,"color":"000000","position":"left","space":0},"bottom":{"borderType":"single","size":2,"color":"000000","position":"bottom","space":0},"right":{"borderType":"single","size":2,"color":"000000","position":"right","space":0},"insideH":{"borderType":"single","size":2,"color":"000000","position":"insideH","space":0},"insideV":{"borderType":"single","size":2,"color":"000000","position":"insideV","space":0}},"margins":{"top":{"val":0,"widthType":"DXA"},"left":{"val":55,"widthType":"DXA"},"bottom":{"val":0,"widthType":"DXA"},"right":{"val":55,"widthType":"DXA"}},"indent":{"width":100,"widthType":"DXA"},"style":null,"layout":null,"caption":null,"description":null}"#
         );
     }
 }
@@ -0,0 +1,73 @@
+use serde::Serialize;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Table properties overridden for a single row. Word writes these for rows
+// which were a table of their own before the tables were joined.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TablePropertyExceptions {
+    borders: Option<TableBorders>,
+    shading: Option<Shading>,
+    margins: Option<TableCellMargins>,
+}
+
+impl TablePropertyExceptions {
+    pub fn new() -> TablePropertyExceptions {
+        Default::default()
+    }
+
+    pub fn set_borders(mut self, borders: TableBorders) -> Self {
+        self.borders = Some(borders);
+        self
+    }
+
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.shading = Some(s);
+        self
+    }
+
+    pub fn set_margins(mut self, margins: TableCellMargins) -> Self {
+        self.margins = Some(margins);
+        self
+    }
+}
+
+impl BuildXML for TablePropertyExceptions {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .open_table_property_exceptions()
+            .add_optional_child(&self.borders)
+            .add_optional_child(&self.shading)
+            .add_optional_child(&self.margins)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_property_exceptions() {
+        let b =
+            TablePropertyExceptions::new()
+                .set_borders(TableBorders::with_empty().set(
+                    TableBorder::new(TableBorderPosition::Top).border_type(BorderType::Double),
+                ))
+                .shading(Shading::new().fill("FF0000"))
+                .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblPrEx><w:tblBorders><w:top w:val="double" w:sz="2" w:space="0" w:color="000000" /></w:tblBorders><w:shd w:val="clear" w:color="auto" w:fill="FF0000" /></w:tblPrEx>"#
+        );
+    }
+}
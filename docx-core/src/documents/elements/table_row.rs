@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use super::{TableCell, TableRowProperty};
+use super::{TableCell, TablePropertyExceptions, TableRowProperty};
 use crate::xml_builder::*;
 use crate::{documents::BuildXML, HeightRule};
 
@@ -10,6 +10,7 @@ pub struct TableRow {
     pub cells: Vec<TableCell>,
     pub has_numbering: bool,
     pub property: TableRowProperty,
+    pub exception_properties: Option<TablePropertyExceptions>,
 }
 
 impl TableRow {
@@ -20,9 +21,16 @@ impl TableRow {
             cells,
             property,
             has_numbering,
+            exception_properties: None,
         }
     }
 
+    // Overrides table properties such as borders for this row only.
+    pub fn exception_properties(mut self, p: TablePropertyExceptions) -> TableRow {
+        self.exception_properties = Some(p);
+        self
+    }
+
     pub fn grid_after(mut self, grid_after: u32) -> TableRow {
         self.property = self.property.grid_after(grid_after);
         self
@@ -58,6 +66,7 @@ impl BuildXML for TableRow {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new()
             .open_table_row()
+            .add_optional_child(&self.exception_properties)
             .add_child(&self.property)
             .add_children(&self.cells);
         b.close().build()
@@ -86,7 +95,7 @@ mod tests {
         let r = TableRow::new(vec![TableCell::new()]);
         assert_eq!(
             serde_json::to_string(&r).unwrap(),
-            r#"{"cells":[{"children":[],"property":{"width":null,"borders":null,"gridSpan":null,"verticalMerge":null,"verticalAlign":null,"textDirection":null,"shading":null},"hasNumbering":false}],"hasNumbering":false,"property":{"gridAfter":null,"widthAfter":null,"gridBefore":null,"widthBefore":null,"rowHeight":null,"heightRule":null}}"#
+            r#"{"cells":[{"children":[],"property":{"width":null,"borders":null,"gridSpan":null,"verticalMerge":null,"verticalAlign":null,"textDirection":null,"shading":null,"noWrap":false,"fitText":false},"hasNumbering":false}],"hasNumbering":false,"property":{"gridAfter":null,"widthAfter":null,"gridBefore":null,"widthBefore":null,"rowHeight":null,"heightRule":null},"exceptionProperties":null}"#
         );
     }
 }
@@ -7,8 +7,8 @@ use crate::xml_builder::*;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TableWidth {
-    width: usize,
-    width_type: WidthType,
+    pub(crate) width: usize,
+    pub(crate) width_type: WidthType,
 }
 
 impl TableWidth {
@@ -8,8 +8,8 @@ use crate::xml_builder::*;
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Text {
-    text: String,
-    preserve_space: bool,
+    pub(crate) text: String,
+    pub(crate) preserve_space: bool,
 }
 
 impl Text {
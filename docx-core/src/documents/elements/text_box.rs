@@ -0,0 +1,167 @@
+use super::*;
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::types::ShapeType;
+use crate::xml_builder::*;
+
+/*
+  A floating shape holding its own paragraphs, e.g. a sidebar or pull-quote.
+  A box can continue the text which overflows an earlier box of the same chain,
+  in which case it holds no content of its own.
+*/
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextBox {
+    pub id: usize,
+    // (w, h) in px
+    pub size: (u32, u32),
+    pub position_h: DrawingPosition,
+    pub position_v: DrawingPosition,
+    pub fill: Option<String>,
+    // (color, width in px)
+    pub border: Option<(String, u32)>,
+    pub content: TextBoxContent,
+    pub chain_id: Option<usize>,
+    // (chain id, sequence in the chain)
+    pub link: Option<(usize, usize)>,
+}
+
+impl TextBox {
+    pub fn new(w: u32, h: u32) -> TextBox {
+        TextBox {
+            id: generate_pic_id(),
+            size: (w, h),
+            position_h: DrawingPosition::Offset(0),
+            position_v: DrawingPosition::Offset(0),
+            fill: None,
+            border: None,
+            content: TextBoxContent::new(),
+            chain_id: None,
+            link: None,
+        }
+    }
+
+    pub fn offset_x(mut self, x: usize) -> TextBox {
+        self.position_h = DrawingPosition::Offset(x);
+        self
+    }
+
+    pub fn offset_y(mut self, y: usize) -> TextBox {
+        self.position_v = DrawingPosition::Offset(y);
+        self
+    }
+
+    pub fn fill(mut self, color: impl Into<String>) -> TextBox {
+        self.fill = Some(color.into());
+        self
+    }
+
+    pub fn border(mut self, color: impl Into<String>, width: u32) -> TextBox {
+        self.border = Some((color.into(), width));
+        self
+    }
+
+    pub fn add_paragraph(mut self, p: Paragraph) -> TextBox {
+        self.content = self.content.add_paragraph(p);
+        self
+    }
+
+    pub fn add_table(mut self, t: Table) -> TextBox {
+        self.content = self.content.add_table(t);
+        self
+    }
+
+    // Starts a chain which later boxes can continue with `link`.
+    pub fn chain_id(mut self, id: usize) -> TextBox {
+        self.chain_id = Some(id);
+        self
+    }
+
+    pub fn link(mut self, chain_id: usize, seq: usize) -> TextBox {
+        self.link = Some((chain_id, seq));
+        self
+    }
+}
+
+impl BuildXML for TextBox {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new().open_wp_shape().wps_c_nv_sp_pr("1");
+        let mut b = build_shape_property(b, ShapeType::Rect, self.size, &self.fill, &self.border);
+        if let Some((id, seq)) = self.link {
+            b = b.wps_linked_text_box(&format!("{}", id), &format!("{}", seq));
+        } else {
+            b = match self.chain_id {
+                Some(id) => b.open_wp_text_box_with_id(&format!("{}", id)),
+                None => b.open_wp_text_box(),
+            };
+            b = b.add_child(&self.content).close();
+        }
+        b.wps_body_pr("square", "t").close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_text_box_build() {
+        let b = TextBox::new(100, 50)
+            .fill("FFFFFF")
+            .border("000000", 1)
+            .add_paragraph(Paragraph::new())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<wps:wsp>
+  <wps:cNvSpPr txBox="1" />
+  <wps:spPr>
+    <a:xfrm>
+      <a:off x="0" y="0" />
+      <a:ext cx="952500" cy="476250" />
+    </a:xfrm>
+    <a:prstGeom prst="rect">
+      <a:avLst />
+    </a:prstGeom>
+    <a:solidFill>
+      <a:srgbClr val="FFFFFF" />
+    </a:solidFill>
+    <a:ln w="9525">
+      <a:solidFill>
+        <a:srgbClr val="000000" />
+      </a:solidFill>
+    </a:ln>
+  </wps:spPr>
+  <wps:txbx><w:txbxContent><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:txbxContent></wps:txbx>
+  <wps:bodyPr wrap="square" anchor="t" />
+</wps:wsp>"#
+        );
+    }
+
+    #[test]
+    fn test_linked_text_box_build() {
+        let b = TextBox::new(100, 50).link(1, 1).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<wps:wsp>
+  <wps:cNvSpPr txBox="1" />
+  <wps:spPr>
+    <a:xfrm>
+      <a:off x="0" y="0" />
+      <a:ext cx="952500" cy="476250" />
+    </a:xfrm>
+    <a:prstGeom prst="rect">
+      <a:avLst />
+    </a:prstGeom>
+  </wps:spPr>
+  <wps:linkedTxbx id="1" seq="1" />
+  <wps:bodyPr wrap="square" anchor="t" />
+</wps:wsp>"#
+        );
+    }
+}
@@ -12,6 +12,7 @@ pub struct TextBoxContent {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum TextBoxContentChild {
     Paragraph(Paragraph),
     Table(Table),
@@ -0,0 +1,51 @@
+use serde::{Serialize, Serializer};
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// 17.7.4.19
+// uiPriority (Optional User Interface Sorting Order)
+// This element specifies a number which shall be used to sort the set of style definitions in the
+// primary user interface when this document is loaded by an application.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiPriority {
+    val: usize,
+}
+
+impl UiPriority {
+    pub fn new(val: usize) -> UiPriority {
+        UiPriority { val }
+    }
+}
+
+impl Serialize for UiPriority {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(self.val as u32)
+    }
+}
+
+impl BuildXML for UiPriority {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.ui_priority(self.val).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_ui_priority() {
+        let c = UiPriority::new(9);
+        let b = c.build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:uiPriority w:val="9" />"#);
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Serialize, Serializer};
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// 17.7.4.20
+// unhideWhenUsed (Remove Semi-Hidden Property When Style Is Used)
+// This element specifies whether the semiHidden property shall now be removed from this style
+// when this style is used by the contents of the document.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UnhideWhenUsed {}
+
+impl UnhideWhenUsed {
+    pub fn new() -> UnhideWhenUsed {
+        Default::default()
+    }
+}
+
+impl Serialize for UnhideWhenUsed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(true)
+    }
+}
+
+impl BuildXML for UnhideWhenUsed {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.unhide_when_used().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_unhide_when_used() {
+        let c = UnhideWhenUsed::new();
+        let b = c.build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:unhideWhenUsed />"#);
+    }
+}
@@ -4,24 +4,35 @@ use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
-pub struct Vanish {}
+pub struct Vanish {
+    val: bool,
+}
 
 impl Vanish {
     pub fn new() -> Vanish {
-        Vanish {}
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> Vanish {
+        self.val = false;
+        self
     }
 }
 
 impl Default for Vanish {
     fn default() -> Self {
-        Vanish {}
+        Vanish { val: true }
     }
 }
 
 impl BuildXML for Vanish {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        b.vanish().build()
+        if self.val {
+            b.vanish().build()
+        } else {
+            b.vanish_with_val("false").build()
+        }
     }
 }
 
@@ -30,7 +41,7 @@ impl Serialize for Vanish {
     where
         S: Serializer,
     {
-        serializer.serialize_bool(true)
+        serializer.serialize_bool(self.val)
     }
 }
 
@@ -48,4 +59,11 @@ mod tests {
         let b = c.build();
         assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:vanish />"#);
     }
+
+    #[test]
+    fn test_build_disabled() {
+        let c = Vanish::new().disable();
+        let b = c.build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:vanish w:val="false" />"#);
+    }
 }
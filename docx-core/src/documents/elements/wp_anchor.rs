@@ -8,6 +8,8 @@ use crate::xml_builder::*;
 #[serde(rename_all = "camelCase")]
 pub struct WpAnchor {
     pub children: Vec<AGraphic>,
+    // distT, distB, distL and distR in EMU.
+    pub wrap_distance: (u32, u32, u32, u32),
 }
 
 /*
@@ -34,14 +36,23 @@ impl WpAnchor {
 
 impl Default for WpAnchor {
     fn default() -> Self {
-        WpAnchor { children: vec![] }
+        WpAnchor {
+            children: vec![],
+            wrap_distance: (0, 0, 0, 0),
+        }
     }
 }
 
 impl BuildXML for WpAnchor {
     fn build(&self) -> Vec<u8> {
+        let (t, bottom, l, r) = self.wrap_distance;
         let b = XMLBuilder::new();
-        let mut b = b.open_anchor();
+        let mut b = b.open_anchor(
+            &format!("{}", t),
+            &format!("{}", bottom),
+            &format!("{}", l),
+            &format!("{}", r),
+        );
         for c in &self.children {
             b = b.add_child(c)
         }
@@ -60,6 +71,9 @@ mod tests {
     #[test]
     fn test_anchor_build() {
         let b = WpAnchor::new().build();
-        assert_eq!(str::from_utf8(&b).unwrap(), r#"<wp:anchor />"#);
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<wp:anchor distT="0" distB="0" distL="0" distR="0" />"#
+        );
     }
 }
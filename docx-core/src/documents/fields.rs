@@ -0,0 +1,97 @@
+use super::*;
+use crate::types::FieldCharType;
+
+// Collects the instruction of every complex field in the given content.
+// Instructions split over several runs are joined, nested fields are skipped.
+pub(crate) fn collect_field_instructions(children: &[DocumentChild]) -> Vec<InstrText> {
+    let mut collector = Collector::default();
+    for c in children {
+        collector.document_child(c);
+    }
+    collector.instructions
+}
+
+#[derive(Default)]
+struct Collector {
+    depth: usize,
+    current: Option<String>,
+    instructions: Vec<InstrText>,
+}
+
+impl Collector {
+    fn document_child(&mut self, c: &DocumentChild) {
+        match c {
+            DocumentChild::Paragraph(p) => self.paragraph(p),
+            DocumentChild::Table(t) => self.table(t),
+            _ => {}
+        }
+    }
+
+    fn table(&mut self, t: &Table) {
+        for row in &t.rows {
+            for cell in &row.cells {
+                for c in &cell.children {
+                    match c {
+                        TableCellContent::Paragraph(p) => self.paragraph(p),
+                        TableCellContent::Table(t) => self.table(t),
+                    }
+                }
+            }
+        }
+    }
+
+    fn paragraph(&mut self, p: &Paragraph) {
+        for c in &p.children {
+            match c {
+                ParagraphChild::Run(r) => self.run(r),
+                ParagraphChild::Insert(i) => {
+                    for c in &i.children {
+                        if let InsertChild::Run(r) = c {
+                            self.run(r);
+                        }
+                    }
+                }
+                ParagraphChild::MoveTo(m) => {
+                    for r in &m.children {
+                        self.run(r);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn run(&mut self, r: &Run) {
+        for c in &r.children {
+            match c {
+                RunChild::FieldChar(f) => match f.field_char_type {
+                    FieldCharType::Begin => {
+                        self.depth += 1;
+                        if self.depth == 1 {
+                            self.current = Some(String::new());
+                        }
+                    }
+                    FieldCharType::Separate => self.finish(),
+                    FieldCharType::End => {
+                        self.finish();
+                        self.depth = self.depth.saturating_sub(1);
+                    }
+                },
+                RunChild::InstrText(i) if self.depth == 1 => {
+                    if let Some(current) = self.current.as_mut() {
+                        current.push_str(&i.instr);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        if self.depth == 1 {
+            if let Some(instr) = self.current.take() {
+                self.instructions.push(InstrText::new(instr));
+            }
+        }
+    }
+}
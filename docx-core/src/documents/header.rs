@@ -15,26 +15,10 @@ impl Header {
     pub fn new() -> Header {
         Default::default()
     }
-
-    pub fn add_paragraph(mut self, p: Paragraph) -> Self {
-        // TODO: support numberings
-        // if p.has_numbering {
-        //     self.has_numbering = true
-        // }
-        self.children.push(HeaderChild::Paragraph(p));
-        self
-    }
-
-    pub fn add_table(mut self, t: Table) -> Self {
-        // TODO: support numberings
-        // if t.has_numbering {
-        //     self.has_numbering = true
-        // }
-        self.children.push(HeaderChild::Table(t));
-        self
-    }
 }
 
+impl_header_footer!(Header, HeaderChild);
+
 impl Default for Header {
     fn default() -> Self {
         Self { children: vec![] }
@@ -42,6 +26,7 @@ impl Default for Header {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum HeaderChild {
     Paragraph(Paragraph),
     Table(Table),
@@ -102,4 +87,14 @@ mod tests {
 <w:hdr xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" mc:Ignorable="w14 wp14" />"#
         );
     }
+
+    #[test]
+    fn test_header_with_page_number_center() {
+        let b = Header::with_page_number_center().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:hdr xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" mc:Ignorable="w14 wp14"><w:p w14:paraId="12345678"><w:pPr><w:rPr /><w:jc w:val="center" /></w:pPr><w:r><w:rPr /><w:fldChar w:fldCharType="begin" w:dirty="true" /><w:instrText xml:space="preserve"> PAGE </w:instrText><w:fldChar w:fldCharType="separate" /><w:fldChar w:fldCharType="end" /></w:r></w:p></w:hdr>"#
+        );
+    }
 }
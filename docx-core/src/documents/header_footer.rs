@@ -0,0 +1,42 @@
+use super::*;
+use crate::types::AlignmentType;
+
+// The builders shared by Header and Footer, which differ only in their part and child type.
+macro_rules! impl_header_footer {
+    ($t: ident, $child: ident) => {
+        impl $t {
+            pub fn add_paragraph(mut self, p: Paragraph) -> Self {
+                // TODO: support numberings
+                // if p.has_numbering {
+                //     self.has_numbering = true
+                // }
+                self.children.push($child::Paragraph(p));
+                self
+            }
+
+            pub fn add_table(mut self, t: Table) -> Self {
+                // TODO: support numberings
+                // if t.has_numbering {
+                //     self.has_numbering = true
+                // }
+                self.children.push($child::Table(t));
+                self
+            }
+
+            pub fn with_text(text: &str) -> Self {
+                $t::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+            }
+
+            pub fn with_page_number_center() -> Self {
+                $t::new().add_paragraph($crate::documents::header_footer::page_number_paragraph())
+            }
+        }
+    };
+}
+
+// A centered paragraph holding a PAGE field.
+pub(crate) fn page_number_paragraph() -> Paragraph {
+    Paragraph::new()
+        .align(AlignmentType::Center)
+        .add_run(Run::new().add_field(" PAGE "))
+}
@@ -1,23 +1,9 @@
-/*
-use std::sync::atomic::AtomicUsize;
-#[cfg(not(test))]
-static HEADER_ID: AtomicUsize = AtomicUsize::new(1);
-
-#[cfg(not(test))]
-pub fn generate_header_id() -> usize {
-    use std::sync::atomic::Ordering;
-
-    let id = HEADER_ID.load(Ordering::Relaxed);
-    HEADER_ID.store(id.wrapping_add(1), Ordering::Relaxed);
-    id
-}
-
-#[cfg(test)]
-pub fn generate_header_id() -> usize {
-    123
+// header1.xml is the document header, section headers are numbered from 2.
+pub fn create_header_rid(id: usize) -> String {
+    format!("rIdHeader{}", id)
 }
 
-pub fn create_header_rid(id: usize) -> String {
-    format!("rIdImage{}", id)
+// footer1.xml is the document footer, section footers are numbered from 2.
+pub fn create_footer_rid(id: usize) -> String {
+    format!("rIdFooter{}", id)
 }
-*/
\ No newline at end of file
@@ -14,9 +14,21 @@ pub trait HistoryId {
     }
 }
 
+#[cfg(not(test))]
+pub fn reset_history_id() {
+    use std::sync::atomic::Ordering;
+
+    HISTORY_ID.store(0, Ordering::Relaxed);
+}
+
 #[cfg(test)]
 pub trait HistoryId {
     fn generate(&self) -> &str {
         "123"
     }
 }
+
+#[cfg(test)]
+pub fn reset_history_id() {
+    // NOP
+}
@@ -0,0 +1,117 @@
+use crate::documents::quote_field_argument;
+
+// Switches of an XE (index entry) field.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexEntryOptions {
+    // Listed below the main entry, i.e. "Main:Sub".
+    pub sub_entry: Option<String>,
+    // Replaces the page number with a cross reference such as "See Borrowing".
+    pub cross_reference: Option<String>,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+impl IndexEntryOptions {
+    pub fn new() -> IndexEntryOptions {
+        Default::default()
+    }
+
+    pub fn sub_entry(mut self, s: impl Into<String>) -> Self {
+        self.sub_entry = Some(s.into());
+        self
+    }
+
+    pub fn cross_reference(mut self, s: impl Into<String>) -> Self {
+        self.cross_reference = Some(s.into());
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub(crate) fn instr(&self, text: &str) -> String {
+        let term = match &self.sub_entry {
+            Some(sub) => format!("{}:{}", text, sub),
+            None => text.to_owned(),
+        };
+        let mut instr = format!(" XE {}", quote_field_argument(&term));
+        if let Some(c) = &self.cross_reference {
+            instr = format!("{} \\t {}", instr, quote_field_argument(c));
+        }
+        if self.bold {
+            instr += " \\b";
+        }
+        if self.italic {
+            instr += " \\i";
+        }
+        instr + " "
+    }
+}
+
+// Switches of the INDEX field which compiles the XE entries.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexOptions {
+    pub columns: Option<usize>,
+    // Heading format inserted between letter groups, e.g. "A".
+    pub heading: Option<String>,
+}
+
+impl IndexOptions {
+    pub fn new() -> IndexOptions {
+        Default::default()
+    }
+
+    pub fn columns(mut self, c: usize) -> Self {
+        self.columns = Some(c);
+        self
+    }
+
+    pub fn heading(mut self, h: impl Into<String>) -> Self {
+        self.heading = Some(h.into());
+        self
+    }
+
+    pub(crate) fn instr(&self) -> String {
+        let mut instr = " INDEX".to_owned();
+        if let Some(h) = &self.heading {
+            instr = format!("{} \\h {}", instr, quote_field_argument(h));
+        }
+        if let Some(c) = self.columns {
+            instr = format!("{} \\c \"{}\"", instr, c);
+        }
+        instr + " "
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_index_entry_instr() {
+        let o = IndexEntryOptions::new()
+            .sub_entry("Ownership")
+            .cross_reference("See Borrowing")
+            .bold();
+        assert_eq!(
+            o.instr("Rust"),
+            r#" XE "Rust:Ownership" \t "See Borrowing" \b "#
+        );
+    }
+
+    #[test]
+    fn test_index_instr() {
+        let o = IndexOptions::new().heading("A").columns(2);
+        assert_eq!(o.instr(), r#" INDEX \h "A" \c "2" "#);
+    }
+}
@@ -0,0 +1,491 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::spacing::{style_chain, style_id};
+use super::*;
+use crate::types::NumberFormatType;
+
+// Computes the number text Word displays for every numbered paragraph in the given content.
+// A paragraph is numbered by its own numPr or by the numPr of its style and the styles it is based on.
+// Counters are shared by the numberings of one abstract numbering, unless the numbering
+// overrides a start value, in which case it counts on its own.
+pub(crate) fn resolve_list_numbers(
+    numberings: &Numberings,
+    styles: &Styles,
+    children: &[DocumentChild],
+) -> HashMap<String, String> {
+    let mut resolver = Resolver {
+        numberings,
+        styles: Some(styles),
+        default_numbering: create_default_numbering(),
+        counters: HashMap::new(),
+        numbers: HashMap::new(),
+    };
+    for c in children {
+        resolver.document_child(c);
+    }
+    resolver.numbers
+}
+
+// The numbering definitions in effect for a level of a numbering instance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedNumbering {
+    pub numbering_id: usize,
+    pub abstract_numbering: AbstractNumbering,
+    // The level definition with the lvlOverride of the numbering applied.
+    pub level: Level,
+    // The start value after applying startOverride.
+    pub start: usize,
+}
+
+// The numId and ilvl of the paragraph, each taken from the paragraph itself
+// or else from the first style of its style chain which sets it.
+pub(crate) fn numbering_of(styles: Option<&Styles>, p: &Paragraph) -> Option<(usize, usize)> {
+    let chain = styles
+        .map(|styles| style_chain(styles, style_id(p)))
+        .unwrap_or_default();
+    let properties = p.property.numbering_property.iter().chain(
+        chain
+            .iter()
+            .filter_map(|s| s.paragraph_property.numbering_property.as_ref()),
+    );
+    let mut id = None;
+    let mut level = None;
+    for n in properties {
+        id = id.or_else(|| n.id.as_ref().map(|i| i.id));
+        level = level.or_else(|| n.level.as_ref().map(|l| l.val));
+    }
+    Some((id?, level.unwrap_or(0)))
+}
+
+pub(crate) fn resolve_numbering(
+    numberings: &Numberings,
+    id: usize,
+    level: usize,
+) -> Option<ResolvedNumbering> {
+    let resolver = Resolver {
+        numberings,
+        styles: None,
+        default_numbering: create_default_numbering(),
+        counters: HashMap::new(),
+        numbers: HashMap::new(),
+    };
+    let numbering = resolver.numbering(id)?;
+    let abstract_numbering = resolver
+        .abstract_numbering(numbering.abstract_num_id)?
+        .clone();
+    let level = resolver
+        .levels(&numbering)
+        .into_iter()
+        .find(|l| l.level == level)?;
+    Some(ResolvedNumbering {
+        numbering_id: id,
+        abstract_numbering,
+        start: level.start.val,
+        level,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CounterKey {
+    Abstract(usize),
+    Numbering(usize),
+}
+
+struct Resolver<'a> {
+    numberings: &'a Numberings,
+    styles: Option<&'a Styles>,
+    default_numbering: AbstractNumbering,
+    counters: HashMap<CounterKey, Vec<Option<usize>>>,
+    numbers: HashMap<String, String>,
+}
+
+impl<'a> Resolver<'a> {
+    fn document_child(&mut self, c: &DocumentChild) {
+        match c {
+            DocumentChild::Paragraph(p) => self.paragraph(p),
+            DocumentChild::Table(t) => self.table(t),
+            _ => {}
+        }
+    }
+
+    fn table(&mut self, t: &Table) {
+        for row in &t.rows {
+            for cell in &row.cells {
+                for c in &cell.children {
+                    match c {
+                        TableCellContent::Paragraph(p) => self.paragraph(p),
+                        TableCellContent::Table(t) => self.table(t),
+                    }
+                }
+            }
+        }
+    }
+
+    fn paragraph(&mut self, p: &Paragraph) {
+        let (id, level) = match self.numbering_of(p) {
+            Some(n) => n,
+            None => return,
+        };
+        // numId 0 removes the numbering.
+        if id == 0 {
+            return;
+        }
+        if let Some(text) = self.count(id, level) {
+            self.numbers.insert(p.id.clone(), text);
+        }
+    }
+
+    fn numbering_of(&self, p: &Paragraph) -> Option<(usize, usize)> {
+        numbering_of(self.styles, p)
+    }
+
+    fn numbering(&self, id: usize) -> Option<Numbering> {
+        self.numberings
+            .numberings
+            .iter()
+            .find(|n| n.id == id)
+            .cloned()
+            .or_else(|| Some(Numbering::new(1, 1)).filter(|n| n.id == id))
+    }
+
+    fn abstract_numbering(&self, id: usize) -> Option<&AbstractNumbering> {
+        self.numberings
+            .abstract_nums
+            .iter()
+            .find(|a| a.id == id)
+            .or(Some(&self.default_numbering).filter(|a| a.id == id))
+    }
+
+    // The level definitions of the numbering with its overrides applied.
+    fn levels(&self, numbering: &Numbering) -> Vec<Level> {
+        let mut levels = self
+            .abstract_numbering(numbering.abstract_num_id)
+            .map(|a| a.levels.clone())
+            .unwrap_or_default();
+        for o in &numbering.level_overrides {
+            if let Some(l) = &o.override_level {
+                levels.retain(|l| l.level != o.level);
+                levels.push(l.clone());
+            }
+            if let Some(start) = o.override_start {
+                if let Some(l) = levels.iter_mut().find(|l| l.level == o.level) {
+                    l.start = Start::new(start);
+                }
+            }
+        }
+        levels
+    }
+
+    fn count(&mut self, id: usize, level: usize) -> Option<String> {
+        let numbering = self.numbering(id)?;
+        let levels = self.levels(&numbering);
+        let current = levels.iter().find(|l| l.level == level)?;
+        let key = if numbering
+            .level_overrides
+            .iter()
+            .any(|o| o.override_start.is_some())
+        {
+            CounterKey::Numbering(id)
+        } else {
+            CounterKey::Abstract(numbering.abstract_num_id)
+        };
+
+        let counters = self.counters.entry(key).or_default();
+        if counters.len() <= level {
+            counters.resize(level + 1, None);
+        }
+        counters[level] = Some(counters[level].map_or(current.start.val, |c| c + 1));
+        // A level restarts after a higher level is used, unless lvlRestart says otherwise.
+        for l in &levels {
+            if l.level <= level || l.level >= counters.len() {
+                continue;
+            }
+            let restart_after = l
+                .level_restart
+                .as_ref()
+                .map(|r| r.val as usize)
+                .unwrap_or(l.level);
+            if level < restart_after {
+                counters[l.level] = None;
+            }
+        }
+
+        let mut text = current.text.val.clone();
+        for l in &levels {
+            let placeholder = format!("%{}", l.level + 1);
+            if !text.contains(&placeholder) {
+                continue;
+            }
+            let n = counters
+                .get(l.level)
+                .copied()
+                .flatten()
+                .unwrap_or(l.start.val);
+            text = text.replace(&placeholder, &format_number(&l.format, n));
+        }
+        Some(text)
+    }
+}
+
+fn format_number(format: &NumberFormat, n: usize) -> String {
+    match NumberFormatType::from_str(&format.val) {
+        Ok(NumberFormatType::DecimalZero) => format!("{:02}", n),
+        Ok(NumberFormatType::UpperRoman) => roman(n),
+        Ok(NumberFormatType::LowerRoman) => roman(n).to_lowercase(),
+        Ok(NumberFormatType::UpperLetter) => letter(n),
+        Ok(NumberFormatType::LowerLetter) => letter(n).to_lowercase(),
+        Ok(NumberFormatType::Ordinal) => format!("{}{}", n, ordinal_suffix(n)),
+        Ok(NumberFormatType::CardinalText) => capitalize(&cardinal_text(n)),
+        Ok(NumberFormatType::OrdinalText) => capitalize(&ordinal_text(n)),
+        Ok(NumberFormatType::Hex) => format!("{:X}", n),
+        Ok(NumberFormatType::DecimalEnclosedCircle) if (1..=20).contains(&n) => {
+            std::char::from_u32(0x2460 + n as u32 - 1)
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| n.to_string())
+        }
+        Ok(NumberFormatType::DecimalEnclosedFullstop) => format!("{}.", n),
+        Ok(NumberFormatType::DecimalEnclosedParen) => format!("({})", n),
+        Ok(NumberFormatType::NumberInDash) => format!("- {} -", n),
+        Ok(NumberFormatType::Bullet) | Ok(NumberFormatType::None) => "".to_owned(),
+        _ => n.to_string(),
+    }
+}
+
+fn roman(mut n: usize) -> String {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut s = String::new();
+    for (v, numeral) in NUMERALS.iter() {
+        while n >= *v {
+            s.push_str(numeral);
+            n -= v;
+        }
+    }
+    s
+}
+
+// Word repeats the letter after z: a, ..., z, aa, bb, ...
+fn letter(n: usize) -> String {
+    if n == 0 {
+        return "".to_owned();
+    }
+    let c = (b'A' + ((n - 1) % 26) as u8) as char;
+    let mut s = String::new();
+    for _ in 0..(n - 1) / 26 + 1 {
+        s.push(c);
+    }
+    s
+}
+
+fn ordinal_suffix(n: usize) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+fn cardinal_text(n: usize) -> String {
+    let (unit, rest) = match n {
+        0..=19 => return ONES[n].to_owned(),
+        20..=99 => {
+            return match n % 10 {
+                0 => TENS[n / 10].to_owned(),
+                r => format!("{}-{}", TENS[n / 10], ONES[r]),
+            }
+        }
+        100..=999 => (format!("{} hundred", ONES[n / 100]), n % 100),
+        1000..=999_999 => (format!("{} thousand", cardinal_text(n / 1000)), n % 1000),
+        _ => return n.to_string(),
+    };
+    match rest {
+        0 => unit,
+        r => format!("{} {}", unit, cardinal_text(r)),
+    }
+}
+
+fn ordinal_text(n: usize) -> String {
+    let cardinal = cardinal_text(n);
+    // Only the last word turns into its ordinal form, e.g. "twenty-one" -> "twenty-first".
+    let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = cardinal.split_at(split);
+    let last = match last {
+        "one" => "first".to_owned(),
+        "two" => "second".to_owned(),
+        "three" => "third".to_owned(),
+        "five" => "fifth".to_owned(),
+        "eight" => "eighth".to_owned(),
+        "nine" => "ninth".to_owned(),
+        "twelve" => "twelfth".to_owned(),
+        w if w.ends_with('y') => format!("{}ieth", &w[..w.len() - 1]),
+        w if w.chars().all(|c| c.is_ascii_digit()) => format!("{}{}", w, ordinal_suffix(n)),
+        w => format!("{}th", w),
+    };
+    format!("{}{}", head, last)
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::StyleType;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn numbered(para_id: &str, id: usize, level: usize) -> DocumentChild {
+        let mut p = Paragraph::new()
+            .add_run(Run::new().add_text("item"))
+            .numbering(NumberingId::new(id), IndentLevel::new(level));
+        p.id = para_id.to_owned();
+        DocumentChild::Paragraph(p)
+    }
+
+    fn outline() -> AbstractNumbering {
+        AbstractNumbering::new(2)
+            .add_level(Level::new(
+                0,
+                Start::new(1),
+                NumberFormat::new("decimal"),
+                LevelText::new("%1."),
+                LevelJc::new("left"),
+            ))
+            .add_level(Level::new(
+                1,
+                Start::new(1),
+                NumberFormat::new("lowerLetter"),
+                LevelText::new("%1.%2)"),
+                LevelJc::new("left"),
+            ))
+    }
+
+    #[test]
+    fn test_resolve_list_numbers() {
+        let numberings = Numberings::new()
+            .add_abstract_numbering(outline())
+            .add_numbering(Numbering::new(2, 2));
+        let children = vec![
+            numbered("a", 2, 0),
+            numbered("b", 2, 1),
+            numbered("c", 2, 1),
+            numbered("d", 2, 0),
+            numbered("e", 2, 1),
+            DocumentChild::Paragraph(Paragraph::new().add_run(Run::new().add_text("plain"))),
+        ];
+        let numbers = resolve_list_numbers(&numberings, &Styles::new(), &children);
+        assert_eq!(numbers.len(), 5);
+        assert_eq!(numbers["a"], "1.");
+        assert_eq!(numbers["b"], "1.a)");
+        assert_eq!(numbers["c"], "1.b)");
+        assert_eq!(numbers["d"], "2.");
+        assert_eq!(numbers["e"], "2.a)");
+    }
+
+    #[test]
+    fn test_resolve_list_numbers_with_start_override() {
+        let numberings = Numberings::new()
+            .add_abstract_numbering(outline())
+            .add_numbering(Numbering::new(2, 2))
+            .add_numbering(Numbering::new(3, 2).add_override(LevelOverride::new(0).start(5)));
+        let children = vec![
+            numbered("a", 2, 0),
+            numbered("b", 2, 0),
+            numbered("c", 3, 0),
+            numbered("d", 3, 1),
+        ];
+        let numbers = resolve_list_numbers(&numberings, &Styles::new(), &children);
+        assert_eq!(numbers["b"], "2.");
+        assert_eq!(numbers["c"], "5.");
+        assert_eq!(numbers["d"], "5.a)");
+    }
+
+    #[test]
+    fn test_resolve_list_numbers_from_style_chain() {
+        let numberings = Numberings::new()
+            .add_abstract_numbering(outline())
+            .add_numbering(Numbering::new(2, 2));
+        let styles = Styles::new()
+            .add_style(Style::new("ListBase", StyleType::Paragraph).numbering(2))
+            .add_style(Style::new("ListItem", StyleType::Paragraph).based_on("ListBase"));
+        let styled = |para_id: &str, level: Option<usize>| {
+            let mut p = Paragraph::new().style("ListItem");
+            if let Some(level) = level {
+                p.property.numbering_property = Some(NumberingProperty {
+                    id: None,
+                    level: Some(IndentLevel::new(level)),
+                });
+            }
+            p.id = para_id.to_owned();
+            DocumentChild::Paragraph(p)
+        };
+        let children = vec![styled("a", None), styled("b", Some(1)), styled("c", None)];
+        let numbers = resolve_list_numbers(&numberings, &styles, &children);
+        assert_eq!(numbers["a"], "1.");
+        assert_eq!(numbers["b"], "1.a)");
+        assert_eq!(numbers["c"], "2.");
+    }
+
+    #[test]
+    fn test_format_number() {
+        let f = |v: &str, n: usize| format_number(&NumberFormat::new(v), n);
+        assert_eq!(f("upperRoman", 14), "XIV");
+        assert_eq!(f("lowerLetter", 28), "bb");
+        assert_eq!(f("ordinal", 12), "12th");
+        assert_eq!(f("ordinal", 22), "22nd");
+        assert_eq!(f("cardinalText", 21), "Twenty-one");
+        assert_eq!(f("ordinalText", 40), "Fortieth");
+        assert_eq!(f("ordinalText", 103), "One hundred third");
+        assert_eq!(f("decimalZero", 7), "07");
+        assert_eq!(f("bullet", 1), "");
+    }
+}
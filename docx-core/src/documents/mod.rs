@@ -1,8 +1,15 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
 
+mod auto_ids;
+mod bookmark_id;
+mod build_options;
 mod build_xml;
 mod comments;
 mod comments_extended;
+mod compare;
 mod content_types;
 mod custom_item;
 mod custom_item_property;
@@ -11,29 +18,53 @@ mod doc_props;
 mod document;
 mod document_rels;
 mod elements;
+mod fields;
 mod font_table;
+#[macro_use]
+mod header_footer;
+mod footer;
 mod header;
 mod header_id;
 mod history_id;
+mod index_options;
+mod list_numbers;
+mod numbering_rels;
 mod numberings;
 mod paragraph_id;
 mod pic_id;
+mod print_settings;
+mod relationships;
 mod rels;
+mod revision;
+mod seq_field;
 mod settings;
+mod spacing;
+mod statistics;
 mod styles;
 mod taskpanes;
 mod taskpanes_rels;
+mod template;
 mod web_settings;
 mod webextension;
 mod xml_docx;
 
+pub(crate) use bookmark_id::*;
 pub(crate) use build_xml::BuildXML;
-pub(crate) use history_id::HistoryId;
+pub(crate) use history_id::{reset_history_id, HistoryId};
 pub(crate) use paragraph_id::*;
+pub(crate) use header_id::*;
 pub(crate) use pic_id::*;
+use fields::collect_field_instructions;
+use list_numbers::resolve_list_numbers;
+pub(crate) use list_numbers::{numbering_of, resolve_numbering};
+pub use list_numbers::ResolvedNumbering;
+pub(crate) use spacing::resolve_spacing;
+use revision::Revision;
 
+pub use build_options::*;
 pub use comments::*;
 pub use comments_extended::*;
+pub use compare::*;
 pub use content_types::*;
 pub use custom_item::*;
 pub use custom_item_property::*;
@@ -43,17 +74,26 @@ pub use document::*;
 pub use document_rels::*;
 pub use elements::*;
 pub use font_table::*;
+pub use footer::*;
 pub use header::*;
+pub use index_options::*;
+pub use numbering_rels::*;
 pub use numberings::*;
+pub use print_settings::*;
+pub use relationships::*;
 pub use rels::*;
+pub use seq_field::*;
 pub use settings::*;
+pub use statistics::*;
 pub use styles::*;
 pub use taskpanes::*;
 pub use taskpanes_rels::*;
+pub use template::*;
 pub use web_settings::*;
 pub use webextension::*;
 pub use xml_docx::*;
 
+use crate::errors::DocxError;
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize)]
@@ -71,6 +111,7 @@ pub struct Docx {
     pub font_table: FontTable,
     pub media: Vec<(usize, Vec<u8>)>,
     pub header: Header,
+    pub footer: Option<Footer>,
     pub comments_extended: CommentsExtended,
     pub web_settings: WebSettings,
     pub taskpanes: Option<Taskpanes>,
@@ -79,6 +120,19 @@ pub struct Docx {
     pub custom_items: Vec<CustomItem>,
     pub custom_item_props: Vec<CustomItemProperty>,
     pub custom_item_rels: Vec<CustomItemRels>,
+    // Read only: the package as found by read_docx.
+    #[serde(skip)]
+    pub(crate) relationships: Relationships,
+    #[serde(skip)]
+    pub(crate) settings_relationships: Relationships,
+    #[serde(skip)]
+    pub(crate) read_content_types: Option<ContentTypes>,
+    // The binary VBA project (vbaProject.bin) of a macro-enabled document.
+    #[serde(skip)]
+    pub(crate) vba_project: Option<Vec<u8>>,
+    // The theme part (theme1.xml), kept as read since the theme is not modeled.
+    #[serde(skip)]
+    pub(crate) theme: Option<Vec<u8>>,
 }
 
 impl Default for Docx {
@@ -111,6 +165,7 @@ impl Default for Docx {
             font_table,
             media,
             header,
+            footer: None,
             comments_extended,
             web_settings,
             taskpanes: None,
@@ -119,6 +174,11 @@ impl Default for Docx {
             custom_items: vec![],
             custom_item_props: vec![],
             custom_item_rels: vec![],
+            relationships: Relationships::new(),
+            settings_relationships: Relationships::new(),
+            read_content_types: None,
+            vba_project: None,
+            theme: None,
         }
     }
 }
@@ -163,8 +223,12 @@ impl Docx {
         self
     }
 
-    // reader only
-    pub(crate) fn web_settings(mut self, s: WebSettings) -> Self {
+    // settings() is taken by the setter.
+    pub fn get_settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn web_settings(mut self, s: WebSettings) -> Self {
         self.web_settings = s;
         self
     }
@@ -199,6 +263,125 @@ impl Docx {
         self
     }
 
+    pub fn insert_at_bookmark(
+        &mut self,
+        name: &str,
+        children: Vec<ParagraphChild>,
+    ) -> Result<(), DocxError> {
+        self.document.insert_at_bookmark(name, children)
+    }
+
+    // Adds a relationship to the main document part, i.e. a hyperlink target.
+    // The id must not collide with the ids assigned on build, so a prefix such as "rIdLink" is advisable.
+    pub fn add_relationship(mut self, rel: Relationship) -> Docx {
+        self.relationships = self.relationships.add_relationship(rel);
+        self
+    }
+
+    // Relationships of the main document part, read or added, including external targets.
+    pub fn relationships(&self) -> &Relationships {
+        &self.relationships
+    }
+
+    // Relationships of the settings part of a read document, i.e. the attached template.
+    pub fn settings_relationships(&self) -> &Relationships {
+        &self.settings_relationships
+    }
+
+    // The template attached to a read document, which may be a remote one.
+    pub fn attached_template(&self) -> Option<&Relationship> {
+        self.settings_relationships
+            .iter()
+            .find(|r| r.rel_type == ATTACHED_TEMPLATE_TYPE)
+    }
+
+    // Body content in document order, i.e. paragraphs and tables as they are interleaved.
+    pub fn document_children(&self) -> &[DocumentChild] {
+        &self.document.children
+    }
+
+    // Content types of a read document, otherwise the ones to be written.
+    pub fn content_types(&self) -> &ContentTypes {
+        self.read_content_types.as_ref().unwrap_or(&self.content_type)
+    }
+
+    // Merge adjacent runs of the same formatting in the body, header and footer.
+    pub fn optimize(mut self) -> Docx {
+        for c in self.document.children.iter_mut() {
+            match c {
+                DocumentChild::Paragraph(p) => p.merge_runs(),
+                DocumentChild::Table(t) => optimize_table(t),
+                _ => {}
+            }
+        }
+        for c in self.header.children.iter_mut() {
+            match c {
+                HeaderChild::Paragraph(p) => p.merge_runs(),
+                HeaderChild::Table(t) => optimize_table(t),
+            }
+        }
+        if let Some(footer) = self.footer.as_mut() {
+            for c in footer.children.iter_mut() {
+                match c {
+                    FooterChild::Paragraph(p) => p.merge_runs(),
+                    FooterChild::Table(t) => optimize_table(t),
+                }
+            }
+        }
+        self
+    }
+
+    // Keep the inserted and drop the deleted content of tracked changes.
+    pub fn accept_all_revisions(self) -> Docx {
+        self.resolve_revisions(Revision::Accept)
+    }
+
+    // Restore the content as it was before the tracked changes.
+    pub fn reject_all_revisions(self) -> Docx {
+        self.resolve_revisions(Revision::Reject)
+    }
+
+    // Keeps a block of document children, i.e. a figure and its caption, from splitting across pages.
+    pub fn keep_together(mut self, range: std::ops::Range<usize>) -> Docx {
+        self.document = self.document.keep_together(range);
+        self
+    }
+
+    // Embeds an HTML or RTF fragment, which Word imports as the document content on open.
+    pub fn add_alt_chunk(mut self, c: AltChunk) -> Docx {
+        self.document = self.document.add_alt_chunk(c);
+        self
+    }
+
+    // Makes the document macro-enabled. The package should be saved as .docm.
+    pub fn add_vba_project(mut self, data: Vec<u8>) -> Docx {
+        self.vba_project = Some(data);
+        self
+    }
+
+    pub fn has_vba_project(&self) -> bool {
+        self.vba_project.is_some()
+    }
+
+    pub fn vba_project(&self) -> Option<&[u8]> {
+        self.vba_project.as_deref()
+    }
+
+    // Sets the theme part, i.e. the theme1.xml of another document, which theme colors and fonts refer to.
+    pub fn add_theme(mut self, data: Vec<u8>) -> Docx {
+        self.theme = Some(data);
+        self
+    }
+
+    pub fn theme(&self) -> Option<&[u8]> {
+        self.theme.as_deref()
+    }
+
+    pub fn add_section(mut self, s: Section) -> Docx {
+        self.document = self.document.add_section(s);
+        self
+    }
+
     pub fn add_table(mut self, t: Table) -> Docx {
         if t.has_numbering {
             // If this document has numbering, set numberings.xml to document_rels.
@@ -209,6 +392,48 @@ impl Docx {
         self
     }
 
+    // Adds the INDEX field. It is left dirty, so Word compiles the entries when the document is opened.
+    pub fn add_index(self, options: IndexOptions) -> Docx {
+        self.add_paragraph(Paragraph::new().add_run(Run::new().add_field(options.instr())))
+    }
+
+    // Lists the captions numbered with the given SEQ identifier, e.g. "Figure".
+    pub fn add_table_of_figures(self, identifier: &str) -> Docx {
+        let instr = format!(" TOC \\h \\z \\c {} ", quote_field_argument(identifier));
+        self.add_paragraph(Paragraph::new().add_run(Run::new().add_field(instr)))
+    }
+
+    // Identifier of every SEQ field in document order.
+    pub fn seq_identifiers(&self) -> Vec<String> {
+        collect_field_instructions(&self.document.children)
+            .into_iter()
+            .filter(|i| i.field_type().as_deref() == Some("SEQ"))
+            .filter_map(|i| i.argument())
+            .collect()
+    }
+
+    // Terms marked with XE fields, with sub entries joined by ':' as in the field.
+    pub fn index_entries(&self) -> Vec<String> {
+        collect_field_instructions(&self.document.children)
+            .into_iter()
+            .filter(|i| i.field_type().as_deref() == Some("XE"))
+            .filter_map(|i| i.argument())
+            .collect()
+    }
+
+    // Picture of every DATE and TIME field in document order.
+    pub fn date_field_formats(&self) -> Vec<String> {
+        collect_field_instructions(&self.document.children)
+            .into_iter()
+            .filter_map(|i| i.date_format())
+            .collect()
+    }
+
+    // The number text displayed for each numbered paragraph, keyed by paragraph id.
+    pub fn resolve_list_numbers(&self) -> HashMap<String, String> {
+        resolve_list_numbers(&self.numberings, &self.styles, &self.document.children)
+    }
+
     pub fn add_header_paragraph(mut self, p: Paragraph) -> Docx {
         if p.has_numbering {
             // If this document has numbering, set numberings.xml to document_rels.
@@ -219,11 +444,33 @@ impl Docx {
         self
     }
 
+    pub fn sections(&self) -> Vec<SectionView<'_>> {
+        self.document.sections()
+    }
+
+    pub fn header(mut self, h: Header) -> Docx {
+        self.header = h;
+        self
+    }
+
+    pub fn footer(mut self, f: Footer) -> Docx {
+        self.footer = Some(f);
+        self
+    }
+
     pub fn add_abstract_numbering(mut self, num: AbstractNumbering) -> Docx {
         self.numberings = self.numberings.add_abstract_numbering(num);
         self
     }
 
+    pub fn add_numbering_picture_bullet(mut self, id: usize, pic: Pic) -> Docx {
+        self.numberings = self
+            .numberings
+            .add_picture_bullet(NumberingPictureBullet::new(id, pic));
+        self.document_rels.has_numberings = true;
+        self
+    }
+
     pub fn add_numbering(mut self, num: Numbering) -> Docx {
         self.numberings = self.numberings.add_numbering(num);
         self
@@ -244,6 +491,17 @@ impl Docx {
         self
     }
 
+    pub fn statistics(&self) -> DocumentStatistics {
+        collect_statistics(&self.document)
+    }
+
+    // Write the current statistics into docProps/app.xml.
+    pub fn update_statistics(mut self) -> Self {
+        let s = self.statistics();
+        self.doc_props.app = self.doc_props.app.statistics(s);
+        self
+    }
+
     pub fn doc_id(mut self, id: &str) -> Self {
         self.settings = self.settings.doc_id(id);
         self
@@ -259,7 +517,49 @@ impl Docx {
         self
     }
 
-    pub fn page_size(mut self, w: u32, h: u32) -> Self {
+    pub fn set_variable(mut self, name: impl Into<String>, val: impl Into<String>) -> Self {
+        self.settings = self.settings.set_doc_var(name, val);
+        self
+    }
+
+    pub fn get_variable(&self, name: &str) -> Option<&str> {
+        self.settings.get_doc_var(name)
+    }
+
+    pub fn variables(&self) -> BTreeMap<String, String> {
+        self.settings.doc_vars()
+    }
+
+    pub fn track_revisions(mut self, v: bool) -> Self {
+        self.settings = self.settings.track_revisions(v);
+        self
+    }
+
+    pub fn footnote_properties(mut self, p: FootnoteProperties) -> Self {
+        self.settings = self.settings.footnote_properties(p);
+        self
+    }
+
+    pub fn endnote_properties(mut self, p: EndnoteProperties) -> Self {
+        self.settings = self.settings.endnote_properties(p);
+        self
+    }
+
+    pub fn print_settings(mut self, s: PrintSettings) -> Self {
+        self.settings = self.settings.print_settings(s);
+        self
+    }
+
+    pub fn add_rsid(mut self, rsid: &str) -> Self {
+        self.settings = self.settings.add_rsid(rsid);
+        self
+    }
+
+    pub fn page_size(
+        mut self,
+        w: impl Into<crate::types::Twip>,
+        h: impl Into<crate::types::Twip>,
+    ) -> Self {
         self.document = self.document.page_size(PageSize::new().size(w, h));
         self
     }
@@ -279,16 +579,73 @@ impl Docx {
         self
     }
 
-    pub fn default_spacing(mut self, spacing: i32) -> Self {
+    pub fn default_spacing(mut self, spacing: impl Into<crate::types::Twip>) -> Self {
         self.styles = self.styles.default_spacing(spacing);
         self
     }
 
+    // Paragraph spacing of docDefaults, which applies unless styles or paragraphs override it.
+    pub fn default_line_spacing(mut self, s: LineSpacing) -> Self {
+        self.styles = self.styles.default_line_spacing(s);
+        self
+    }
+
     pub fn default_fonts(mut self, font: RunFonts) -> Self {
         self.styles = self.styles.default_fonts(font);
         self
     }
 
+    // Sets the default fonts of latin, East Asian and complex script text, and the languages
+    // of the theme fonts, so Word picks the theme fonts of the same scripts.
+    pub fn default_script_fonts(
+        self,
+        ascii: &str,
+        east_asia: &str,
+        cs: &str,
+        lang: LangSettings,
+    ) -> Self {
+        self.default_fonts(
+            RunFonts::new()
+                .ascii(ascii)
+                .hi_ansi(ascii)
+                .east_asia(east_asia)
+                .cs(cs),
+        )
+        .theme_font_lang(lang)
+    }
+
+    pub fn get_default_fonts(&self) -> Option<&RunFonts> {
+        self.styles
+            .get_doc_defaults()
+            .get_run_property()
+            .fonts
+            .as_ref()
+    }
+
+    // Languages of the theme fonts for latin, East Asian and complex script text.
+    pub fn theme_font_lang(mut self, l: LangSettings) -> Self {
+        self.settings = self.settings.theme_font_lang(l);
+        self
+    }
+
+    // Proofing language inherited by every run without a language of its own.
+    // The theme fonts are picked for the same languages.
+    pub fn default_language(mut self, l: LangSettings) -> Self {
+        self.settings = self.settings.theme_font_lang(l.clone());
+        self.styles = self.styles.default_lang(l);
+        self
+    }
+
+    // The language of docDefaults, or of the theme fonts when docDefaults sets none.
+    pub fn get_default_language(&self) -> Option<LangSettings> {
+        self.styles
+            .get_doc_defaults()
+            .get_run_property()
+            .lang
+            .clone()
+            .or_else(|| self.settings.get_theme_font_lang().cloned())
+    }
+
     pub fn taskpanes(mut self) -> Self {
         self.taskpanes = Some(Taskpanes::new());
         self.rels = self.rels.add_taskpanes_rel();
@@ -315,11 +672,57 @@ impl Docx {
     }
 
     pub fn build(&mut self) -> XMLDocx {
+        self.build_with(BuildOptions::default())
+    }
+
+    pub fn build_with(&mut self, options: BuildOptions) -> XMLDocx {
+        // Ids are renumbered on a copy, so the document keeps the ids it was built with.
+        if options.deterministic {
+            let mut docx = self.clone();
+            reset_history_id();
+            auto_ids::renumber_auto_ids(&mut docx);
+            return docx.build_parts(options);
+        }
         self.reset();
+        self.build_parts(options)
+    }
 
+    fn build_parts(&mut self, options: BuildOptions) -> XMLDocx {
         self.update_comments();
+        self.update_rsids();
 
-        let (image_ids, images) = self.create_images();
+        if self.footer.is_some() {
+            self.document_rels.has_footer = true;
+            self.content_type = std::mem::take(&mut self.content_type).add_footer(1);
+            self.document.section_property = std::mem::take(&mut self.document.section_property)
+                .footer_reference(FooterReference::new("default", create_footer_rid(1)));
+        }
+
+        if self.vba_project.is_some() {
+            self.document_rels.has_vba_project = true;
+            self.content_type = std::mem::take(&mut self.content_type).add_vba_project();
+        }
+
+        if self.theme.is_some() {
+            self.document_rels.has_theme = true;
+            self.content_type = std::mem::take(&mut self.content_type).add_theme();
+        }
+
+        let (image_ids, mut images) = self.create_images();
+        let (section_headers, section_footers) = self.create_section_headers();
+        let alt_chunks = self.create_alt_chunks();
+
+        let numbering_rels = if self.numberings.picture_bullets.is_empty() {
+            None
+        } else {
+            let mut rels = NumberingRels::new();
+            // The images are kept in the bullets, so building again writes them all the same.
+            for b in self.numberings.picture_bullets.iter() {
+                rels.image_ids.push(b.pic.id);
+                images.push((b.pic.id, b.pic.image.clone()));
+            }
+            Some(rels.build())
+        };
 
         let web_extensions = self.web_extensions.iter().map(|ext| ext.build()).collect();
         let custom_items = self.custom_items.iter().map(|xml| xml.build()).collect();
@@ -331,6 +734,24 @@ impl Docx {
             .collect();
 
         self.document_rels.image_ids = image_ids;
+        // Parts are written with relationships of their own, so only the others are carried over.
+        self.document_rels.relationships = self
+            .relationships
+            .iter()
+            .filter(|r| !is_part_relationship(&r.rel_type))
+            .cloned()
+            .collect();
+
+        // webSettings.xml is written only when it has something to configure.
+        let web_settings = if self.web_settings.optimize_for_browser
+            || !self.web_settings.divs.is_empty()
+        {
+            self.document_rels.has_web_settings = true;
+            self.content_type = self.content_type.clone().add_web_settings();
+            Some(self.web_settings.build())
+        } else {
+            None
+        };
 
         XMLDocx {
             content_type: self.content_type.build(),
@@ -341,10 +762,18 @@ impl Docx {
             comments: self.comments.build(),
             document_rels: self.document_rels.build(),
             settings: self.settings.build(),
+            web_settings,
             font_table: self.font_table.build(),
             numberings: self.numberings.build(),
+            numbering_rels,
             media: images,
             header: self.header.build(),
+            footer: self.footer.as_ref().map(|f| f.build()),
+            section_headers,
+            section_footers,
+            alt_chunks,
+            vba_project: self.vba_project.clone(),
+            theme: self.theme.clone(),
             comments_extended: self.comments_extended.build(),
             taskpanes: self.taskpanes.map(|taskpanes| taskpanes.build()),
             taskpanes_rels: self.taskpanes_rels.build(),
@@ -352,6 +781,7 @@ impl Docx {
             custom_items,
             custom_item_rels,
             custom_item_props,
+            options,
         }
     }
 
@@ -369,10 +799,129 @@ impl Docx {
         serde_json::to_string_pretty(&self).unwrap()
     }
 
+    // Assign relationship ids to altChunks and collect their parts.
+    fn create_alt_chunks(&mut self) -> Vec<(String, Vec<u8>)> {
+        let mut parts = vec![];
+        let mut rels = vec![];
+        for child in self.document.children.iter_mut() {
+            if let DocumentChild::AltChunk(c) = child {
+                let id = parts.len() + 1;
+                let part_name = c.part_name(id);
+                c.id = create_alt_chunk_rid(id);
+                self.content_type = std::mem::take(&mut self.content_type)
+                    .add_alt_chunk(&part_name, c.chunk_type);
+                rels.push((c.id.clone(), part_name.clone()));
+                parts.push((part_name, c.data.clone()));
+            }
+        }
+        self.document_rels.alt_chunks = rels;
+        parts
+    }
+
+    // Resolve header and footer links of sections into references and parts.
+    // Sections with a blank first page refer to empty first page header and footer parts.
+    fn create_section_headers(&mut self) -> (Parts, Parts) {
+        let mut headers = vec![];
+        let mut footers = vec![];
+        for child in self.document.children.iter_mut() {
+            if let DocumentChild::Paragraph(p) = child {
+                if let Some(property) = p.property.section_property.take() {
+                    p.property.section_property =
+                        Some(add_blank_first_page(property, &mut headers, &mut footers));
+                }
+            }
+            if let DocumentChild::Section(s) = child {
+                for (t, linked) in s.header_links.clone() {
+                    let mut property = std::mem::take(&mut s.property);
+                    if linked {
+                        property = property.remove_header_reference(t);
+                    } else {
+                        let id = headers.len() + 2;
+                        let header = s
+                            .headers
+                            .iter()
+                            .find(|(h, _)| *h == t)
+                            .map(|(_, h)| h.build())
+                            .unwrap_or_else(|| Header::new().build());
+                        property = property.header_reference(HeaderReference::new(
+                            t.to_string(),
+                            create_header_rid(id),
+                        ));
+                        headers.push((id, header));
+                    }
+                    s.property = property;
+                }
+                for (t, linked) in s.footer_links.clone() {
+                    let mut property = std::mem::take(&mut s.property);
+                    if linked {
+                        property = property.remove_footer_reference(t);
+                    } else {
+                        let id = footers.len() + 2;
+                        let footer = s
+                            .footers
+                            .iter()
+                            .find(|(f, _)| *f == t)
+                            .map(|(_, f)| f.build())
+                            .unwrap_or_else(|| Footer::new().build());
+                        property = property.footer_reference(FooterReference::new(
+                            t.to_string(),
+                            create_footer_rid(id),
+                        ));
+                        footers.push((id, footer));
+                    }
+                    s.property = property;
+                }
+                let property = std::mem::take(&mut s.property);
+                s.property = add_blank_first_page(property, &mut headers, &mut footers);
+            }
+        }
+        let property = std::mem::take(&mut self.document.section_property);
+        self.document.section_property = add_blank_first_page(property, &mut headers, &mut footers);
+        self.document_rels.header_ids = headers.iter().map(|(id, _)| *id).collect();
+        self.document_rels.footer_ids = footers.iter().map(|(id, _)| *id).collect();
+        for (id, _) in headers.iter() {
+            self.content_type = self.content_type.clone().add_header(*id);
+        }
+        for (id, _) in footers.iter() {
+            self.content_type = self.content_type.clone().add_footer(*id);
+        }
+        (headers, footers)
+    }
+
+    fn resolve_revisions(mut self, r: Revision) -> Docx {
+        let children = std::mem::take(&mut self.document.children);
+        self.document.children = r.resolve_children(children);
+        for c in self.document.children.iter_mut() {
+            if let DocumentChild::Section(s) = c {
+                for (_, h) in s.headers.iter_mut() {
+                    *h = r.resolve_header(std::mem::take(h));
+                }
+            }
+        }
+        self.header = r.resolve_header(self.header);
+        self.footer = self.footer.map(|f| r.resolve_footer(f));
+        self
+    }
+
     fn reset(&self) {
         crate::reset_para_id();
     }
 
+    // Register every rsid used in the body so settings lists them all.
+    fn update_rsids(&mut self) {
+        let mut rsids = vec![];
+        for child in &self.document.children {
+            match child {
+                DocumentChild::Paragraph(p) => collect_paragraph_rsids(p, &mut rsids),
+                DocumentChild::Table(t) => collect_table_rsids(t, &mut rsids),
+                _ => {}
+            }
+        }
+        for rsid in rsids {
+            self.settings = std::mem::take(&mut self.settings).add_rsid(rsid);
+        }
+    }
+
     // Traverse and clone comments from document and add to comments node.
     fn update_comments(&mut self) {
         let mut comments: Vec<Comment> = vec![];
@@ -681,3 +1230,88 @@ impl Docx {
         (image_ids, images)
     }
 }
+
+// Id and content of header or footer parts.
+type Parts = Vec<(usize, Vec<u8>)>;
+
+// Refer the first page of a section with a blank first page to new, empty header and footer parts.
+fn add_blank_first_page(
+    property: SectionProperty,
+    headers: &mut Parts,
+    footers: &mut Parts,
+) -> SectionProperty {
+    if !property.has_blank_first_page() {
+        return property;
+    }
+    let header_id = headers.len() + 2;
+    headers.push((header_id, Header::new().add_paragraph(Paragraph::new()).build()));
+    let footer_id = footers.len() + 2;
+    footers.push((footer_id, Footer::new().add_paragraph(Paragraph::new()).build()));
+    let first = crate::types::HeaderFooterReferenceType::First.to_string();
+    property
+        .header_reference(HeaderReference::new(first.clone(), create_header_rid(header_id)))
+        .footer_reference(FooterReference::new(first, create_footer_rid(footer_id)))
+}
+
+fn collect_paragraph_rsids(p: &Paragraph, rsids: &mut Vec<String>) {
+    let run_rsids = |r: &Run, rsids: &mut Vec<String>| {
+        rsids.extend(r.run_property.rsids.values().into_iter().map(String::from))
+    };
+    rsids.extend(p.property.rsids.values().into_iter().map(String::from));
+    for c in &p.children {
+        match c {
+            ParagraphChild::Run(r) => run_rsids(r, rsids),
+            ParagraphChild::Insert(i) => {
+                for c in &i.children {
+                    if let InsertChild::Run(r) = c {
+                        run_rsids(r, rsids);
+                    }
+                }
+            }
+            ParagraphChild::Delete(d) => {
+                for c in &d.children {
+                    if let DeleteChild::Run(r) = c {
+                        run_rsids(r, rsids);
+                    }
+                }
+            }
+            ParagraphChild::MoveFrom(m) => {
+                for r in &m.children {
+                    run_rsids(r, rsids);
+                }
+            }
+            ParagraphChild::MoveTo(m) => {
+                for r in &m.children {
+                    run_rsids(r, rsids);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn optimize_table(t: &mut Table) {
+    for row in t.rows.iter_mut() {
+        for cell in row.cells.iter_mut() {
+            for c in cell.children.iter_mut() {
+                match c {
+                    TableCellContent::Paragraph(p) => p.merge_runs(),
+                    TableCellContent::Table(t) => optimize_table(t),
+                }
+            }
+        }
+    }
+}
+
+fn collect_table_rsids(t: &Table, rsids: &mut Vec<String>) {
+    for row in &t.rows {
+        for cell in &row.cells {
+            for c in &cell.children {
+                match c {
+                    TableCellContent::Paragraph(p) => collect_paragraph_rsids(p, rsids),
+                    TableCellContent::Table(t) => collect_table_rsids(t, rsids),
+                }
+            }
+        }
+    }
+}
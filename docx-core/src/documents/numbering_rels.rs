@@ -0,0 +1,35 @@
+use serde::Serialize;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NumberingRels {
+    pub image_ids: Vec<usize>,
+}
+
+impl NumberingRels {
+    pub fn new() -> NumberingRels {
+        Default::default()
+    }
+}
+
+impl BuildXML for NumberingRels {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new()
+            .declaration(None)
+            .open_relationships("http://schemas.openxmlformats.org/package/2006/relationships");
+
+        for id in self.image_ids.iter() {
+            b = b.relationship(
+                &create_pic_rid(*id),
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
+                &format!("media/image{}.jpg", *id),
+            )
+        }
+
+        b.close().build()
+    }
+}
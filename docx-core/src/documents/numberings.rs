@@ -8,8 +8,9 @@ use serde::Serialize;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Numberings {
-    abstract_nums: Vec<AbstractNumbering>,
-    numberings: Vec<Numbering>,
+    pub(crate) picture_bullets: Vec<NumberingPictureBullet>,
+    pub(crate) abstract_nums: Vec<AbstractNumbering>,
+    pub(crate) numberings: Vec<Numbering>,
 }
 
 impl Numberings {
@@ -17,6 +18,11 @@ impl Numberings {
         Default::default()
     }
 
+    pub fn add_picture_bullet(mut self, b: NumberingPictureBullet) -> Self {
+        self.picture_bullets.push(b);
+        self
+    }
+
     pub fn add_abstract_numbering(mut self, n: AbstractNumbering) -> Self {
         self.abstract_nums.push(n);
         self
@@ -31,6 +37,7 @@ impl Numberings {
 impl Default for Numberings {
     fn default() -> Self {
         Self {
+            picture_bullets: vec![],
             abstract_nums: vec![],
             numberings: vec![],
         }
@@ -40,6 +47,9 @@ impl Default for Numberings {
 impl BuildXML for Numberings {
     fn build(&self) -> Vec<u8> {
         let mut b = XMLBuilder::new().declaration(Some(true)).open_numbering();
+        for p in &self.picture_bullets {
+            b = b.add_child(p);
+        }
         b = b.add_child(&create_default_numbering());
         for n in &self.abstract_nums {
             b = b.add_child(n);
@@ -52,7 +62,7 @@ impl BuildXML for Numberings {
     }
 }
 
-fn create_default_numbering() -> AbstractNumbering {
+pub(crate) fn create_default_numbering() -> AbstractNumbering {
     AbstractNumbering::new(1)
         .add_level(
             Level::new(
@@ -62,7 +72,12 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelText::new("%1."),
                 LevelJc::new("left"),
             )
-            .indent(Some(420), Some(SpecialIndentType::Hanging(420)), None, None),
+            .indent(
+                Some(Twip(420)),
+                Some(SpecialIndentType::Hanging(420)),
+                None,
+                None,
+            ),
         )
         .add_level(
             Level::new(
@@ -72,7 +87,12 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelText::new("(%2)"),
                 LevelJc::new("left"),
             )
-            .indent(Some(840), Some(SpecialIndentType::Hanging(420)), None, None),
+            .indent(
+                Some(Twip(840)),
+                Some(SpecialIndentType::Hanging(420)),
+                None,
+                None,
+            ),
         )
         .add_level(
             Level::new(
@@ -83,7 +103,7 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelJc::new("left"),
             )
             .indent(
-                Some(1260),
+                Some(Twip(1260)),
                 Some(SpecialIndentType::Hanging(420)),
                 None,
                 None,
@@ -98,7 +118,7 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelJc::new("left"),
             )
             .indent(
-                Some(1680),
+                Some(Twip(1680)),
                 Some(SpecialIndentType::Hanging(420)),
                 None,
                 None,
@@ -113,7 +133,7 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelJc::new("left"),
             )
             .indent(
-                Some(2100),
+                Some(Twip(2100)),
                 Some(SpecialIndentType::Hanging(420)),
                 None,
                 None,
@@ -128,7 +148,7 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelJc::new("left"),
             )
             .indent(
-                Some(2520),
+                Some(Twip(2520)),
                 Some(SpecialIndentType::Hanging(420)),
                 None,
                 None,
@@ -143,7 +163,7 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelJc::new("left"),
             )
             .indent(
-                Some(2940),
+                Some(Twip(2940)),
                 Some(SpecialIndentType::Hanging(420)),
                 None,
                 None,
@@ -158,7 +178,7 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelJc::new("left"),
             )
             .indent(
-                Some(3360),
+                Some(Twip(3360)),
                 Some(SpecialIndentType::Hanging(420)),
                 None,
                 None,
@@ -173,7 +193,7 @@ fn create_default_numbering() -> AbstractNumbering {
                 LevelJc::new("left"),
             )
             .indent(
-                Some(3780),
+                Some(Twip(3780)),
                 Some(SpecialIndentType::Hanging(420)),
                 None,
                 None,
@@ -0,0 +1,90 @@
+use serde::Serialize;
+
+// Compatibility toggles which are written into <w:compat> of settings.xml.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintSettings {
+    pub space_for_ul: bool,
+    pub balance_single_byte_double_byte_width: bool,
+    pub do_not_leave_backslash_alone: bool,
+    pub ul_trail_space: bool,
+    pub do_not_expand_shift_return: bool,
+    pub adjust_line_height_table: bool,
+    pub use_fe_layout: bool,
+    pub auto_space_like_word95: bool,
+}
+
+impl PrintSettings {
+    pub fn new() -> PrintSettings {
+        Default::default()
+    }
+
+    // All toggles are disabled. The reader starts from this when <w:compat> is found.
+    pub(crate) fn empty() -> PrintSettings {
+        PrintSettings {
+            space_for_ul: false,
+            balance_single_byte_double_byte_width: false,
+            do_not_leave_backslash_alone: false,
+            ul_trail_space: false,
+            do_not_expand_shift_return: false,
+            adjust_line_height_table: false,
+            use_fe_layout: false,
+            auto_space_like_word95: false,
+        }
+    }
+
+    pub fn space_for_ul(mut self, v: bool) -> Self {
+        self.space_for_ul = v;
+        self
+    }
+
+    pub fn balance_single_byte_double_byte_width(mut self, v: bool) -> Self {
+        self.balance_single_byte_double_byte_width = v;
+        self
+    }
+
+    pub fn do_not_leave_backslash_alone(mut self, v: bool) -> Self {
+        self.do_not_leave_backslash_alone = v;
+        self
+    }
+
+    pub fn ul_trail_space(mut self, v: bool) -> Self {
+        self.ul_trail_space = v;
+        self
+    }
+
+    pub fn do_not_expand_shift_return(mut self, v: bool) -> Self {
+        self.do_not_expand_shift_return = v;
+        self
+    }
+
+    pub fn adjust_line_height_table(mut self, v: bool) -> Self {
+        self.adjust_line_height_table = v;
+        self
+    }
+
+    pub fn use_fe_layout(mut self, v: bool) -> Self {
+        self.use_fe_layout = v;
+        self
+    }
+
+    pub fn auto_space_like_word95(mut self, v: bool) -> Self {
+        self.auto_space_like_word95 = v;
+        self
+    }
+}
+
+impl Default for PrintSettings {
+    fn default() -> Self {
+        Self {
+            space_for_ul: true,
+            balance_single_byte_double_byte_width: true,
+            do_not_leave_backslash_alone: true,
+            ul_trail_space: true,
+            do_not_expand_shift_return: true,
+            adjust_line_height_table: true,
+            use_fe_layout: true,
+            auto_space_like_word95: false,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::types::*;
+
+pub const ATTACHED_TEMPLATE_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/attachedTemplate";
+pub const HYPERLINK_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Relationship {
+    pub id: String,
+    pub rel_type: String,
+    // Kept as written in the part, i.e. relative to the part for internal targets.
+    pub target: String,
+    pub target_mode: TargetMode,
+}
+
+impl Relationship {
+    pub fn new(
+        id: impl Into<String>,
+        rel_type: impl Into<String>,
+        target: impl Into<String>,
+    ) -> Relationship {
+        Relationship {
+            id: id.into(),
+            rel_type: rel_type.into(),
+            target: target.into(),
+            target_mode: TargetMode::Internal,
+        }
+    }
+
+    // A hyperlink target outside of the package, i.e. a web address or a file path.
+    pub fn hyperlink(id: impl Into<String>, target: impl Into<String>) -> Relationship {
+        Relationship::new(id, HYPERLINK_TYPE, target).target_mode(TargetMode::External)
+    }
+
+    pub fn target_mode(mut self, mode: TargetMode) -> Self {
+        self.target_mode = mode;
+        self
+    }
+
+    // Targets with a scheme such as https: or mailto: can't be parts of the package,
+    // so they are external even when the mode is not set.
+    pub fn is_external(&self) -> bool {
+        self.target_mode == TargetMode::External || has_uri_scheme(&self.target)
+    }
+}
+
+// i.e. "https://example.com" or "mailto:someone@example.com", but not "media/image1.png".
+// Single letter schemes are drive letters of Windows paths such as "C:\doc.docx", which are
+// external all the same but must be marked so explicitly.
+fn has_uri_scheme(target: &str) -> bool {
+    match target.find(':') {
+        Some(i) if i > 1 => target[..i]
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.'),
+        _ => false,
+    }
+}
+
+// The relationships of the main document part keyed by relationship id.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+pub struct Relationships {
+    rels: BTreeMap<String, Relationship>,
+}
+
+impl Relationships {
+    pub fn new() -> Relationships {
+        Default::default()
+    }
+
+    pub fn add_relationship(mut self, rel: Relationship) -> Self {
+        self.rels.insert(rel.id.clone(), rel);
+        self
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Relationship> {
+        self.rels.get(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Relationship> {
+        self.rels.values()
+    }
+
+    pub fn external(&self) -> impl Iterator<Item = &Relationship> {
+        self.rels.values().filter(|r| r.is_external())
+    }
+
+    pub fn len(&self) -> usize {
+        self.rels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rels.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_is_external() {
+        assert!(Relationship::hyperlink("rIdLink1", "C:\\docs\\spec.docx").is_external());
+        assert!(Relationship::new("rIdLink2", HYPERLINK_TYPE, "https://example.com").is_external());
+        assert!(
+            Relationship::new("rIdLink3", HYPERLINK_TYPE, "mailto:a@example.com").is_external()
+        );
+        assert!(!Relationship::new("rId1", HYPERLINK_TYPE, "media/image1.png").is_external());
+    }
+}
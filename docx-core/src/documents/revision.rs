@@ -0,0 +1,388 @@
+use super::*;
+
+// Flatten tracked insertions, deletions, moves and formatting changes into plain content.
+// Accepting keeps inserted and moved-to runs and drops deleted and moved-from ones,
+// rejecting does the inverse and restores the formatting recorded by rPrChange.
+// A removed paragraph mark merges the paragraph into the following one,
+// which keeps its own paragraph properties.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Revision {
+    Accept,
+    Reject,
+}
+
+impl Revision {
+    pub(crate) fn resolve_children(self, children: Vec<DocumentChild>) -> Vec<DocumentChild> {
+        let mut resolved = vec![];
+        let mut pending: Option<Paragraph> = None;
+        for c in children {
+            match c {
+                DocumentChild::Paragraph(p) => {
+                    let removes_mark = self.removes_mark(&p);
+                    let mut p = self.resolve_paragraph(p);
+                    if let Some(prev) = pending.take() {
+                        p.children = prev.children.into_iter().chain(p.children).collect();
+                    }
+                    if removes_mark {
+                        pending = Some(p);
+                    } else {
+                        resolved.push(DocumentChild::Paragraph(p));
+                    }
+                }
+                // A paragraph only merges into a directly following paragraph.
+                c => {
+                    if let Some(prev) = pending.take() {
+                        resolved.push(DocumentChild::Paragraph(prev));
+                    }
+                    match c {
+                        DocumentChild::Table(t) => {
+                            resolved.push(DocumentChild::Table(self.resolve_table(t)))
+                        }
+                        c => resolved.push(c),
+                    }
+                }
+            }
+        }
+        if let Some(prev) = pending {
+            resolved.push(DocumentChild::Paragraph(prev));
+        }
+        resolved
+    }
+
+    pub(crate) fn resolve_header(self, header: Header) -> Header {
+        let children = header
+            .children
+            .into_iter()
+            .map(|c| match c {
+                HeaderChild::Paragraph(p) => DocumentChild::Paragraph(p),
+                HeaderChild::Table(t) => DocumentChild::Table(t),
+            })
+            .collect();
+        let children = self
+            .resolve_children(children)
+            .into_iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(HeaderChild::Paragraph(p)),
+                DocumentChild::Table(t) => Some(HeaderChild::Table(t)),
+                _ => None,
+            })
+            .collect();
+        Header { children }
+    }
+
+    pub(crate) fn resolve_footer(self, footer: Footer) -> Footer {
+        let children = footer
+            .children
+            .into_iter()
+            .map(|c| match c {
+                FooterChild::Paragraph(p) => DocumentChild::Paragraph(p),
+                FooterChild::Table(t) => DocumentChild::Table(t),
+            })
+            .collect();
+        let children = self
+            .resolve_children(children)
+            .into_iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(FooterChild::Paragraph(p)),
+                DocumentChild::Table(t) => Some(FooterChild::Table(t)),
+                _ => None,
+            })
+            .collect();
+        Footer { children }
+    }
+
+    fn resolve_comment(self, c: &mut Comment) {
+        let children = c
+            .children
+            .drain(..)
+            .map(|c| match c {
+                CommentChild::Paragraph(p) => DocumentChild::Paragraph(p),
+                CommentChild::Table(t) => DocumentChild::Table(t),
+            })
+            .collect();
+        c.children = self
+            .resolve_children(children)
+            .into_iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(CommentChild::Paragraph(p)),
+                DocumentChild::Table(t) => Some(CommentChild::Table(t)),
+                _ => None,
+            })
+            .collect();
+    }
+
+    fn resolve_run_property(self, rp: &mut RunProperty) {
+        if let Some(change) = rp.property_change.take() {
+            if self == Revision::Reject {
+                let rsids = std::mem::take(&mut rp.rsids);
+                *rp = *change.property;
+                rp.rsids = rsids;
+            }
+        }
+    }
+
+    fn removes_mark(self, p: &Paragraph) -> bool {
+        match self {
+            Revision::Accept => p.property.run_property.del.is_some(),
+            Revision::Reject => p.property.run_property.ins.is_some(),
+        }
+    }
+
+    fn resolve_table(self, mut t: Table) -> Table {
+        for row in t.rows.iter_mut() {
+            for cell in row.cells.iter_mut() {
+                let children = cell
+                    .children
+                    .drain(..)
+                    .map(|c| match c {
+                        TableCellContent::Paragraph(p) => DocumentChild::Paragraph(p),
+                        TableCellContent::Table(t) => DocumentChild::Table(t),
+                    })
+                    .collect();
+                cell.children = self
+                    .resolve_children(children)
+                    .into_iter()
+                    .filter_map(|c| match c {
+                        DocumentChild::Paragraph(p) => Some(TableCellContent::Paragraph(p)),
+                        DocumentChild::Table(t) => Some(TableCellContent::Table(t)),
+                        _ => None,
+                    })
+                    .collect();
+            }
+        }
+        t
+    }
+
+    fn resolve_paragraph(self, mut p: Paragraph) -> Paragraph {
+        let mut children = vec![];
+        for c in p.children.drain(..) {
+            match c {
+                ParagraphChild::Run(run) => children.push(ParagraphChild::Run(run)),
+                ParagraphChild::Insert(ins) => {
+                    for c in ins.children {
+                        match c {
+                            InsertChild::Run(run) => {
+                                if self == Revision::Accept {
+                                    children.push(ParagraphChild::Run(run));
+                                }
+                            }
+                            // Text inserted and deleted again never survives.
+                            InsertChild::Delete(del) => {
+                                children.extend(del.children.into_iter().filter_map(comment_only))
+                            }
+                            InsertChild::CommentStart(c) => {
+                                children.push(ParagraphChild::CommentStart(c))
+                            }
+                            InsertChild::CommentEnd(c) => {
+                                children.push(ParagraphChild::CommentEnd(c))
+                            }
+                        }
+                    }
+                }
+                ParagraphChild::Delete(del) => {
+                    for c in del.children {
+                        match c {
+                            DeleteChild::Run(run) if self == Revision::Reject => {
+                                children.push(ParagraphChild::Run(Box::new(restore_run(*run))))
+                            }
+                            c => children.extend(comment_only(c)),
+                        }
+                    }
+                }
+                ParagraphChild::MoveFrom(m) => {
+                    if self == Revision::Reject {
+                        children.extend(
+                            m.children
+                                .into_iter()
+                                .map(|run| ParagraphChild::Run(Box::new(restore_run(run)))),
+                        );
+                    }
+                }
+                ParagraphChild::MoveTo(m) => {
+                    if self == Revision::Accept {
+                        children.extend(
+                            m.children
+                                .into_iter()
+                                .map(|run| ParagraphChild::Run(Box::new(run))),
+                        );
+                    }
+                }
+                _ => children.push(c),
+            }
+        }
+        for c in children.iter_mut() {
+            match c {
+                ParagraphChild::Run(run) => self.resolve_run_property(&mut run.run_property),
+                ParagraphChild::CommentStart(c) => self.resolve_comment(&mut c.comment),
+                _ => {}
+            }
+        }
+        p.children = children;
+        self.resolve_run_property(&mut p.property.run_property);
+        p.property.run_property.ins = None;
+        p.property.run_property.del = None;
+        p
+    }
+}
+
+fn comment_only(c: DeleteChild) -> Option<ParagraphChild> {
+    match c {
+        DeleteChild::CommentStart(c) => Some(ParagraphChild::CommentStart(c)),
+        DeleteChild::CommentEnd(c) => Some(ParagraphChild::CommentEnd(c)),
+        DeleteChild::Run(_) => None,
+    }
+}
+
+fn restore_run(mut run: Run) -> Run {
+    run.children = run
+        .children
+        .into_iter()
+        .map(|c| match c {
+            RunChild::DeleteText(t) => RunChild::Text(t.into()),
+            _ => c,
+        })
+        .collect();
+    run
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::{AlignmentType, HeaderFooterReferenceType};
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn redlined() -> Vec<DocumentChild> {
+        let mut removed_mark = Paragraph::new()
+            .add_run(Run::new().add_text("Hello"))
+            .add_delete(Delete::new().add_run(Run::new().add_delete_text(" old")));
+        removed_mark.property.run_property = RunProperty::new().delete(Delete::new());
+        let next = Paragraph::new()
+            .add_insert(Insert::new(Run::new().add_text(" world")))
+            .align(AlignmentType::Center);
+        vec![
+            DocumentChild::Paragraph(removed_mark),
+            DocumentChild::Paragraph(next),
+        ]
+    }
+
+    #[test]
+    fn test_accept_merges_deleted_paragraph_mark() {
+        let children = Revision::Accept.resolve_children(redlined());
+        assert_eq!(
+            children,
+            vec![DocumentChild::Paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hello"))
+                    .add_run(Run::new().add_text(" world"))
+                    .align(AlignmentType::Center)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_reject_restores_deleted_runs() {
+        let children = Revision::Reject.resolve_children(redlined());
+        assert_eq!(
+            children,
+            vec![
+                DocumentChild::Paragraph(
+                    Paragraph::new()
+                        .add_run(Run::new().add_text("Hello"))
+                        .add_run(Run::new().add_text(" old"))
+                ),
+                DocumentChild::Paragraph(Paragraph::new().align(AlignmentType::Center)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_accept_does_not_merge_across_sections() {
+        let mut children = redlined();
+        children.insert(1, DocumentChild::Section(Box::new(Section::new())));
+        let children = Revision::Accept.resolve_children(children);
+        assert_eq!(
+            children,
+            vec![
+                DocumentChild::Paragraph(Paragraph::new().add_run(Run::new().add_text("Hello"))),
+                DocumentChild::Section(Box::new(Section::new())),
+                DocumentChild::Paragraph(
+                    Paragraph::new()
+                        .add_run(Run::new().add_text(" world"))
+                        .align(AlignmentType::Center)
+                ),
+            ]
+        );
+    }
+
+    fn moved_and_reformatted() -> Vec<DocumentChild> {
+        let mut reformatted = Run::new().add_text("c").bold();
+        reformatted.run_property = reformatted
+            .run_property
+            .property_change(RunPropertyChange::new(RunProperty::new().italic()));
+        vec![DocumentChild::Paragraph(
+            Paragraph::new()
+                .add_move_from(MoveFrom::new().add_run(Run::new().add_delete_text("a")))
+                .add_move_to(MoveTo::new().add_run(Run::new().add_text("b")))
+                .add_run(reformatted),
+        )]
+    }
+
+    #[test]
+    fn test_accept_moves_and_formatting() {
+        let children = Revision::Accept.resolve_children(moved_and_reformatted());
+        assert_eq!(
+            children,
+            vec![DocumentChild::Paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("b"))
+                    .add_run(Run::new().add_text("c").bold())
+            )]
+        );
+    }
+
+    #[test]
+    fn test_reject_moves_and_formatting() {
+        let children = Revision::Reject.resolve_children(moved_and_reformatted());
+        assert_eq!(
+            children,
+            vec![DocumentChild::Paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("a"))
+                    .add_run(Run::new().add_text("c").italic())
+            )]
+        );
+    }
+
+    #[test]
+    fn test_resolve_footer_and_section_headers() {
+        let tracked = || {
+            Paragraph::new()
+                .add_insert(Insert::new(Run::new().add_text("new")))
+                .add_delete(Delete::new().add_run(Run::new().add_delete_text("old")))
+        };
+        let docx = Docx::new()
+            .add_section(Section::new().header(
+                HeaderFooterReferenceType::First,
+                Header::new().add_paragraph(tracked()),
+            ))
+            .footer(Footer::new().add_paragraph(tracked()))
+            .accept_all_revisions();
+        let accepted = Paragraph::new().add_run(Run::new().add_text("new"));
+        assert_eq!(
+            docx.footer,
+            Some(Footer::new().add_paragraph(accepted.clone()))
+        );
+        match &docx.document.children[0] {
+            DocumentChild::Section(s) => assert_eq!(
+                s.headers,
+                vec![(
+                    HeaderFooterReferenceType::First,
+                    Header::new().add_paragraph(accepted)
+                )]
+            ),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::documents::quote_field_argument;
+
+// Switches of a SEQ field, which numbers captions sharing the same identifier.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeqField {
+    // Defaults to the caption label, i.e. "Figure".
+    pub identifier: Option<String>,
+    // Number format such as "ARABIC" or "ROMAN".
+    pub format: Option<String>,
+    // Restart numbering after each heading of this level.
+    pub reset_level: Option<usize>,
+}
+
+impl SeqField {
+    pub fn new() -> SeqField {
+        Default::default()
+    }
+
+    pub fn identifier(mut self, id: impl Into<String>) -> Self {
+        self.identifier = Some(id.into());
+        self
+    }
+
+    pub fn format(mut self, f: impl Into<String>) -> Self {
+        self.format = Some(f.into());
+        self
+    }
+
+    pub fn reset_level(mut self, level: usize) -> Self {
+        self.reset_level = Some(level);
+        self
+    }
+
+    pub(crate) fn instr(&self, label: &str) -> String {
+        let id = self.identifier.as_deref().unwrap_or(label);
+        let mut instr = format!(" SEQ {}", quote_field_argument(id));
+        if let Some(f) = &self.format {
+            instr = format!("{} \\* {}", instr, f);
+        }
+        if let Some(l) = self.reset_level {
+            instr = format!("{} \\s {}", instr, l);
+        }
+        instr + " "
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_seq_instr() {
+        let s = SeqField::new().format("ARABIC").reset_level(1);
+        assert_eq!(s.instr("Figure"), r#" SEQ "Figure" \* ARABIC \s 1 "#);
+    }
+}
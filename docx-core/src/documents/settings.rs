@@ -4,6 +4,7 @@ use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,6 +13,14 @@ pub struct Settings {
     zoom: Zoom,
     doc_id: Option<DocId>,
     doc_vars: Vec<DocVar>,
+    track_revisions: bool,
+    document_protection: Option<DocumentProtection>,
+    auto_hyphenation: bool,
+    footnote_properties: Option<FootnoteProperties>,
+    endnote_properties: Option<EndnoteProperties>,
+    rsids: Vec<String>,
+    theme_font_lang: Option<LangSettings>,
+    print_settings: PrintSettings,
 }
 
 impl Settings {
@@ -33,6 +42,131 @@ impl Settings {
         self.doc_vars.push(DocVar::new(name, val));
         self
     }
+
+    // Replaces the value of an existing variable instead of adding a duplicate.
+    pub fn set_doc_var(mut self, name: impl Into<String>, val: impl Into<String>) -> Self {
+        let v = DocVar::new(name, val);
+        if let Some(existing) = self.doc_vars.iter_mut().find(|d| d.name == v.name) {
+            *existing = v;
+        } else {
+            self.doc_vars.push(v);
+        }
+        self
+    }
+
+    pub fn get_doc_var(&self, name: &str) -> Option<&str> {
+        self.doc_vars
+            .iter()
+            .find(|d| d.name == name)
+            .map(|d| d.val.as_str())
+    }
+
+    pub fn doc_vars(&self) -> BTreeMap<String, String> {
+        self.doc_vars
+            .iter()
+            .map(|d| (d.name.clone(), d.val.clone()))
+            .collect()
+    }
+
+    pub fn zoom(mut self, percent: usize) -> Self {
+        self.zoom = Zoom::new(percent);
+        self
+    }
+
+    pub fn track_revisions(mut self, v: bool) -> Self {
+        self.track_revisions = v;
+        self
+    }
+
+    pub fn document_protection(mut self, p: DocumentProtection) -> Self {
+        self.document_protection = Some(p);
+        self
+    }
+
+    pub fn theme_font_lang(mut self, l: LangSettings) -> Self {
+        self.theme_font_lang = Some(l);
+        self
+    }
+
+    pub fn auto_hyphenation(mut self, v: bool) -> Self {
+        self.auto_hyphenation = v;
+        self
+    }
+
+    pub fn footnote_properties(mut self, p: FootnoteProperties) -> Self {
+        self.footnote_properties = Some(p);
+        self
+    }
+
+    pub fn endnote_properties(mut self, p: EndnoteProperties) -> Self {
+        self.endnote_properties = Some(p);
+        self
+    }
+
+    pub fn print_settings(mut self, s: PrintSettings) -> Self {
+        self.print_settings = s;
+        self
+    }
+
+    pub fn add_rsid(mut self, rsid: impl Into<String>) -> Self {
+        let rsid = rsid.into();
+        if !self.rsids.contains(&rsid) {
+            self.rsids.push(rsid);
+        }
+        self
+    }
+
+    // Drops the docId and rsids, which identify a single document and its editing sessions.
+    pub(crate) fn clear_document_ids(mut self) -> Self {
+        self.doc_id = None;
+        self.rsids = vec![];
+        self
+    }
+
+    pub fn get_default_tab_stop(&self) -> usize {
+        self.default_tab_stop.val
+    }
+
+    pub fn get_zoom(&self) -> usize {
+        self.zoom.val
+    }
+
+    pub fn get_doc_id(&self) -> Option<&str> {
+        self.doc_id.as_ref().map(|d| d.id.as_str())
+    }
+
+    pub fn get_track_revisions(&self) -> bool {
+        self.track_revisions
+    }
+
+    pub fn get_document_protection(&self) -> Option<&DocumentProtection> {
+        self.document_protection.as_ref()
+    }
+
+    pub fn get_auto_hyphenation(&self) -> bool {
+        self.auto_hyphenation
+    }
+
+    pub fn get_footnote_properties(&self) -> Option<&FootnoteProperties> {
+        self.footnote_properties.as_ref()
+    }
+
+    pub fn get_endnote_properties(&self) -> Option<&EndnoteProperties> {
+        self.endnote_properties.as_ref()
+    }
+
+    // The compatibility toggles of <w:compat>.
+    pub fn get_print_settings(&self) -> &PrintSettings {
+        &self.print_settings
+    }
+
+    pub fn get_theme_font_lang(&self) -> Option<&LangSettings> {
+        self.theme_font_lang.as_ref()
+    }
+
+    pub fn get_rsids(&self) -> &[String] {
+        &self.rsids
+    }
 }
 
 impl Default for Settings {
@@ -42,6 +176,14 @@ impl Default for Settings {
             zoom: Zoom::new(100),
             doc_id: None,
             doc_vars: vec![],
+            track_revisions: false,
+            document_protection: None,
+            auto_hyphenation: false,
+            footnote_properties: None,
+            endnote_properties: None,
+            rsids: vec![],
+            theme_font_lang: None,
+            print_settings: PrintSettings::new(),
         }
     }
 }
@@ -53,15 +195,49 @@ impl BuildXML for Settings {
             .declaration(Some(true))
             .open_settings()
             .add_child(&self.default_tab_stop)
-            .add_child(&self.zoom)
-            .open_compat()
-            .space_for_ul()
-            .balance_single_byte_double_byte_width()
-            .do_not_leave_backslash_alone()
-            .ul_trail_space()
-            .do_not_expand_shift_return()
-            .adjust_line_height_table()
-            .use_fe_layout()
+            .add_child(&self.zoom);
+
+        if self.track_revisions {
+            b = b.track_revisions();
+        }
+
+        b = b.add_optional_child(&self.document_protection);
+        if self.auto_hyphenation {
+            b = b.auto_hyphenation();
+        }
+
+        b = b
+            .add_optional_child(&self.footnote_properties)
+            .add_optional_child(&self.endnote_properties)
+            .open_compat();
+
+        let p = &self.print_settings;
+        if p.space_for_ul {
+            b = b.space_for_ul();
+        }
+        if p.balance_single_byte_double_byte_width {
+            b = b.balance_single_byte_double_byte_width();
+        }
+        if p.do_not_leave_backslash_alone {
+            b = b.do_not_leave_backslash_alone();
+        }
+        if p.ul_trail_space {
+            b = b.ul_trail_space();
+        }
+        if p.do_not_expand_shift_return {
+            b = b.do_not_expand_shift_return();
+        }
+        if p.adjust_line_height_table {
+            b = b.adjust_line_height_table();
+        }
+        if p.auto_space_like_word95 {
+            b = b.auto_space_like_word95();
+        }
+        if p.use_fe_layout {
+            b = b.use_fe_layout();
+        }
+
+        b = b
             .compat_setting(
                 "compatibilityMode",
                 "http://schemas.microsoft.com/office/word",
@@ -102,6 +278,17 @@ impl BuildXML for Settings {
             }
             b = b.close();
         }
+
+        if !self.rsids.is_empty() {
+            b = b.open_rsids();
+            for r in self.rsids.iter() {
+                b = b.rsid(r);
+            }
+            b = b.close();
+        }
+        if let Some(l) = &self.theme_font_lang {
+            b = b.theme_font_lang(&l.attrs());
+        }
         b.close().build()
     }
 }
@@ -139,4 +326,76 @@ mod tests {
 </w:settings>"#
         );
     }
+
+    #[test]
+    fn test_settings_with_print_settings() {
+        let c = Settings::new().print_settings(
+            PrintSettings::new()
+                .do_not_expand_shift_return(false)
+                .balance_single_byte_double_byte_width(false)
+                .auto_space_like_word95(true),
+        );
+        let b = c.build();
+        let s = str::from_utf8(&b).unwrap();
+        assert!(s.contains(
+            r#"<w:compat>
+    <w:spaceForUL />
+    <w:doNotLeaveBackslashAlone />
+    <w:ulTrailSpace />
+    <w:adjustLineHeightInTable />
+    <w:autoSpaceLikeWord95 />
+    <w:useFELayout />
+    <w:compatSetting"#
+        ));
+    }
+
+    #[test]
+    fn test_settings_with_track_revisions() {
+        let c = Settings::new()
+            .track_revisions(true)
+            .add_rsid("00D63D33")
+            .add_rsid("00D63D33");
+        let b = c.build();
+        let s = str::from_utf8(&b).unwrap();
+        assert!(s.contains(r#"<w:zoom w:percent="100" /><w:trackRevisions />"#));
+        assert!(s.ends_with(
+            r#"  </w:compat>
+  <w:rsids>
+    <w:rsid w:val="00D63D33" />
+  </w:rsids>
+</w:settings>"#
+        ));
+    }
+
+    #[test]
+    fn test_settings_set_doc_var() {
+        let c = Settings::new()
+            .set_doc_var("Client", "ACME")
+            .set_doc_var("Step", "1")
+            .set_doc_var("Step", "2");
+        assert_eq!(c.get_doc_var("Step"), Some("2"));
+        let b = c.build();
+        let s = str::from_utf8(&b).unwrap();
+        assert!(s.contains(
+            r#"<w:docVar w:name="Client" w:val="ACME" /><w:docVar w:name="Step" w:val="2" />"#
+        ));
+    }
+
+    #[test]
+    fn test_settings_doc_var_with_special_characters() {
+        let c = Settings::new().set_doc_var("A&B", r#"{"a":1} & <b>"#);
+        let b = c.build();
+        let s = str::from_utf8(&b).unwrap();
+        assert!(s.contains(
+            r#"<w:docVar w:name="A&amp;B" w:val="{&quot;a&quot;:1} &amp; &lt;b&gt;" />"#
+        ));
+    }
+    #[test]
+    fn test_settings_with_theme_font_lang() {
+        let c =
+            Settings::new().theme_font_lang(LangSettings::new().val("en-US").east_asia("ja-JP"));
+        let b = c.build();
+        let s = str::from_utf8(&b).unwrap();
+        assert!(s.contains(r#"<w:themeFontLang w:val="en-US" w:eastAsia="ja-JP" />"#));
+    }
 }
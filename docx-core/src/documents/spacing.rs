@@ -0,0 +1,217 @@
+use super::*;
+use crate::types::Twip;
+
+// Resolves the spacing Word lays out for a paragraph. Spacing of docDefaults is overridden by
+// the paragraph style, applied from its root base style, and finally by direct formatting.
+// With contextual spacing, the spacing before and after is dropped next to a paragraph
+// of the same style. Contextual spacing is taken from the nearest property which sets it.
+pub(crate) fn resolve_spacing(docx: &Docx, p: &Paragraph) -> LineSpacing {
+    let (mut spacing, contextual) = resolve_paragraph_spacing(&docx.styles, p);
+    if !contextual {
+        return spacing;
+    }
+    if let Some((prev, next)) = find_neighbors(&docx.document.children, &p.id) {
+        let style = style_id(p);
+        if prev.map(|prev| style_id(prev) == style).unwrap_or_default() {
+            spacing = spacing.before(Some(Twip(0)));
+        }
+        if next.map(|next| style_id(next) == style).unwrap_or_default() {
+            spacing = spacing.after(Some(Twip(0)));
+        }
+    }
+    spacing
+}
+
+fn resolve_paragraph_spacing(styles: &Styles, p: &Paragraph) -> (LineSpacing, bool) {
+    let mut properties = vec![];
+    if let Some(defaults) = styles.get_doc_defaults().get_paragraph_property() {
+        properties.push(defaults);
+    }
+    properties.extend(
+        style_chain(styles, style_id(p))
+            .into_iter()
+            .rev()
+            .map(|s| &s.paragraph_property),
+    );
+    properties.push(&p.property);
+
+    let mut spacing = LineSpacing::default();
+    let mut contextual = false;
+    for property in properties {
+        if let Some(s) = &property.line_spacing {
+            spacing = spacing.merge(s);
+        }
+        if let Some(v) = property.contextual_spacing {
+            contextual = v;
+        }
+    }
+    (spacing, contextual)
+}
+
+// Paragraphs without pStyle use Normal, as ParagraphStyle does.
+pub(crate) fn style_id(p: &Paragraph) -> &str {
+    p.property
+        .style
+        .as_ref()
+        .map(|s| s.val())
+        .unwrap_or("Normal")
+}
+
+// The style followed by its base styles, stopping at a cycle.
+pub(crate) fn style_chain<'a>(styles: &'a Styles, id: &str) -> Vec<&'a Style> {
+    let mut chain: Vec<&Style> = vec![];
+    let mut next = styles.find_style_by_id(id);
+    while let Some(s) = next {
+        if chain.iter().any(|c| c.style_id == s.style_id) {
+            break;
+        }
+        chain.push(s);
+        next = s
+            .based_on
+            .as_ref()
+            .and_then(|b| styles.find_style_by_id(b.val()));
+    }
+    chain
+}
+
+type Neighbors<'a> = (Option<&'a Paragraph>, Option<&'a Paragraph>);
+
+// The paragraphs directly before and after the paragraph of the id, in the body or a cell.
+fn find_neighbors<'a>(children: &'a [DocumentChild], id: &str) -> Option<Neighbors<'a>> {
+    let siblings: Vec<Option<&Paragraph>> = children
+        .iter()
+        .map(|c| match c {
+            DocumentChild::Paragraph(p) => Some(p),
+            _ => None,
+        })
+        .collect();
+    if let Some(n) = neighbors_in(&siblings, id) {
+        return Some(n);
+    }
+    children.iter().find_map(|c| match c {
+        DocumentChild::Table(t) => find_neighbors_in_table(t, id),
+        _ => None,
+    })
+}
+
+fn find_neighbors_in_table<'a>(t: &'a Table, id: &str) -> Option<Neighbors<'a>> {
+    for row in &t.rows {
+        for cell in &row.cells {
+            let siblings: Vec<Option<&Paragraph>> = cell
+                .children
+                .iter()
+                .map(|c| match c {
+                    TableCellContent::Paragraph(p) => Some(p),
+                    _ => None,
+                })
+                .collect();
+            if let Some(n) = neighbors_in(&siblings, id) {
+                return Some(n);
+            }
+            for c in &cell.children {
+                if let TableCellContent::Table(t) = c {
+                    if let Some(n) = find_neighbors_in_table(t, id) {
+                        return Some(n);
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+fn neighbors_in<'a>(siblings: &[Option<&'a Paragraph>], id: &str) -> Option<Neighbors<'a>> {
+    let i = siblings
+        .iter()
+        .position(|p| p.map(|p| p.id == id).unwrap_or_default())?;
+    let prev = if i > 0 { siblings[i - 1] } else { None };
+    let next = siblings.get(i + 1).copied().flatten();
+    Some((prev, next))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn list_item(para_id: &str) -> Paragraph {
+        let mut p = Paragraph::new()
+            .add_run(Run::new().add_text("item"))
+            .style("List");
+        p.id = para_id.to_owned();
+        p
+    }
+
+    fn docx() -> Docx {
+        Docx::new().styles(
+            Styles::new()
+                .default_line_spacing(
+                    LineSpacing::new(None)
+                        .after(Some(Twip(160)))
+                        .line(Some(259)),
+                )
+                .add_style(Style::new("Normal", StyleType::Paragraph))
+                .add_style(
+                    Style::new("List", StyleType::Paragraph)
+                        .based_on("Normal")
+                        .line_spacing(LineSpacing::new(None).before(Some(Twip(120))))
+                        .contextual_spacing(true),
+                ),
+        )
+    }
+
+    #[test]
+    fn test_resolve_spacing() {
+        let docx = docx()
+            .add_paragraph(list_item("a"))
+            .add_paragraph(list_item("b"))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("plain")));
+        let first = list_item("a");
+        let last = list_item("b");
+        assert_eq!(
+            resolve_spacing(&docx, &first),
+            LineSpacing::new(None)
+                .before(Some(Twip(120)))
+                .after(Some(Twip(0)))
+                .line(Some(259))
+        );
+        assert_eq!(
+            resolve_spacing(&docx, &last),
+            LineSpacing::new(None)
+                .before(Some(Twip(0)))
+                .after(Some(Twip(160)))
+                .line(Some(259))
+        );
+    }
+
+    #[test]
+    fn test_resolve_spacing_with_direct_formatting() {
+        let p = list_item("a").spacing(LineSpacing::new(None).after(Some(Twip(240))));
+        let docx = docx().add_paragraph(p.clone());
+        assert_eq!(
+            resolve_spacing(&docx, &p),
+            LineSpacing::new(None)
+                .before(Some(Twip(120)))
+                .after(Some(Twip(240)))
+                .line(Some(259))
+        );
+    }
+
+    #[test]
+    fn test_resolve_spacing_without_contextual_spacing() {
+        let p = list_item("a").contextual_spacing(false);
+        let docx = docx()
+            .add_paragraph(p.clone())
+            .add_paragraph(list_item("b"));
+        assert_eq!(
+            resolve_spacing(&docx, &p),
+            LineSpacing::new(None)
+                .before(Some(Twip(120)))
+                .after(Some(Twip(160)))
+                .line(Some(259))
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use serde::Serialize;
+
+use super::*;
+use crate::types::BreakType;
+
+// Word's own page count needs a layout, so pages are estimated from the page breaks Word
+// rendered when it last saved the document. Without these hints, i.e. for generated documents,
+// page breaks and an average amount of characters per page are used.
+const CHARACTERS_PER_PAGE: usize = 3000;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentStatistics {
+    pub words: usize,
+    pub characters: usize,
+    pub characters_with_spaces: usize,
+    pub paragraphs: usize,
+    pub pages: usize,
+}
+
+// Counts the text as it is displayed with tracked changes accepted,
+// i.e. inserted runs are counted and deleted runs are not.
+pub(crate) fn collect_statistics(document: &Document) -> DocumentStatistics {
+    let mut counter = Counter::default();
+    for c in &document.children {
+        counter.document_child(c);
+    }
+    // A section break starts a new page unless the following section starts continuously.
+    counter.page_breaks += document
+        .sections()
+        .iter()
+        .skip(1)
+        .filter(|s| s.property.starts_new_page())
+        .count();
+    let estimated = if counter.rendered_page_breaks > 0 {
+        counter.rendered_page_breaks
+    } else {
+        counter.statistics.characters_with_spaces.saturating_sub(1) / CHARACTERS_PER_PAGE
+    };
+    counter.statistics.pages = std::cmp::max(counter.page_breaks, estimated) + 1;
+    counter.statistics
+}
+
+#[derive(Default)]
+struct Counter {
+    statistics: DocumentStatistics,
+    page_breaks: usize,
+    rendered_page_breaks: usize,
+}
+
+impl Counter {
+    fn document_child(&mut self, c: &DocumentChild) {
+        match c {
+            DocumentChild::Paragraph(p) => self.paragraph(p),
+            DocumentChild::Table(t) => self.table(t),
+            _ => {}
+        }
+    }
+
+    fn table(&mut self, t: &Table) {
+        for row in &t.rows {
+            for cell in &row.cells {
+                for c in &cell.children {
+                    match c {
+                        TableCellContent::Paragraph(p) => self.paragraph(p),
+                        TableCellContent::Table(t) => self.table(t),
+                    }
+                }
+            }
+        }
+    }
+
+    fn paragraph(&mut self, p: &Paragraph) {
+        if p.property.page_break_before {
+            self.page_breaks += 1;
+        }
+        let mut text = String::new();
+        for c in &p.children {
+            match c {
+                ParagraphChild::Run(r) => self.run(r, &mut text),
+                ParagraphChild::Insert(i) => {
+                    for c in &i.children {
+                        if let InsertChild::Run(r) = c {
+                            self.run(r, &mut text);
+                        }
+                    }
+                }
+                // Moved text is counted where it is moved to, like inserted text.
+                ParagraphChild::MoveTo(m) => {
+                    for r in &m.children {
+                        self.run(r, &mut text);
+                    }
+                }
+                _ => {}
+            }
+        }
+        // Empty paragraphs are not counted, as in Word.
+        if text.trim().is_empty() {
+            return;
+        }
+        let s = &mut self.statistics;
+        s.paragraphs += 1;
+        s.words += text.split_whitespace().count();
+        s.characters += text.chars().filter(|c| !c.is_whitespace()).count();
+        s.characters_with_spaces += text.chars().count();
+    }
+
+    fn run(&mut self, r: &Run, text: &mut String) {
+        for c in &r.children {
+            match c {
+                RunChild::Text(t) => text.push_str(&t.text),
+                RunChild::Tab(_) => text.push('\t'),
+                RunChild::Break(b) => {
+                    if b.break_type == BreakType::Page {
+                        self.page_breaks += 1;
+                    }
+                    text.push(' ');
+                }
+                RunChild::LastRenderedPageBreak(_) => self.rendered_page_breaks += 1,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::SectionType;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_collect_statistics() {
+        let children = vec![
+            DocumentChild::Paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hello world"))
+                    .add_delete(Delete::new().add_run(Run::new().add_delete_text(" removed")))
+                    .add_insert(Insert::new(Run::new().add_text(" again")))
+                    .add_move_from(MoveFrom::new().add_run(Run::new().add_delete_text(" gone")))
+                    .add_move_to(MoveTo::new().add_run(Run::new().add_text(" here"))),
+            ),
+            DocumentChild::Paragraph(Paragraph::new()),
+            DocumentChild::Paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Two").add_break(BreakType::Page))
+                    .add_run(Run::new().add_tab().add_text("pages")),
+            ),
+        ];
+        assert_eq!(
+            collect_statistics(&Document {
+                children,
+                ..Default::default()
+            }),
+            DocumentStatistics {
+                words: 6,
+                characters: 27,
+                characters_with_spaces: 32,
+                paragraphs: 2,
+                pages: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_collect_statistics_with_rendered_page_breaks() {
+        let children = vec![
+            DocumentChild::Paragraph(Paragraph::new().add_run(Run::new().add_text("One"))),
+            DocumentChild::Paragraph(
+                Paragraph::new().add_run(Run::new().add_last_rendered_page_break().add_text("Two")),
+            ),
+            DocumentChild::Paragraph(
+                Paragraph::new().add_run(
+                    Run::new()
+                        .add_text("Th")
+                        .add_last_rendered_page_break()
+                        .add_text("ree"),
+                ),
+            ),
+        ];
+        let document = Document {
+            children,
+            ..Default::default()
+        };
+        assert_eq!(collect_statistics(&document).pages, 3);
+    }
+
+    #[test]
+    fn test_collect_statistics_with_sections() {
+        let document = Document::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("One")))
+            .add_section(Section::new())
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Two")))
+            .add_section(Section::new())
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Still two")))
+            .default_section_property(SectionProperty::new().section_type(SectionType::Continuous));
+        // The second section starts on a new page, the last one continues it.
+        assert_eq!(collect_statistics(&document).pages, 2);
+    }
+}
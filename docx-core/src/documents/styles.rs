@@ -27,7 +27,7 @@ impl Styles {
         self
     }
 
-    pub fn default_spacing(mut self, spacing: i32) -> Self {
+    pub fn default_spacing(mut self, spacing: impl Into<Twip>) -> Self {
         self.doc_defaults = self.doc_defaults.spacing(spacing);
         self
     }
@@ -37,6 +37,24 @@ impl Styles {
         self
     }
 
+    pub fn default_lang(mut self, l: LangSettings) -> Self {
+        self.doc_defaults = self.doc_defaults.lang(l);
+        self
+    }
+
+    pub fn default_line_spacing(mut self, s: LineSpacing) -> Self {
+        self.doc_defaults = self.doc_defaults.line_spacing(s);
+        self
+    }
+
+    pub fn find_style_by_id(&self, id: &str) -> Option<&Style> {
+        self.styles.iter().find(|s| s.style_id == id)
+    }
+
+    pub fn get_doc_defaults(&self) -> &DocDefaults {
+        &self.doc_defaults
+    }
+
     pub(crate) fn doc_defaults(mut self, doc_defaults: DocDefaults) -> Self {
         self.doc_defaults = doc_defaults;
         self
@@ -55,7 +73,9 @@ impl Default for Styles {
 impl BuildXML for Styles {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        let normal = Style::new("Normal", StyleType::Paragraph).name("Normal");
+        let normal = Style::new("Normal", StyleType::Paragraph)
+            .name("Normal")
+            .q_format();
         b.open_styles()
             .add_child(&self.doc_defaults)
             .add_child(&normal)
@@ -81,7 +101,7 @@ mod tests {
         let b = c.build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
-            r#"<w:styles xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 w15"><w:docDefaults><w:rPrDefault><w:rPr /></w:rPrDefault></w:docDefaults><w:style w:type="paragraph" w:styleId="Normal"><w:name w:val="Normal" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:basedOn w:val="Normal" /><w:next w:val="Normal" /><w:qFormat /></w:style><w:style w:type="paragraph" w:styleId="Title"><w:name w:val="TitleName" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:basedOn w:val="Normal" /><w:next w:val="Normal" /><w:qFormat /></w:style></w:styles>"#
+            r#"<w:styles xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 w15"><w:docDefaults><w:rPrDefault><w:rPr /></w:rPrDefault></w:docDefaults><w:style w:type="paragraph" w:styleId="Normal"><w:name w:val="Normal" /><w:qFormat /><w:pPr><w:rPr /></w:pPr><w:rPr /></w:style><w:style w:type="paragraph" w:styleId="Title"><w:name w:val="TitleName" /><w:pPr><w:rPr /></w:pPr><w:rPr /></w:style></w:styles>"#
         );
     }
 }
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use super::*;
+use crate::reader::{read_docx, ReaderError};
+
+// Parts of the base document which every document of a template starts from.
+#[derive(Debug, PartialEq)]
+struct TemplateParts {
+    styles: Styles,
+    numberings: Numberings,
+    settings: Settings,
+    font_table: FontTable,
+    theme: Option<Vec<u8>>,
+}
+
+// Stamps out documents with the styles, numberings, settings and theme of a base document,
+// i.e. a corporate template, which is read and parsed only once. Cloning the template and
+// handing it to other threads shares the parsed parts, while every new document gets
+// its own copy of them, since Docx owns its parts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocxTemplate {
+    parts: Arc<TemplateParts>,
+}
+
+impl DocxTemplate {
+    // The body, headers and media of the document are not part of the template.
+    pub fn new(docx: &Docx) -> DocxTemplate {
+        DocxTemplate {
+            parts: Arc::new(TemplateParts {
+                styles: docx.styles.clone(),
+                numberings: docx.numberings.clone(),
+                settings: docx.settings.clone(),
+                font_table: docx.font_table.clone(),
+                theme: docx.theme.clone(),
+            }),
+        }
+    }
+
+    pub fn read(buf: &[u8]) -> Result<DocxTemplate, ReaderError> {
+        Ok(DocxTemplate::new(&read_docx(buf)?))
+    }
+
+    pub fn get_styles(&self) -> &Styles {
+        &self.parts.styles
+    }
+
+    pub fn get_numberings(&self) -> &Numberings {
+        &self.parts.numberings
+    }
+
+    // An empty document with a copy of the parts of the template, to which only the body needs
+    // to be added. The docId and rsids of the base document are dropped, as they identify that document.
+    pub fn new_document(&self) -> Docx {
+        let parts = &self.parts;
+        let mut docx = Docx::new()
+            .styles(parts.styles.clone())
+            .numberings(parts.numberings.clone())
+            .settings(parts.settings.clone().clear_document_ids());
+        docx.font_table = parts.font_table.clone();
+        docx.theme = parts.theme.clone();
+        // Styles may refer to numberings although no paragraph of the body does.
+        docx.document_rels.has_numberings = !parts.numberings.numberings.is_empty();
+        docx
+    }
+}
+
+impl From<&Docx> for DocxTemplate {
+    fn from(docx: &Docx) -> Self {
+        DocxTemplate::new(docx)
+    }
+}
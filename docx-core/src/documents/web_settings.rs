@@ -1,21 +1,102 @@
 use serde::Serialize;
 
 use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WebSettings {
     pub divs: Vec<Div>,
+    pub optimize_for_browser: bool,
 }
 
 impl WebSettings {
     pub fn new() -> WebSettings {
         Default::default()
     }
+
+    pub fn optimize_for_browser(mut self, v: bool) -> Self {
+        self.optimize_for_browser = v;
+        self
+    }
+
+    pub fn add_div(mut self, d: Div) -> Self {
+        self.divs.push(d);
+        self
+    }
 }
 
 impl Default for WebSettings {
     fn default() -> Self {
-        Self { divs: vec![] }
+        Self {
+            divs: vec![],
+            optimize_for_browser: false,
+        }
+    }
+}
+
+impl BuildXML for WebSettings {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new()
+            .declaration(Some(true))
+            .open_web_settings();
+        if self.optimize_for_browser {
+            b = b.optimize_for_browser();
+        }
+        if !self.divs.is_empty() {
+            b = b.open_divs().add_children(&self.divs).close();
+        }
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_web_settings() {
+        let c = WebSettings::new().optimize_for_browser(true);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:webSettings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml">
+  <w:optimizeForBrowser />
+</w:webSettings>"#
+        );
+    }
+
+    #[test]
+    fn test_web_settings_with_divs() {
+        let c = WebSettings::new().add_div(
+            Div::new("1")
+                .margin_left(10)
+                .add_child(Div::new("2").margin_top(20)),
+        );
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:webSettings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml">
+  <w:divs><w:div w:id="1">
+  <w:marLeft w:val="10" />
+  <w:marRight w:val="0" />
+  <w:marTop w:val="0" />
+  <w:marBottom w:val="0" />
+  <w:divsChild><w:div w:id="2">
+  <w:marLeft w:val="0" />
+  <w:marRight w:val="0" />
+  <w:marTop w:val="20" />
+  <w:marBottom w:val="0" />
+</w:div></w:divsChild>
+</w:div></w:divs>
+</w:webSettings>"#
+        );
     }
 }
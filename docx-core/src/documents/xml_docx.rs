@@ -1,4 +1,4 @@
-use super::XMLDocProps;
+use super::{BuildOptions, XMLDocProps};
 
 use crate::zipper;
 use std::io::prelude::*;
@@ -14,10 +14,19 @@ pub struct XMLDocx {
     pub comments: Vec<u8>,
     pub document_rels: Vec<u8>,
     pub settings: Vec<u8>,
+    pub web_settings: Option<Vec<u8>>,
     pub font_table: Vec<u8>,
     pub numberings: Vec<u8>,
+    pub numbering_rels: Option<Vec<u8>>,
     pub media: Vec<(usize, Vec<u8>)>,
     pub header: Vec<u8>,
+    pub footer: Option<Vec<u8>>,
+    pub section_headers: Vec<(usize, Vec<u8>)>,
+    pub section_footers: Vec<(usize, Vec<u8>)>,
+    // Part name and content of the altChunk parts.
+    pub alt_chunks: Vec<(String, Vec<u8>)>,
+    pub vba_project: Option<Vec<u8>>,
+    pub theme: Option<Vec<u8>>,
     pub comments_extended: Vec<u8>,
     pub taskpanes: Option<Vec<u8>>,
     pub taskpanes_rels: Vec<u8>,
@@ -25,6 +34,7 @@ pub struct XMLDocx {
     pub custom_items: Vec<Vec<u8>>,
     pub custom_item_rels: Vec<Vec<u8>>,
     pub custom_item_props: Vec<Vec<u8>>,
+    pub options: BuildOptions,
 }
 
 impl XMLDocx {
@@ -6,6 +6,10 @@ pub enum DocxError {
     EmitterError(#[from] xml::writer::Error),
     #[error("Failed to zip XML documents.")]
     ZipError(#[from] zip::result::ZipError),
+    #[error("Invalid raw XML: {0}")]
+    RawXmlError(String),
+    #[error("Bookmark not found: {0}")]
+    BookmarkNotFound(String),
     #[error("Unknown error")]
     Unknown,
 }
@@ -0,0 +1,22 @@
+use xml::attribute::OwnedAttribute;
+
+use super::read;
+use crate::documents::Color;
+
+// Read a color from its value and theme attributes, i.e. ("color", "themeColor", "themeTint", "themeShade").
+pub fn read_color(attrs: &[OwnedAttribute], names: (&str, &str, &str, &str)) -> Option<Color> {
+    if let Some(theme_color) = read(attrs, names.1) {
+        let mut c = Color::theme(theme_color);
+        if let Some(tint) = read(attrs, names.2).and_then(|v| u8::from_str_radix(&v, 16).ok()) {
+            c = c.tint(tint);
+        }
+        if let Some(shade) = read(attrs, names.3).and_then(|v| u8::from_str_radix(&v, 16).ok()) {
+            c = c.shade(shade);
+        }
+        if let Some(val) = read(attrs, names.0).filter(|v| v != "auto") {
+            c = c.fallback(val);
+        }
+        return Some(c);
+    }
+    read(attrs, names.0).map(Color::from)
+}
@@ -0,0 +1,18 @@
+use xml::attribute::OwnedAttribute;
+
+use crate::documents::LangSettings;
+
+// Reads w:lang and w:themeFontLang, which share the attributes.
+pub fn read_lang(attrs: &[OwnedAttribute]) -> LangSettings {
+    let mut l = LangSettings::new();
+    for a in attrs {
+        let v = a.value.to_owned();
+        match a.name.local_name.as_str() {
+            "val" => l = l.val(v),
+            "eastAsia" => l = l.east_asia(v),
+            "bidi" => l = l.bidi(v),
+            _ => {}
+        }
+    }
+    l
+}
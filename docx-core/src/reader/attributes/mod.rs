@@ -1,19 +1,29 @@
 mod bool_value;
 mod border;
+mod color;
 mod id;
 mod indent;
 mod indent_level;
+mod lang;
 pub(crate) mod line_spacing;
 mod name;
+mod rsids;
+mod run_fonts;
+mod tab_stop;
 mod val;
 mod width;
 
 pub use bool_value::*;
 pub use border::*;
+pub use color::*;
 pub use id::*;
 pub use indent::*;
 pub use indent_level::*;
+pub use lang::*;
 pub use name::*;
+pub use rsids::*;
+pub use run_fonts::*;
+pub use tab_stop::*;
 pub use val::*;
 pub use width::*;
 
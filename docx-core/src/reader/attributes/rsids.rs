@@ -0,0 +1,19 @@
+use xml::attribute::OwnedAttribute;
+
+use crate::documents::Rsids;
+
+pub fn read_rsids(attrs: &[OwnedAttribute]) -> Rsids {
+    let mut rsids = Rsids::new();
+    for a in attrs {
+        let v = a.value.to_owned();
+        match a.name.local_name.as_str() {
+            "rsidR" => rsids = rsids.r(v),
+            "rsidRDefault" => rsids = rsids.r_default(v),
+            "rsidRPr" => rsids = rsids.r_pr(v),
+            "rsidP" => rsids = rsids.p(v),
+            "rsidDel" => rsids = rsids.del(v),
+            _ => {}
+        }
+    }
+    rsids
+}
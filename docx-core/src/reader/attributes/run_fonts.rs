@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+
+use crate::documents::RunFonts;
+use crate::types::*;
+
+pub fn read_run_fonts(attrs: &[OwnedAttribute]) -> RunFonts {
+    let mut f = RunFonts::new();
+    for a in attrs {
+        let v = a.value.to_owned();
+        match a.name.local_name.as_str() {
+            "ascii" => f = f.ascii(v),
+            "hAnsi" => f = f.hi_ansi(v),
+            "cs" => f = f.cs(v),
+            "eastAsia" => f = f.east_asia(v),
+            "asciiTheme" => f = f.ascii_theme(v),
+            "hAnsiTheme" => f = f.hi_ansi_theme(v),
+            "cstheme" => f = f.cs_theme(v),
+            "eastAsiaTheme" => f = f.east_asia_theme(v),
+            "hint" => {
+                if let Ok(h) = FontHintType::from_str(&v) {
+                    f = f.hint(h);
+                }
+            }
+            _ => {}
+        }
+    }
+    f
+}
@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+
+use crate::documents::TabStop;
+use crate::types::*;
+use crate::ReaderError;
+
+// Returns None for a tab without position, e.g. a <w:tab/> inside a run.
+pub fn read_tab_stop(attrs: &[OwnedAttribute]) -> Result<Option<TabStop>, ReaderError> {
+    let mut val = None;
+    let mut leader = None;
+    let mut pos = None;
+    for a in attrs {
+        let local_name = &a.name.local_name;
+        if local_name == "val" {
+            val = Some(TabValueType::from_str(&a.value)?);
+        } else if local_name == "leader" {
+            leader = Some(TabLeaderType::from_str(&a.value)?);
+        } else if local_name == "pos" {
+            pos = Some(f64::from_str(&a.value)? as usize);
+        }
+    }
+    match (val, pos) {
+        (Some(val), Some(pos)) => {
+            let mut t = TabStop::new(val, pos);
+            if let Some(leader) = leader {
+                t = t.leader(leader);
+            }
+            Ok(Some(t))
+        }
+        _ => Ok(None),
+    }
+}
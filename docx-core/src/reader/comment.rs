@@ -16,6 +16,9 @@ impl ElementReader for Comment {
         if let Some(author) = read(attrs, "author") {
             comment = comment.author(author);
         };
+        if let Some(initials) = read(attrs, "initials") {
+            comment = comment.initials(initials);
+        }
         if let Some(date) = read(attrs, "date") {
             comment = comment.date(date);
         }
@@ -22,7 +22,7 @@ impl ElementReader for Delete {
                         .expect("should convert to XMLElement");
                     match e {
                         XMLElement::Run => {
-                            del = del.add_run(Run::read(r, attrs)?);
+                            del = del.add_run(Run::read(r, &attributes)?);
                         }
                         XMLElement::CommentRangeStart => {
                             if let Some(id) = read(&attributes, "id") {
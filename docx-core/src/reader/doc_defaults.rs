@@ -5,6 +5,7 @@ use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
 use super::*;
+use crate::types::Twip;
 
 impl ElementReader for DocDefaults {
     fn read<R: Read>(
@@ -12,6 +13,7 @@ impl ElementReader for DocDefaults {
         _attrs: &[OwnedAttribute],
     ) -> Result<Self, ReaderError> {
         let mut doc_defaults = DocDefaults::new();
+        let mut in_paragraph_default = false;
         loop {
             let e = r.next();
             match e {
@@ -19,10 +21,32 @@ impl ElementReader for DocDefaults {
                     attributes, name, ..
                 }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
-                    if let XMLElement::RunProperty = e {
-                        let run_pr = RunProperty::read(r, &attributes)?;
-                        doc_defaults = doc_defaults.run_property(run_pr);
-                        continue;
+                    match e {
+                        XMLElement::RunProperty => {
+                            let run_pr = RunProperty::read(r, &attributes)?;
+                            // The run properties of pPrDefault apply to paragraph marks only.
+                            if !in_paragraph_default {
+                                doc_defaults = doc_defaults.run_property(run_pr);
+                            }
+                            continue;
+                        }
+                        XMLElement::ParagraphPropertyDefault => {
+                            in_paragraph_default = true;
+                        }
+                        XMLElement::Spacing if in_paragraph_default => {
+                            let (before, after, line, spacing_type) =
+                                attributes::line_spacing::read_line_spacing(&attributes)?;
+                            doc_defaults = doc_defaults.line_spacing(
+                                LineSpacing::new(spacing_type)
+                                    .before(before.map(Twip::from))
+                                    .after(after.map(Twip::from))
+                                    .line(line),
+                            );
+                        }
+                        XMLElement::ContextualSpacing if in_paragraph_default => {
+                            doc_defaults = doc_defaults.contextual_spacing(read_bool(&attributes));
+                        }
+                        _ => {}
                     }
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
@@ -5,6 +5,7 @@ use crate::reader::*;
 use xml::reader::{EventReader, XmlEvent};
 
 use super::{Paragraph, Table};
+use crate::types::AltChunkType;
 
 impl FromXML for Document {
     fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
@@ -38,6 +39,15 @@ impl FromXML for Document {
                             doc = doc.add_bookmark_end(e.id);
                             continue;
                         }
+                        XMLElement::AltChunk => {
+                            // The part is loaded after the document relationships are known.
+                            if let Some(id) = read(&attributes, "id") {
+                                let mut c = AltChunk::new(AltChunkType::Html, vec![]);
+                                c.id = id;
+                                doc = doc.add_alt_chunk(c);
+                            }
+                            continue;
+                        }
                         XMLElement::CommentRangeStart => {
                             if let Some(id) = read(&attributes, "id") {
                                 if let Ok(id) = usize::from_str(&id) {
@@ -71,3 +81,53 @@ impl FromXML for Document {
         Ok(doc)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_body_order() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:body>
+    <w:p><w:r><w:t>first</w:t></w:r></w:p>
+    <w:tbl><w:tr><w:tc><w:p /></w:tc></w:tr></w:tbl>
+    <w:p />
+    <w:tbl><w:tr><w:tc><w:p /></w:tc></w:tr></w:tbl>
+    <w:sdt>
+        <w:sdtContent>
+            <w:p><w:r><w:t>in sdt</w:t></w:r></w:p>
+            <w:tbl><w:tr><w:tc><w:p /></w:tc></w:tr></w:tbl>
+        </w:sdtContent>
+    </w:sdt>
+    <w:p><w:r><w:t>last</w:t></w:r></w:p>
+</w:body>
+</w:document>"#;
+        let doc = Document::from_xml(xml.as_bytes()).unwrap();
+        let kinds: Vec<&str> = doc
+            .children
+            .iter()
+            .map(|c| match c {
+                DocumentChild::Paragraph(p) if p.children.is_empty() => "empty",
+                DocumentChild::Paragraph(_) => "paragraph",
+                DocumentChild::Table(_) => "table",
+                _ => "other",
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "paragraph",
+                "table",
+                "empty",
+                "table",
+                "paragraph",
+                "table",
+                "paragraph"
+            ]
+        );
+    }
+}
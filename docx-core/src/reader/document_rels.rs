@@ -7,6 +7,7 @@ use xml::reader::{EventReader, XmlEvent};
 
 use super::errors::*;
 use super::*;
+use crate::types::TargetMode;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ReadDocumentRels {
@@ -77,6 +78,76 @@ fn read_rels_xml<R: Read>(
     Ok(rels)
 }
 
+// Read the relationships of the part keyed by relationship id, i.e. for the images referred from the part.
+pub fn read_rels_with_ids(
+    archive: &mut zip::read::ZipArchive<Cursor<&[u8]>>,
+    part_path: impl AsRef<Path>,
+) -> Result<BTreeMap<String, PathBuf>, ReaderError> {
+    let dir = &part_path
+        .as_ref()
+        .parent()
+        .ok_or(ReaderError::DocumentRelsNotFoundError)?;
+    let rels = read_relationships(archive, &part_path)?;
+    Ok(rels
+        .iter()
+        .map(|r| (r.id.clone(), dir.join(&r.target)))
+        .collect())
+}
+
+// Read the relationships of the part as written, including the external ones.
+pub fn read_relationships(
+    archive: &mut zip::read::ZipArchive<Cursor<&[u8]>>,
+    part_path: impl AsRef<Path>,
+) -> Result<Relationships, ReaderError> {
+    let p = find_rels_filename(&part_path)?;
+    let p = p.to_str().ok_or(ReaderError::DocumentRelsNotFoundError)?;
+    let data = read_zip(archive, p)?;
+    read_relationships_xml(&data[..])
+}
+
+fn read_relationships_xml<R: Read>(reader: R) -> Result<Relationships, ReaderError> {
+    let mut parser = EventReader::new(reader);
+    let mut rels = Relationships::new();
+    loop {
+        let e = parser.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                attributes, name, ..
+            }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if let XMLElement::Relationship = e {
+                    let mut rel = Relationship::new("", "", "");
+                    for a in attributes {
+                        let local_name = &a.name.local_name;
+                        if local_name == "Id" {
+                            rel.id = a.value.to_owned();
+                        } else if local_name == "Type" {
+                            rel.rel_type = a.value.to_owned();
+                        } else if local_name == "Target" {
+                            rel.target = a.value.to_owned();
+                        } else if local_name == "TargetMode" {
+                            // An unknown mode keeps the default, the target still tells external ones apart.
+                            if let Ok(mode) = TargetMode::from_str(&a.value) {
+                                rel.target_mode = mode;
+                            }
+                        }
+                    }
+                    rels = rels.add_relationship(rel);
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                if let XMLElement::Relationships = e {
+                    break;
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+    Ok(rels)
+}
+
 fn find_rels_filename(main_path: impl AsRef<Path>) -> Result<PathBuf, ReaderError> {
     let path = main_path.as_ref();
     let dir = path
@@ -90,3 +161,49 @@ fn find_rels_filename(main_path: impl AsRef<Path>) -> Result<PathBuf, ReaderErro
         .join(base)
         .with_extension("xml.rels"))
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_relationships_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml" />
+  <Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/attachedTemplate" Target="https://example.com/template.dotm" TargetMode="External" />
+</Relationships>"#;
+        let rels = read_relationships_xml(xml.as_bytes()).unwrap();
+        assert_eq!(rels.len(), 2);
+        assert_eq!(
+            rels.get("rId1"),
+            Some(&Relationship::new(
+                "rId1",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles",
+                "styles.xml"
+            ))
+        );
+        let external: Vec<&str> = rels.external().map(|r| r.id.as_str()).collect();
+        assert_eq!(external, vec!["rId2"]);
+    }
+
+    #[test]
+    fn test_read_relationships_xml_unknown_target_mode() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml" TargetMode="Remote" />
+  <Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="https://example.com" TargetMode="Remote" />
+</Relationships>"#;
+        let rels = read_relationships_xml(xml.as_bytes()).unwrap();
+        assert_eq!(rels.len(), 2);
+        assert_eq!(
+            rels.get("rId1").map(|r| r.target_mode),
+            Some(TargetMode::Internal)
+        );
+        let external: Vec<&str> = rels.external().map(|r| r.id.as_str()).collect();
+        assert_eq!(external, vec!["rId2"]);
+    }
+}
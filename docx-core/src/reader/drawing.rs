@@ -6,6 +6,7 @@ use std::str::FromStr;
 use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
+use super::wp_anchor::read_anchor;
 use super::*;
 
 impl ElementReader for Drawing {
@@ -24,9 +25,19 @@ impl ElementReader for Drawing {
                         .expect("should convert to XMLElement");
                     match e {
                         WpXMLElement::Anchor => {
-                            let anchor = WpAnchor::read(r, &attributes)?;
+                            let (anchor, text_box) = read_anchor(r, &attributes)?;
+                            if let Some(t) = text_box {
+                                drawing = drawing.text_box(t);
+                            }
                             drawing = drawing.add_anchor(anchor);
                         }
+                        // Anchored shapes are read by the anchor, so a shape here is inline.
+                        _ if WpsXMLElement::from_str(&name.local_name)
+                            == Ok(WpsXMLElement::Wsp) =>
+                        {
+                            let shape = Shape::read(r, &attributes)?;
+                            drawing = drawing.shape(shape);
+                        }
                         _ => {}
                     }
                 }
@@ -0,0 +1,45 @@
+use std::io::Read;
+
+use xml::reader::{EventReader, XmlEvent};
+
+// Elements which show something in a header or footer part.
+const CONTENT_ELEMENTS: [&str; 7] = [
+    "t",
+    "drawing",
+    "pict",
+    "object",
+    "sym",
+    "fldSimple",
+    "instrText",
+];
+
+// A header or footer part is blank unless it has text, drawings or fields.
+pub(crate) fn is_blank_header_footer<R: Read>(r: R) -> bool {
+    let parser = EventReader::new(r);
+    for e in parser {
+        match e {
+            Ok(XmlEvent::StartElement { name, .. })
+                if CONTENT_ELEMENTS.contains(&name.local_name.as_str()) =>
+            {
+                return false
+            }
+            Err(_) => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_is_blank_header_footer() {
+        let blank = r#"<w:hdr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:p><w:pPr><w:pStyle w:val="Header" /></w:pPr></w:p></w:hdr>"#;
+        assert!(is_blank_header_footer(blank.as_bytes()));
+        let text = r#"<w:ftr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:p><w:r><w:t>1</w:t></w:r></w:p></w:ftr>"#;
+        assert!(!is_blank_header_footer(text.as_bytes()));
+    }
+}
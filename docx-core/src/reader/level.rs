@@ -26,6 +26,8 @@ impl ElementReader for Level {
         let mut level_restart = None;
         let mut has_indent = false;
         let mut suffix = LevelSuffixType::Tab;
+        let mut picture_bullet_id = None;
+        let mut tabs = vec![];
 
         loop {
             let e = r.next();
@@ -56,6 +58,9 @@ impl ElementReader for Level {
                                 level_restart = Some(LevelRestart::new(v));
                             }
                         }
+                        XMLElement::LevelPictureBulletId => {
+                            picture_bullet_id = Some(usize::from_str(&attributes[0].value)?);
+                        }
                         XMLElement::LevelJustification => {
                             jc = LevelJc::new(attributes[0].value.clone());
                         }
@@ -67,6 +72,11 @@ impl ElementReader for Level {
                             start_chars = i.3;
                             has_indent = true;
                         }
+                        XMLElement::Tab => {
+                            if let Some(t) = read_tab_stop(&attributes)? {
+                                tabs.push(t);
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -79,9 +89,18 @@ impl ElementReader for Level {
                             l = l.paragraph_style(style_id);
                         }
                         if has_indent {
-                            l = l.indent(indent_start, special_indent, indent_end, start_chars);
+                            l = l.indent(
+                                indent_start.map(Twip),
+                                special_indent,
+                                indent_end.map(Twip),
+                                start_chars,
+                            );
                         }
                         l.level_restart = level_restart;
+                        l.picture_bullet_id = picture_bullet_id;
+                        for t in tabs {
+                            l = l.add_tab(t);
+                        }
                         return Ok(l);
                     }
                 }
@@ -17,11 +17,16 @@ mod document_rels;
 mod drawing;
 mod errors;
 mod from_xml;
+mod header_footer;
 mod ignore;
 mod insert;
 mod level;
 mod level_override;
 mod mc_fallback;
+mod move_from;
+mod move_to;
+mod note_properties;
+mod numbering_picture_bullet;
 mod numbering_property;
 mod numberings;
 mod paragraph;
@@ -29,9 +34,11 @@ mod read_zip;
 mod rels;
 mod run;
 mod run_property;
+mod run_property_change;
 mod section_property;
 mod settings;
 mod shading;
+mod shape;
 mod style;
 mod styles;
 mod table;
@@ -41,7 +48,9 @@ mod table_cell_borders;
 mod table_cell_margins;
 mod table_cell_property;
 mod table_property;
+mod table_property_exceptions;
 mod table_row;
+mod text_box;
 mod text_box_content;
 mod web_settings;
 mod wp_anchor;
@@ -50,8 +59,11 @@ mod wps_text_box;
 mod xml_element;
 
 use std::io::Cursor;
+use std::str::FromStr;
 
 use crate::documents::*;
+use crate::types::AltChunkType;
+use header_footer::is_blank_header_footer;
 
 pub use attributes::*;
 pub use document_rels::*;
@@ -76,6 +88,10 @@ const COMMENTS_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
 const WEB_SETTINGS_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/webSettings";
+const VBA_PROJECT_TYPE: &str =
+    "http://schemas.microsoft.com/office/2006/relationships/vbaProject";
+const THEME_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme";
 // 2011
 const COMMENTS_EXTENDED_TYPE: &str =
     "http://schemas.microsoft.com/office/2011/relationships/commentsExtended";
@@ -86,10 +102,11 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     let mut archive = zip::ZipArchive::new(cur)?;
     // First, the content type for relationship parts and the Main Document part
     // (the only required part) must be defined (physically located at /[Content_Types].xml in the package)
-    let _content_types = {
+    let content_types = {
         let data = read_zip(&mut archive, "[Content_Types].xml")?;
         ContentTypes::from_xml(&data[..])?
     };
+    docx.read_content_types = Some(content_types);
 
     // Next, the single required relationship (the package-level relationship to the Main Document part)
     //  must be defined (physically located at /_rels/.rels in the package)
@@ -119,6 +136,9 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     }
 
     let rels = read_document_rels(&mut archive, &document_path)?;
+    if let Ok(relationships) = read_relationships(&mut archive, &document_path) {
+        docx.relationships = relationships;
+    }
 
     // Read commentsExtended
     let comments_extended_path = rels.find_target_path(COMMENTS_EXTENDED_TYPE);
@@ -192,6 +212,68 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     };
     docx = docx.document(document);
 
+    // Read altChunk parts, which are referred from the document
+    let document_dir = std::path::Path::new(&document_path)
+        .parent()
+        .map(|d| d.to_path_buf())
+        .unwrap_or_default();
+    for child in docx.document.children.iter_mut() {
+        if let DocumentChild::AltChunk(c) = child {
+            let path = match docx.relationships.get(&c.id) {
+                Some(rel) => document_dir.join(&rel.target),
+                None => continue,
+            };
+            let path = path.to_str().unwrap_or_default().replace('\\', "/");
+            let content_type = docx
+                .read_content_types
+                .as_ref()
+                .and_then(|t| t.content_type_of(&format!("/{}", path)))
+                .and_then(|t| AltChunkType::from_str(t).ok());
+            let extension = std::path::Path::new(&path)
+                .extension()
+                .and_then(|e| e.to_str())
+                .and_then(AltChunkType::from_extension);
+            if let Some(t) = content_type.or(extension) {
+                c.chunk_type = t;
+            }
+            if let Ok(data) = read_zip(&mut archive, &path) {
+                c.data = data;
+            }
+        }
+    }
+
+    // Recognize blank first pages, whose first page header and footer parts are empty.
+    let relationships = &docx.relationships;
+    let section_properties = docx
+        .document
+        .children
+        .iter_mut()
+        .filter_map(|c| match c {
+            DocumentChild::Paragraph(p) => p.property.section_property.as_mut(),
+            _ => None,
+        })
+        .chain(std::iter::once(&mut docx.document.section_property));
+    for sp in section_properties {
+        let rids = std::mem::take(&mut sp.first_page_rids);
+        if !sp.has_title_pg() || rids.is_empty() {
+            continue;
+        }
+        let blank = rids.iter().all(|id| {
+            let path = match relationships.get(id) {
+                Some(rel) => document_dir.join(&rel.target),
+                None => return false,
+            };
+            let path = path.to_str().unwrap_or_default().replace('\\', "/");
+            match read_zip(&mut archive, &path) {
+                Ok(data) => is_blank_header_footer(&data[..]),
+                Err(_) => false,
+            }
+        });
+        if blank {
+            *sp = std::mem::take(sp).blank_first_page(true);
+        }
+    }
+
     // store comments to paragraphs.
     if !comments.inner().is_empty() {
         docx.store_comments(comments.inner());
@@ -218,7 +300,19 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
             &mut archive,
             num_path.to_str().expect("should have numberings"),
         )?;
-        let nums = Numberings::from_xml(&data[..])?;
+        let mut nums = Numberings::from_xml(&data[..])?;
+        if !nums.picture_bullets.is_empty() {
+            if let Ok(image_rels) = read_rels_with_ids(&mut archive, &num_path) {
+                for b in nums.picture_bullets.iter_mut() {
+                    let path = b.image_rid.as_ref().and_then(|id| image_rels.get(id));
+                    if let Some(path) = path.and_then(|p| p.to_str()) {
+                        if let Ok(image) = read_zip(&mut archive, path) {
+                            b.pic.image = image;
+                        }
+                    }
+                }
+            }
+        }
         docx = docx.numberings(nums);
     }
 
@@ -231,6 +325,23 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
         )?;
         let settings = Settings::from_xml(&data[..])?;
         docx = docx.settings(settings);
+        if let Ok(relationships) = read_relationships(&mut archive, &settings_path) {
+            docx.settings_relationships = relationships;
+        }
+    }
+
+    // Read the VBA project of a macro-enabled document
+    if let Some(vba_path) = rels.find_target_path(VBA_PROJECT_TYPE) {
+        if let Ok(data) = read_zip(&mut archive, vba_path.to_str().unwrap_or_default()) {
+            docx.vba_project = Some(data);
+        }
+    }
+
+    // Read the theme, which is kept as is
+    if let Some(theme_path) = rels.find_target_path(THEME_TYPE) {
+        if let Ok(data) = read_zip(&mut archive, theme_path.to_str().unwrap_or_default()) {
+            docx.theme = Some(data);
+        }
     }
 
     // Read web settings
@@ -0,0 +1,125 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::types::*;
+
+macro_rules! impl_note_properties_reader {
+    ($t: ident, $pos: ident, $end: expr) => {
+        impl ElementReader for $t {
+            fn read<R: Read>(
+                r: &mut EventReader<R>,
+                _attrs: &[OwnedAttribute],
+            ) -> Result<Self, ReaderError> {
+                let mut p = $t::new();
+                loop {
+                    let e = r.next();
+                    match e {
+                        Ok(XmlEvent::StartElement {
+                            attributes, name, ..
+                        }) => {
+                            let e = XMLElement::from_str(&name.local_name).unwrap();
+                            if let Some(val) = read_val(&attributes) {
+                                match e {
+                                    XMLElement::Position => {
+                                        if let Ok(v) = $pos::from_str(&val) {
+                                            p = p.position(v);
+                                        }
+                                    }
+                                    XMLElement::NumberFormat => {
+                                        p = p.number_format(val);
+                                    }
+                                    XMLElement::NumStart => {
+                                        if let Ok(v) = usize::from_str(&val) {
+                                            p = p.start(v);
+                                        }
+                                    }
+                                    XMLElement::NumRestart => {
+                                        if let Ok(v) = NoteRestartType::from_str(&val) {
+                                            p = p.restart(v);
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        Ok(XmlEvent::EndElement { name, .. }) => {
+                            let e = XMLElement::from_str(&name.local_name).unwrap();
+                            if e == $end {
+                                return Ok(p);
+                            }
+                        }
+                        Err(_) => return Err(ReaderError::XMLReadError),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_note_properties_reader!(
+    FootnoteProperties,
+    FootnotePositionType,
+    XMLElement::FootnoteProperty
+);
+impl_note_properties_reader!(
+    EndnoteProperties,
+    EndnotePositionType,
+    XMLElement::EndnoteProperty
+);
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_endnote_properties() {
+        let c = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:endnotePr>
+        <w:pos w:val="sectEnd" />
+        <w:numFmt w:val="lowerRoman" />
+        <w:numStart w:val="1" />
+        <w:numRestart w:val="eachSect" />
+        <w:endnote w:id="-1" />
+    </w:endnotePr>
+</w:settings>"#;
+        let s = Settings::from_xml(c.as_bytes()).unwrap();
+        assert_eq!(
+            s,
+            Settings::new().endnote_properties(
+                EndnoteProperties::new()
+                    .position(EndnotePositionType::SectEnd)
+                    .number_format("lowerRoman")
+                    .start(1)
+                    .restart(NoteRestartType::EachSection)
+            )
+        );
+    }
+    #[test]
+    fn test_read_footnote_only_position_in_endnote_properties() {
+        let c = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:footnotePr>
+        <w:pos w:val="beneathText" />
+    </w:footnotePr>
+    <w:endnotePr>
+        <w:pos w:val="beneathText" />
+    </w:endnotePr>
+</w:settings>"#;
+        let s = Settings::from_xml(c.as_bytes()).unwrap();
+        assert_eq!(
+            s,
+            Settings::new()
+                .footnote_properties(
+                    FootnoteProperties::new().position(FootnotePositionType::BeneathText)
+                )
+                .endnote_properties(EndnoteProperties::new())
+        );
+    }
+}
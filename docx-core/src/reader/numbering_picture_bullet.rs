@@ -0,0 +1,80 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+// i.e. style="width:9pt;height:9pt", read into EMU.
+fn read_shape_size(style: &str) -> (u32, u32) {
+    let mut size = (0, 0);
+    for s in style.split(';') {
+        let mut kv = s.split(':');
+        let (k, v) = (kv.next(), kv.next());
+        if let (Some(k), Some(v)) = (k, v) {
+            let emu = if v.ends_with("pt") {
+                f32::from_str(&v.replace("pt", "")).map(|v| (v * 12700.0).round() as u32)
+            } else {
+                f32::from_str(&v.replace("px", "")).map(|v| (v * 9525.0).round() as u32)
+            };
+            match (k.trim(), emu) {
+                ("width", Ok(emu)) => size.0 = emu,
+                ("height", Ok(emu)) => size.1 = emu,
+                _ => {}
+            }
+        }
+    }
+    size
+}
+
+impl ElementReader for NumberingPictureBullet {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut id = 0;
+        for a in attrs {
+            if a.name.local_name == "numPicBulletId" {
+                id = usize::from_str(&a.value)?;
+            }
+        }
+        let mut size = (0, 0);
+        let mut image_rid = None;
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = VXMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        VXMLElement::Shape => {
+                            if let Some(a) = attributes.iter().find(|a| a.name.local_name == "style")
+                            {
+                                size = read_shape_size(&a.value);
+                            }
+                        }
+                        VXMLElement::ImageData => {
+                            if let Some(a) = attributes.iter().find(|a| a.name.local_name == "id") {
+                                image_rid = Some(a.value.clone());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::NumberingPictureBullet {
+                        let pic = Pic::new_with_dimensions(vec![], size.0, size.1);
+                        let mut b = NumberingPictureBullet::new(id, pic);
+                        b.image_rid = image_rid;
+                        return Ok(b);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
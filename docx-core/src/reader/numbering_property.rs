@@ -35,13 +35,9 @@ impl ElementReader for NumberingProperty {
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     if e == XMLElement::NumberingProperty {
-                        if id.is_none() || level.is_none() {
-                            return Ok(NumberingProperty::new());
-                        }
-                        let np = NumberingProperty::new().add_num(
-                            NumberingId::new(id.unwrap()),
-                            IndentLevel::new(level.unwrap()),
-                        );
+                        let mut np = NumberingProperty::new();
+                        np.id = id.map(NumberingId::new);
+                        np.level = level.map(IndentLevel::new);
                         return Ok(np);
                     }
                 }
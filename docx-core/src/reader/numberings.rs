@@ -18,6 +18,11 @@ impl FromXML for Numberings {
                 }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     match e {
+                        XMLElement::NumberingPictureBullet => {
+                            let b = NumberingPictureBullet::read(&mut parser, &attributes)?;
+                            nums = nums.add_picture_bullet(b);
+                            continue;
+                        }
                         XMLElement::AbstractNumbering => {
                             let mut id = 0;
                             for a in attributes {
@@ -165,7 +170,7 @@ mod tests {
                         LevelJc::new("left"),
                     )
                     .indent(
-                        Some(720),
+                        Some(Twip(720)),
                         Some(SpecialIndentType::Hanging(360)),
                         None,
                         None,
@@ -176,6 +181,83 @@ mod tests {
         assert_eq!(n, nums)
     }
 
+    #[test]
+    fn test_numberings_from_xml_with_picture_bullet() {
+        let xml = r##"<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+            xmlns:v="urn:schemas-microsoft-com:vml" xmlns:o="urn:schemas-microsoft-com:office:office"
+            xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+    <w:numPicBullet w:numPicBulletId="0">
+        <w:pict>
+            <v:shape id="_x0000_i1025" type="#_x0000_t75" style="width:9pt;height:9pt" o:bullet="t">
+                <v:imagedata r:id="rId1" o:title=""/>
+            </v:shape>
+        </w:pict>
+    </w:numPicBullet>
+    <w:abstractNum w:abstractNumId="0">
+        <w:lvl w:ilvl="0">
+            <w:start w:val="1"/>
+            <w:numFmt w:val="bullet"/>
+            <w:lvlText w:val=""/>
+            <w:lvlPicBulletId w:val="0"/>
+            <w:lvlJc w:val="left"/>
+        </w:lvl>
+    </w:abstractNum>
+</w:numbering>"##;
+        let n = Numberings::from_xml(xml.as_bytes()).unwrap();
+        let mut bullet = NumberingPictureBullet::new(0, Pic::new_with_dimensions(vec![], 114300, 114300));
+        bullet.image_rid = Some("rId1".to_owned());
+        let nums = Numberings::new()
+            .add_picture_bullet(bullet)
+            .add_abstract_numbering(
+                AbstractNumbering::new(0).add_level(
+                    Level::new(
+                        0,
+                        Start::new(1),
+                        NumberFormat::new("bullet"),
+                        LevelText::new(""),
+                        LevelJc::new("left"),
+                    )
+                    .picture_bullet(0),
+                ),
+            );
+        assert_eq!(n, nums)
+    }
+
+    #[test]
+    fn test_numberings_from_xml_with_suffix_and_tab() {
+        let xml = r#"<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:abstractNum w:abstractNumId="0">
+        <w:lvl w:ilvl="0">
+            <w:start w:val="1"/>
+            <w:numFmt w:val="decimal"/>
+            <w:suff w:val="space"/>
+            <w:lvlText w:val="%1."/>
+            <w:lvlJc w:val="left"/>
+            <w:pPr>
+                <w:tabs>
+                    <w:tab w:val="num" w:leader="dot" w:pos="420"/>
+                </w:tabs>
+            </w:pPr>
+        </w:lvl>
+    </w:abstractNum>
+</w:numbering>"#;
+        let n = Numberings::from_xml(xml.as_bytes()).unwrap();
+        let nums = Numberings::new().add_abstract_numbering(
+            AbstractNumbering::new(0).add_level(
+                Level::new(
+                    0,
+                    Start::new(1),
+                    NumberFormat::new("decimal"),
+                    LevelText::new("%1."),
+                    LevelJc::new("left"),
+                )
+                .suffix(LevelSuffixType::Space)
+                .add_tab(TabStop::new(TabValueType::Num, 420).leader(TabLeaderType::Dot)),
+            ),
+        );
+        assert_eq!(n, nums)
+    }
+
     #[test]
     fn test_numberings_from_xml_with_num_style_link() {
         let xml = r#"<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
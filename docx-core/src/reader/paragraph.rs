@@ -18,6 +18,7 @@ impl ElementReader for Paragraph {
         if let Some(para_id) = read(attrs, "paraId") {
             p = p.id(para_id);
         }
+        p = p.rsids(read_rsids(attrs));
         loop {
             let e = r.next();
             match e {
@@ -30,7 +31,7 @@ impl ElementReader for Paragraph {
 
                     match e {
                         XMLElement::Run => {
-                            let run = Run::read(r, attrs)?;
+                            let run = Run::read(r, &attributes)?;
                             p = p.add_run(run);
                             continue;
                         }
@@ -44,6 +45,16 @@ impl ElementReader for Paragraph {
                             p = p.add_delete(del);
                             continue;
                         }
+                        XMLElement::MoveFrom => {
+                            let m = MoveFrom::read(r, &attributes)?;
+                            p = p.add_move_from(m);
+                            continue;
+                        }
+                        XMLElement::MoveTo => {
+                            let m = MoveTo::read(r, &attributes)?;
+                            p = p.add_move_to(m);
+                            continue;
+                        }
                         XMLElement::BookmarkStart => {
                             let s = BookmarkStart::read(r, &attributes)?;
                             p = p.add_bookmark_start(s.id, s.name);
@@ -74,7 +85,7 @@ impl ElementReader for Paragraph {
                         XMLElement::Indent => {
                             let (start, end, special, start_chars, hanging_chars, first_line_chars) =
                                 read_indent(&attributes)?;
-                            p = p.indent(start, special, end, start_chars);
+                            p = p.indent(start.map(Twip), special, end.map(Twip), start_chars);
 
                             if let Some(chars) = hanging_chars {
                                 p = p.hanging_chars(chars);
@@ -87,7 +98,12 @@ impl ElementReader for Paragraph {
                         XMLElement::Spacing => {
                             let (before, after, line, spacing_type) =
                                 attributes::line_spacing::read_line_spacing(&attributes)?;
-                            p = p.line_spacing(before, after, line, spacing_type);
+                            p = p.line_spacing(
+                                before.map(Twip::from),
+                                after.map(Twip::from),
+                                line,
+                                spacing_type,
+                            );
                             continue;
                         }
                         XMLElement::Justification => {
@@ -111,23 +127,41 @@ impl ElementReader for Paragraph {
                             }
                             continue;
                         }
+                        XMLElement::SectionProperty => {
+                            let e = SectionProperty::read(r, &attributes)?;
+                            p.property.section_property = Some(e);
+                            continue;
+                        }
                         XMLElement::RunProperty => {
                             let run_pr = RunProperty::read(r, attrs)?;
                             p = p.run_property(run_pr);
                             continue;
                         }
+                        XMLElement::Shading => {
+                            if let Ok(shd) = Shading::read(r, &attributes) {
+                                p = p.shading(shd);
+                            }
+                        }
                         XMLElement::KeepNext => {
                             p.property.keep_next = true;
                         }
                         XMLElement::KeepLines => {
                             p.property.keep_lines = true;
                         }
+                        XMLElement::ContextualSpacing => {
+                            p.property.contextual_spacing = Some(read_bool(&attributes));
+                        }
                         XMLElement::PageBreakBefore => {
                             p.property.page_break_before = true;
                         }
                         XMLElement::WindowControl => {
                             p.property.window_control = true;
                         }
+                        XMLElement::Tab => {
+                            if let Some(t) = read_tab_stop(&attributes)? {
+                                p = p.add_tab(t);
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -188,10 +222,33 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: false,
+                auto_id: true,
             }
         );
     }
 
+    #[test]
+    fn test_read_run_rsids() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:p>
+        <w:r w:rsidR="00A1" w:rsidRPr="00B2">
+            <w:t>a</w:t>
+        </w:r>
+    </w:p>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let p = Paragraph::read(&mut parser, &[]).unwrap();
+
+        assert_eq!(
+            p.children,
+            vec![ParagraphChild::Run(Box::new(
+                Run::new()
+                    .add_text("a")
+                    .rsids(Rsids::new().r("00A1").r_pr("00B2"))
+            ))]
+        );
+    }
+
     #[test]
     fn test_read_indent_start_chars() {
         let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
@@ -224,6 +281,7 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: false,
+                auto_id: true,
             }
         );
     }
@@ -255,6 +313,7 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: false,
+                auto_id: true,
             }
         );
     }
@@ -290,6 +349,7 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: true,
+                auto_id: true,
             }
         );
     }
@@ -327,6 +387,7 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: false,
+                auto_id: true,
             }
         );
     }
@@ -366,6 +427,7 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: false,
+                auto_id: true,
             }
         );
     }
@@ -404,6 +466,7 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: false,
+                auto_id: true,
             }
         );
     }
@@ -454,6 +517,7 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: false,
+                auto_id: true,
             }
         );
     }
@@ -496,6 +560,7 @@ mod tests {
                     ..Default::default()
                 },
                 has_numbering: false,
+                auto_id: true,
             }
         );
     }
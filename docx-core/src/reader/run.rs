@@ -9,21 +9,24 @@ use xml::reader::{EventReader, XmlEvent};
 use super::Run;
 
 use crate::reader::*;
-use crate::types::BreakType;
+use crate::types::{BreakClear, BreakType, FieldCharType};
+use crate::escape::escape;
 
 #[derive(PartialEq, Debug)]
 enum TextState {
     Idle,
     Text,
     Delete,
+    Instr,
 }
 
 impl ElementReader for Run {
     fn read<R: Read>(
         r: &mut EventReader<R>,
-        _attrs: &[OwnedAttribute],
+        attrs: &[OwnedAttribute],
     ) -> Result<Self, ReaderError> {
         let mut run = Run::new();
+        let rsids = read_rsids(attrs);
         let mut text_state = TextState::Idle;
         loop {
             let e = r.next();
@@ -47,12 +50,31 @@ impl ElementReader for Run {
                                 }
                                 XMLElement::Text => text_state = TextState::Text,
                                 XMLElement::DeleteText => text_state = TextState::Delete,
+                                XMLElement::InstrText => text_state = TextState::Instr,
+                                XMLElement::FieldChar => {
+                                    if let Some(t) = attributes::read(&attributes, "fldCharType") {
+                                        let dirty = attributes::read(&attributes, "dirty")
+                                            .map(|v| !is_false(&v))
+                                            .unwrap_or_default();
+                                        run =
+                                            run.add_field_char(FieldCharType::from_str(&t)?, dirty);
+                                    }
+                                }
                                 XMLElement::Break => {
-                                    if let Some(a) = &attributes.get(0) {
-                                        run = run.add_break(BreakType::from_str(&a.value)?)
-                                    } else {
-                                        run = run.add_break(BreakType::TextWrapping)
+                                    let t = match attributes::read(&attributes, "type") {
+                                        Some(t) => BreakType::from_str(&t)?,
+                                        None => BreakType::TextWrapping,
+                                    };
+                                    let mut br = Break::new(t);
+                                    if let Some(c) = attributes::read(&attributes, "clear")
+                                        .and_then(|c| BreakClear::from_str(&c).ok())
+                                    {
+                                        br = br.clear(c);
                                     }
+                                    run.children.push(RunChild::Break(br));
+                                }
+                                XMLElement::LastRenderedPageBreak => {
+                                    run = run.add_last_rendered_page_break();
                                 }
                                 XMLElement::Drawing => {
                                     let drawing = Drawing::read(r, &attributes)?;
@@ -74,30 +96,39 @@ impl ElementReader for Run {
                     };
                 }
                 Ok(XmlEvent::Characters(c)) => match text_state {
+                    // Deleted text is written as is, so it is kept escaped like Text.
                     TextState::Delete => {
-                        run = run.add_delete_text(c);
+                        run = run.add_delete_text(escape(&c));
                     }
                     TextState::Text => {
                         run = run.add_text(c);
                     }
+                    TextState::Instr => {
+                        run = run.add_instr_text(c);
+                    }
                     _ => {}
                 },
                 Ok(XmlEvent::Whitespace(c)) => match text_state {
                     TextState::Delete => {
-                        run = run.add_delete_text(c);
+                        run = run.add_delete_text(escape(&c));
                     }
                     TextState::Text => {
                         run = run.add_text(c);
                     }
+                    TextState::Instr => {
+                        run = run.add_instr_text(c);
+                    }
                     _ => {}
                 },
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     match e {
                         XMLElement::Run => {
-                            return Ok(run);
+                            return Ok(run.rsids(rsids));
+                        }
+                        XMLElement::DeleteText | XMLElement::Text | XMLElement::InstrText => {
+                            text_state = TextState::Idle
                         }
-                        XMLElement::DeleteText | XMLElement::Text => text_state = TextState::Idle,
                         _ => {}
                     }
                 }
@@ -112,6 +143,7 @@ impl ElementReader for Run {
 mod tests {
 
     use super::*;
+    use crate::types::FontHintType;
     #[cfg(test)]
     use pretty_assertions::assert_eq;
 
@@ -136,6 +168,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_delete_text_escaped() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:delText>a &lt; b</w:delText></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(run, Run::new().add_delete_text("a &lt; b"));
+    }
+
+    #[test]
+    fn test_read_size_cs() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:rPr><w:sz w:val="20"/><w:szCs w:val="28"/></w:rPr><w:t>H</w:t></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(run, Run::new().add_text("H").size(20).size_cs(28));
+    }
+
+    #[test]
+    fn test_read_position() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:rPr><w:position w:val="-6"/></w:rPr><w:t>H</w:t></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            run,
+            Run {
+                children: vec![RunChild::Text(Text::new("H"))],
+                run_property: RunProperty {
+                    position: Some(Position::new(-6)),
+                    ..RunProperty::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_fonts() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:rPr><w:rFonts w:ascii="Arial" w:cs="Arial" w:eastAsiaTheme="minorEastAsia" w:hint="cs"/></w:rPr><w:t>H</w:t></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            run,
+            Run {
+                children: vec![RunChild::Text(Text::new("H"))],
+                run_property: RunProperty {
+                    fonts: Some(
+                        RunFonts::new()
+                            .ascii("Arial")
+                            .cs("Arial")
+                            .east_asia_theme("minorEastAsia")
+                            .hint(FontHintType::Cs)
+                    ),
+                    ..RunProperty::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_field() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:fldChar w:fldCharType="begin" w:dirty="true"/><w:instrText xml:space="preserve"> DOCVARIABLE Client </w:instrText><w:fldChar w:fldCharType="separate"/><w:t>ACME</w:t><w:fldChar w:fldCharType="end"/></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            run,
+            Run::new()
+                .add_field_char(FieldCharType::Begin, true)
+                .add_instr_text(" DOCVARIABLE Client ")
+                .add_field_char(FieldCharType::Separate, false)
+                .add_text("ACME")
+                .add_field_char(FieldCharType::End, false)
+        );
+    }
+
+    #[test]
+    fn test_read_vanish() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:rPr><w:vanish w:val="false"/><w:specVanish/></w:rPr><w:t>H</w:t></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(run, Run::new().add_text("H").disable_vanish().spec_vanish());
+    }
+
     #[test]
     fn test_read_tab() {
         let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
@@ -152,6 +276,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_last_rendered_page_break() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:lastRenderedPageBreak /><w:t>H</w:t></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(run, Run::new().add_last_rendered_page_break().add_text("H"));
+    }
+
     #[test]
     fn test_read_br() {
         let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
@@ -184,6 +318,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_br_clear() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r><w:br w:clear="all" w:type="textWrapping" /></w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(run, Run::new().add_break_clear(BreakClear::All));
+    }
+
     #[test]
     fn test_read_italic_false() {
         let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
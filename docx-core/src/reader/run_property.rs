@@ -35,16 +35,38 @@ impl ElementReader for RunProperty {
                                 rp = rp.vert_align(v)
                             }
                         }
-                        XMLElement::Color => rp = rp.color(attributes[0].value.clone()),
+                        XMLElement::Shading => {
+                            if let Ok(shd) = Shading::read(r, &attributes) {
+                                rp = rp.shading(shd);
+                            }
+                        }
+                        XMLElement::Color => {
+                            if let Some(c) = read_color(
+                                &attributes,
+                                ("val", "themeColor", "themeTint", "themeShade"),
+                            ) {
+                                rp = rp.color(c)
+                            }
+                        }
                         XMLElement::Size => rp = rp.size(usize::from_str(&attributes[0].value)?),
+                        XMLElement::SizeCs => {
+                            if let Some(v) = read_val(&attributes) {
+                                rp = rp.size_cs(usize::from_str(&v)?)
+                            }
+                        }
                         XMLElement::Spacing => {
                             if let Some(v) = read_val(&attributes) {
                                 let v = value_to_dax(&v)?;
                                 rp = rp.spacing(v)
                             }
                         }
-                        // TODO: Implement later
-                        XMLElement::RunFonts => {}
+                        XMLElement::Lang => rp = rp.lang(read_lang(&attributes)),
+                        XMLElement::RunPosition => {
+                            if let Some(v) = read_val(&attributes) {
+                                rp = rp.position(f64::from_str(&v)? as i32)
+                            }
+                        }
+                        XMLElement::RunFonts => rp = rp.fonts(read_run_fonts(&attributes)),
                         XMLElement::Underline => rp = rp.underline(&attributes[0].value.clone()),
                         XMLElement::Italic => {
                             if !read_bool(&attributes) {
@@ -53,7 +75,20 @@ impl ElementReader for RunProperty {
                             }
                             rp = rp.italic();
                         }
-                        XMLElement::Vanish => rp = rp.vanish(),
+                        XMLElement::Vanish => {
+                            if !read_bool(&attributes) {
+                                rp = rp.disable_vanish();
+                                continue;
+                            }
+                            rp = rp.vanish();
+                        }
+                        XMLElement::SpecVanish => {
+                            if !read_bool(&attributes) {
+                                rp = rp.disable_spec_vanish();
+                                continue;
+                            }
+                            rp = rp.spec_vanish();
+                        }
                         XMLElement::TextBorder => {
                             if let Ok(attr) = read_border(&attributes) {
                                 let mut border = TextBorder::new()
@@ -76,6 +111,11 @@ impl ElementReader for RunProperty {
                                 rp = rp.delete(del);
                             }
                         }
+                        XMLElement::RunPropertyChange => {
+                            if let Ok(c) = RunPropertyChange::read(r, &attributes) {
+                                rp = rp.property_change(c);
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -0,0 +1,44 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl ElementReader for RunPropertyChange {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut property = RunProperty::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::RunProperty {
+                        property = RunProperty::read(r, &attributes)?;
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::RunPropertyChange {
+                        let mut c = RunPropertyChange::new(property);
+                        if let Some(author) = read(attrs, "author") {
+                            c = c.author(author);
+                        }
+                        if let Some(date) = read(attrs, "date") {
+                            c = c.date(date);
+                        }
+                        return Ok(c);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
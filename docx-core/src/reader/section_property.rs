@@ -79,6 +79,32 @@ impl ElementReader for SectionProperty {
                             let size = read_page_size(&attributes)?;
                             sp = sp.page_size(size);
                         }
+                        XMLElement::Columns => {
+                            for a in attributes.iter() {
+                                match a.name.local_name.as_str() {
+                                    "num" => sp = sp.columns(usize::from_str(&a.value)?),
+                                    "space" => {
+                                        sp = sp.column_space(value_to_dax(&a.value)? as usize)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        XMLElement::SectionType => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(t) = crate::types::SectionType::from_str(&v) {
+                                    sp = sp.section_type(t);
+                                }
+                            }
+                        }
+                        XMLElement::TitlePg => {
+                            sp = sp.title_pg(read_bool(&attributes));
+                        }
+                        XMLElement::HeaderReference | XMLElement::FooterReference
+                            if read(&attributes, "type").as_deref() == Some("first") =>
+                        {
+                            sp.first_page_rids.extend(read(&attributes, "id"));
+                        }
                         XMLElement::DocGrid => {
                             if let Ok(doc_grid) = DocGrid::read(r, &attributes) {
                                 sp = sp.doc_grid(doc_grid);
@@ -3,6 +3,7 @@ use xml::reader::{EventReader, XmlEvent};
 
 use super::*;
 use crate::reader::{FromXML, ReaderError};
+use crate::types::DocumentProtectionType;
 
 use std::str::FromStr;
 
@@ -10,6 +11,7 @@ impl FromXML for Settings {
     fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
         let mut parser = EventReader::new(reader);
         let mut settings = Self::default();
+        let mut print_settings: Option<PrintSettings> = None;
 
         loop {
             let e = parser.next();
@@ -37,10 +39,94 @@ impl FromXML for Settings {
                             let val = attributes::read_val(&attributes);
                             if let Some(name) = name {
                                 if let Some(val) = val {
-                                    settings = settings.add_doc_var(name, val);
+                                    settings = settings.set_doc_var(name, val);
                                 }
                             }
                         }
+                        XMLElement::TrackRevisions => {
+                            settings = settings.track_revisions(read_bool(&attributes));
+                        }
+                        XMLElement::Zoom => {
+                            for a in &attributes {
+                                if a.name.local_name == "percent" {
+                                    if let Ok(v) = usize::from_str(&a.value) {
+                                        settings = settings.zoom(v);
+                                    }
+                                }
+                            }
+                        }
+                        XMLElement::DocumentProtection => {
+                            let mut edit = DocumentProtectionType::None;
+                            let mut enforcement = false;
+                            for a in &attributes {
+                                match a.name.local_name.as_str() {
+                                    "edit" => {
+                                        if let Ok(v) = DocumentProtectionType::from_str(&a.value) {
+                                            edit = v;
+                                        }
+                                    }
+                                    "enforcement" => enforcement = !is_false(&a.value),
+                                    _ => {}
+                                }
+                            }
+                            settings = settings.document_protection(
+                                DocumentProtection::new(edit).enforcement(enforcement),
+                            );
+                        }
+                        XMLElement::AutoHyphenation => {
+                            settings = settings.auto_hyphenation(read_bool(&attributes));
+                        }
+                        XMLElement::FootnoteProperty => {
+                            if let Ok(p) = FootnoteProperties::read(&mut parser, &attributes) {
+                                settings = settings.footnote_properties(p);
+                            }
+                        }
+                        XMLElement::EndnoteProperty => {
+                            if let Ok(p) = EndnoteProperties::read(&mut parser, &attributes) {
+                                settings = settings.endnote_properties(p);
+                            }
+                        }
+                        XMLElement::Rsid => {
+                            if let Some(val) = attributes::read_val(&attributes) {
+                                settings = settings.add_rsid(val);
+                            }
+                        }
+                        XMLElement::ThemeFontLang => {
+                            settings = settings.theme_font_lang(attributes::read_lang(&attributes));
+                        }
+                        XMLElement::Compat => {
+                            print_settings = Some(PrintSettings::empty());
+                        }
+                        XMLElement::SpaceForUL
+                        | XMLElement::BalanceSingleByteDoubleByteWidth
+                        | XMLElement::DoNotLeaveBackslashAlone
+                        | XMLElement::UlTrailSpace
+                        | XMLElement::DoNotExpandShiftReturn
+                        | XMLElement::AdjustLineHeightInTable
+                        | XMLElement::UseFELayout
+                        | XMLElement::AutoSpaceLikeWord95 => {
+                            if let Some(p) = print_settings.take() {
+                                let v = read_bool(&attributes);
+                                print_settings = Some(match e {
+                                    XMLElement::SpaceForUL => p.space_for_ul(v),
+                                    XMLElement::BalanceSingleByteDoubleByteWidth => {
+                                        p.balance_single_byte_double_byte_width(v)
+                                    }
+                                    XMLElement::DoNotLeaveBackslashAlone => {
+                                        p.do_not_leave_backslash_alone(v)
+                                    }
+                                    XMLElement::UlTrailSpace => p.ul_trail_space(v),
+                                    XMLElement::DoNotExpandShiftReturn => {
+                                        p.do_not_expand_shift_return(v)
+                                    }
+                                    XMLElement::AdjustLineHeightInTable => {
+                                        p.adjust_line_height_table(v)
+                                    }
+                                    XMLElement::UseFELayout => p.use_fe_layout(v),
+                                    _ => p.auto_space_like_word95(v),
+                                });
+                            }
+                        }
                         XMLElement::DefaultTabStop => {
                             let val = attributes::read_val(&attributes);
                             if let Some(val) = val {
@@ -54,8 +140,14 @@ impl FromXML for Settings {
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
-                    if let XMLElement::Settings = e {
-                        break;
+                    match e {
+                        XMLElement::Compat => {
+                            if let Some(p) = print_settings.take() {
+                                settings = settings.print_settings(p);
+                            }
+                        }
+                        XMLElement::Settings => break,
+                        _ => {}
                     }
                 }
                 Ok(XmlEvent::EndDocument { .. }) => break,
@@ -66,3 +158,53 @@ impl FromXML for Settings {
         Ok(settings)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_print_settings() {
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:compat>
+        <w:spaceForUL />
+        <w:doNotExpandShiftReturn />
+        <w:autoSpaceLikeWord95 />
+    </w:compat>
+</w:settings>"#;
+        let s = Settings::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            s,
+            Settings::new().print_settings(
+                PrintSettings::empty()
+                    .space_for_ul(true)
+                    .do_not_expand_shift_return(true)
+                    .auto_space_like_word95(true)
+            )
+        );
+    }
+
+    #[test]
+    fn test_read_zoom_protection_and_hyphenation() {
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:zoom w:percent="120" />
+    <w:documentProtection w:edit="trackedChanges" w:enforcement="1" />
+    <w:defaultTabStop w:val="720" />
+    <w:autoHyphenation />
+</w:settings>"#;
+        let s = Settings::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(s.get_zoom(), 120);
+        assert_eq!(s.get_default_tab_stop(), 720);
+        assert_eq!(
+            s.get_document_protection(),
+            Some(&DocumentProtection::new(
+                DocumentProtectionType::TrackedChanges
+            ))
+        );
+        assert!(s.get_auto_hyphenation());
+        assert!(!s.get_track_revisions());
+    }
+}
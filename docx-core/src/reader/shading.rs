@@ -19,12 +19,17 @@ impl ElementReader for Shading {
                 if let Ok(val) = ShdType::from_str(&a.value) {
                     shd = shd.shd_type(val);
                 }
-            } else if local_name == "color" {
-                shd = shd.color(&a.value);
-            } else if local_name == "fill" {
-                shd = shd.fill(&a.value);
             }
         }
+        if let Some(c) = read_color(attrs, ("color", "themeColor", "themeTint", "themeShade")) {
+            shd = shd.color(c);
+        }
+        if let Some(c) = read_color(
+            attrs,
+            ("fill", "themeFill", "themeFillTint", "themeFillShade"),
+        ) {
+            shd = shd.fill(c);
+        }
         Ok(shd)
     }
 }
@@ -0,0 +1,167 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::types::{emu, ShapeType};
+
+impl ElementReader for Shape {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        _attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        // Presets which are not supported are kept as rectangles.
+        let mut shape = Shape::new(ShapeType::Rect, 0, 0);
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    if let Ok(XMLElement::TxbxContent) = XMLElement::from_str(&name.local_name) {
+                        shape.content = TextBoxContent::read(r, &attributes)?;
+                        continue;
+                    }
+                    // Colors of wps:style are references to the theme, not those of the shape.
+                    if let Ok(WpsXMLElement::SpProperty) = WpsXMLElement::from_str(&name.local_name)
+                    {
+                        let p = read_shape_property(r)?;
+                        shape.size = p.size;
+                        shape.geometry = p.geometry;
+                        shape.fill = p.fill;
+                        shape.line = p.line;
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    if let Ok(WpsXMLElement::Wsp) = WpsXMLElement::from_str(&name.local_name) {
+                        return Ok(shape);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+// The wps:spPr of preset shapes and text boxes, as written by build_shape_property.
+pub(crate) struct ShapeProperty {
+    pub size: (u32, u32),
+    pub geometry: ShapeType,
+    pub fill: Option<String>,
+    pub line: Option<(String, u32)>,
+}
+
+// Reads the children of wps:spPr. The fill is a:solidFill directly under spPr, and the line
+// color the one of a:ln, so colors of other fills are skipped.
+pub(crate) fn read_shape_property<R: Read>(
+    r: &mut EventReader<R>,
+) -> Result<ShapeProperty, ReaderError> {
+    let mut property = ShapeProperty {
+        size: (0, 0),
+        geometry: ShapeType::Rect,
+        fill: None,
+        line: None,
+    };
+    // The elements open inside spPr, so depth 1 is a direct child.
+    let mut depth = 0;
+    let mut in_fill = false;
+    let mut in_line = false;
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                depth += 1;
+                match AXMLElement::from_str(&name.local_name).unwrap() {
+                    AXMLElement::Ext => {
+                        let cx = read(&attributes, "cx").unwrap_or_default();
+                        let cy = read(&attributes, "cy").unwrap_or_default();
+                        property.size = (
+                            emu::to_px(u32::from_str(&cx).unwrap_or(0)),
+                            emu::to_px(u32::from_str(&cy).unwrap_or(0)),
+                        );
+                    }
+                    AXMLElement::PrstGeom => {
+                        if let Some(prst) = read(&attributes, "prst") {
+                            if let Ok(t) = ShapeType::from_str(&prst) {
+                                property.geometry = t;
+                            }
+                        }
+                    }
+                    AXMLElement::SolidFill if depth == 1 => in_fill = true,
+                    AXMLElement::Ln if depth == 1 => {
+                        in_line = true;
+                        let w = read(&attributes, "w").unwrap_or_default();
+                        let w = emu::to_px(u32::from_str(&w).unwrap_or(0));
+                        property.line = Some(("000000".to_owned(), w));
+                    }
+                    AXMLElement::SrgbClr => {
+                        if let Some(color) = read_val(&attributes) {
+                            if in_line {
+                                if let Some(line) = property.line.as_mut() {
+                                    line.0 = color;
+                                }
+                            } else if in_fill {
+                                property.fill = Some(color);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => {
+                // The end of spPr.
+                if depth == 0 {
+                    return Ok(property);
+                }
+                if depth == 1 {
+                    in_fill = false;
+                    in_line = false;
+                }
+                depth -= 1;
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_shape_skips_style_colors() {
+        let c = r#"<wps:wsp xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+    <wps:cNvSpPr />
+    <wps:spPr>
+        <a:xfrm><a:off x="0" y="0" /><a:ext cx="1143000" cy="571500" /></a:xfrm>
+        <a:prstGeom prst="ellipse"><a:avLst /></a:prstGeom>
+        <a:ln w="19050"><a:solidFill><a:srgbClr val="0000FF" /></a:solidFill></a:ln>
+    </wps:spPr>
+    <wps:style>
+        <a:fillRef idx="1"><a:srgbClr val="FF0000" /></a:fillRef>
+    </wps:style>
+    <wps:bodyPr />
+</wps:wsp>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        // Skip the start of wps:wsp.
+        loop {
+            if let Ok(XmlEvent::StartElement { .. }) = parser.next() {
+                break;
+            }
+        }
+        let s = Shape::read(&mut parser, &[]).unwrap();
+        assert_eq!(s.geometry, ShapeType::Ellipse);
+        assert_eq!(s.size, (120, 60));
+        assert_eq!(s.fill, None);
+        assert_eq!(s.line, Some(("0000FF".to_owned(), 2)));
+    }
+}
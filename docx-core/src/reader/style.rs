@@ -42,11 +42,44 @@ impl ElementReader for Style {
                             }
                             continue;
                         }
+                        XMLElement::Next => {
+                            if let Some(v) = read_val(&attributes) {
+                                style = style.next(v);
+                            }
+                            continue;
+                        }
+                        XMLElement::UiPriority => {
+                            if let Some(v) = read_val(&attributes) {
+                                if let Ok(v) = usize::from_str(&v) {
+                                    style = style.ui_priority(v);
+                                }
+                            }
+                            continue;
+                        }
+                        XMLElement::SemiHidden => {
+                            if read_bool(&attributes) {
+                                style = style.semi_hidden();
+                            }
+                            continue;
+                        }
+                        XMLElement::UnhideWhenUsed => {
+                            if read_bool(&attributes) {
+                                style = style.unhide_when_used();
+                            }
+                            continue;
+                        }
+                        XMLElement::QFormat => {
+                            if read_bool(&attributes) {
+                                style = style.q_format();
+                            }
+                            continue;
+                        }
                         // pPr
                         XMLElement::Indent => {
                             let (start, end, special, start_chars, hanging_chars, first_line_chars) =
                                 read_indent(&attributes)?;
-                            style = style.indent(start, special, end, start_chars);
+                            style =
+                                style.indent(start.map(Twip), special, end.map(Twip), start_chars);
 
                             if let Some(chars) = hanging_chars {
                                 style = style.hanging_chars(chars);
@@ -57,6 +90,26 @@ impl ElementReader for Style {
 
                             continue;
                         }
+                        XMLElement::NumberingProperty => {
+                            let num_pr = NumberingProperty::read(r, &attributes)?;
+                            style.paragraph_property.numbering_property = Some(num_pr);
+                            continue;
+                        }
+                        XMLElement::Spacing => {
+                            let (before, after, line, spacing_type) =
+                                attributes::line_spacing::read_line_spacing(&attributes)?;
+                            style = style.line_spacing(
+                                LineSpacing::new(spacing_type)
+                                    .before(before.map(Twip::from))
+                                    .after(after.map(Twip::from))
+                                    .line(line),
+                            );
+                            continue;
+                        }
+                        XMLElement::ContextualSpacing => {
+                            style = style.contextual_spacing(read_bool(&attributes));
+                            continue;
+                        }
                         XMLElement::Justification => {
                             style = style.align(AlignmentType::from_str(&attributes[0].value)?);
                             continue;
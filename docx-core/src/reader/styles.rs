@@ -76,7 +76,31 @@ mod tests {
             Style::new("FootnoteTextChar", StyleType::Character)
                 .name("Footnote Text Char")
                 .size(20)
-                .based_on("DefaultParagraphFont"),
+                .based_on("DefaultParagraphFont")
+                .ui_priority(99)
+                .unhide_when_used()
+                .semi_hidden(),
+        );
+        assert_eq!(s, styles);
+    }
+
+    #[test]
+    fn test_from_xml_with_numbering_style() {
+        let xml = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:style w:type="numbering" w:styleId="ListStyle">
+        <w:name w:val="List Style"/>
+        <w:pPr>
+            <w:numPr>
+                <w:numId w:val="1"/>
+            </w:numPr>
+        </w:pPr>
+    </w:style>
+</w:styles>"#;
+        let s = Styles::from_xml(xml.as_bytes()).unwrap();
+        let styles = Styles::new().add_style(
+            Style::new("ListStyle", StyleType::Numbering)
+                .name("List Style")
+                .numbering(1),
         );
         assert_eq!(s, styles);
     }
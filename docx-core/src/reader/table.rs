@@ -50,6 +50,16 @@ impl ElementReader for Table {
                                 t = t.style(s);
                             }
                         }
+                        XMLElement::TableCaption => {
+                            if let Some(v) = read_val(&attributes) {
+                                t = t.caption(v);
+                            }
+                        }
+                        XMLElement::TableDescription => {
+                            if let Some(v) = read_val(&attributes) {
+                                t = t.description(v);
+                            }
+                        }
                         XMLElement::TableCellMargin => {
                             if let Ok(margins) = TableCellMargins::read(r, &attributes) {
                                 t = t.margins(margins)
@@ -126,4 +136,74 @@ mod tests {
                 .indent(100)
         );
     }
+
+    #[test]
+    fn test_read_table_with_caption() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:tblPr>
+        <w:tblCaption w:val="Sales"/>
+        <w:tblDescription w:val="Quarterly sales by region"/>
+    </w:tblPr>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            t,
+            Table::without_borders(vec![])
+                .caption("Sales")
+                .description("Quarterly sales by region")
+        );
+    }
+
+    #[test]
+    fn test_read_table_with_escaped_caption() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:tblPr>
+        <w:tblCaption w:val="Q&amp;A &quot;summary&quot;"/>
+    </w:tblPr>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            t,
+            Table::without_borders(vec![]).caption(r#"Q&A "summary""#)
+        );
+    }
+
+    #[test]
+    fn test_read_table_row_with_exception_properties() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:tr>
+        <w:tblPrEx>
+            <w:tblBorders>
+                <w:top w:val="double" w:sz="4" w:space="0" w:color="000000"/>
+            </w:tblBorders>
+            <w:shd w:val="clear" w:color="auto" w:fill="FF0000"/>
+        </w:tblPrEx>
+        <w:trPr />
+    </w:tr>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            t.rows[0].exception_properties,
+            Some(
+                TablePropertyExceptions::new()
+                    .set_borders(
+                        TableBorders::with_empty().set(
+                            TableBorder::new(TableBorderPosition::Top)
+                                .border_type(BorderType::Double)
+                                .size(4)
+                        )
+                    )
+                    .shading(Shading::new().fill("FF0000"))
+            )
+        );
+    }
 }
@@ -153,4 +153,53 @@ mod tests {
                 .vertical_align(VAlignType::Bottom),
         );
     }
+
+    #[test]
+    fn test_read_pct_width() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tc>
+    <w:tcPr>
+        <w:tcW w:w="25%" w:type="pct"/>
+    </w:tcPr>
+    <w:p>
+        <w:r>
+            <w:rPr></w:rPr>
+        </w:r>
+    </w:p>
+</w:tc>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let cell = TableCell::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            cell,
+            TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new()))
+                .width(1250, WidthType::Pct),
+        );
+    }
+
+    #[test]
+    fn test_read_no_wrap_and_fit_text() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tc>
+    <w:tcPr>
+        <w:noWrap/>
+        <w:tcFitText w:val="false"/>
+    </w:tcPr>
+    <w:p>
+        <w:r>
+            <w:rPr></w:rPr>
+        </w:r>
+    </w:p>
+</w:tc>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let cell = TableCell::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            cell,
+            TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new()))
+                .no_wrap(true),
+        );
+    }
 }
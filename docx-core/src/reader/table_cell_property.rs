@@ -28,7 +28,7 @@ impl ElementReader for TableCellProperty {
                                 if local_name == "type" {
                                     width_type = WidthType::from_str(&a.value)?;
                                 } else if local_name == "w" {
-                                    w = usize::from_str(&a.value)?;
+                                    w = read_width_value(&a.value)?;
                                 }
                             }
                             property = property.width(w, width_type);
@@ -63,6 +63,12 @@ impl ElementReader for TableCellProperty {
                                 }
                             }
                         }
+                        XMLElement::NoWrap => {
+                            property = property.no_wrap(read_bool(&attributes));
+                        }
+                        XMLElement::TableCellFitText => {
+                            property = property.fit_text(read_bool(&attributes));
+                        }
                         XMLElement::TableCellBorders => {
                             let borders = TableCellBorders::read(r, &attributes)?;
                             property = property.set_borders(borders);
@@ -82,3 +88,12 @@ impl ElementReader for TableCellProperty {
         }
     }
 }
+
+// Percentages may also be written as e.g. "50%" instead of fiftieths of a percent.
+fn read_width_value(v: &str) -> Result<usize, ReaderError> {
+    if let Some(pct) = v.strip_suffix('%') {
+        Ok((f32::from_str(pct)? * 50.0) as usize)
+    } else {
+        Ok(usize::from_str(v)?)
+    }
+}
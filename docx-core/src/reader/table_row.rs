@@ -18,6 +18,7 @@ impl ElementReader for TableRow {
         let mut width_before = None;
         let mut row_height = None;
         let mut height_rule = Some(HeightRule::AtLeast);
+        let mut exception_properties = None;
         loop {
             let e = r.next();
             match e {
@@ -33,6 +34,10 @@ impl ElementReader for TableRow {
                             cells.push(TableCell::read(r, &attributes)?);
                             continue;
                         }
+                        XMLElement::TablePropertyExceptions => {
+                            exception_properties =
+                                Some(TablePropertyExceptions::read(r, &attributes)?);
+                        }
                         XMLElement::GridAfter => {
                             if let Some(v) = read_val(&attributes) {
                                 grid_after = Some(u32::from_str(&v)?);
@@ -99,6 +104,10 @@ impl ElementReader for TableRow {
                             row = row.height_rule(height_rule);
                         }
 
+                        if let Some(p) = exception_properties {
+                            row = row.exception_properties(p);
+                        }
+
                         return Ok(row);
                     }
                 }
@@ -0,0 +1,62 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::shape::read_shape_property;
+use super::*;
+
+// Reads the wps:wsp of a text box. Its position is read by the anchor holding it.
+impl ElementReader for TextBox {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        _attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut text_box = TextBox::new(0, 0);
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    if let Ok(XMLElement::TxbxContent) = XMLElement::from_str(&name.local_name) {
+                        text_box.content = TextBoxContent::read(r, &attributes)?;
+                        continue;
+                    }
+                    match WpsXMLElement::from_str(&name.local_name).unwrap() {
+                        WpsXMLElement::SpProperty => {
+                            let p = read_shape_property(r)?;
+                            text_box.size = p.size;
+                            text_box.fill = p.fill;
+                            text_box.border = p.line;
+                        }
+                        WpsXMLElement::Txbx => {
+                            if let Some(id) = read(&attributes, "id") {
+                                if let Ok(id) = usize::from_str(&id) {
+                                    text_box = text_box.chain_id(id);
+                                }
+                            }
+                        }
+                        WpsXMLElement::LinkedTxbx => {
+                            let id = read(&attributes, "id").and_then(|v| usize::from_str(&v).ok());
+                            let seq =
+                                read(&attributes, "seq").and_then(|v| usize::from_str(&v).ok());
+                            if let (Some(id), Some(seq)) = (id, seq) {
+                                text_box = text_box.link(id, seq);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    if let Ok(WpsXMLElement::Wsp) = WpsXMLElement::from_str(&name.local_name) {
+                        return Ok(text_box);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -18,10 +18,16 @@ impl FromXML for WebSettings {
                 }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     dbg!(&e);
-                    if let XMLElement::Div = e {
-                        if let Ok(div) = Div::read(&mut parser, &attributes) {
-                            settings.divs.push(div);
+                    match e {
+                        XMLElement::Div => {
+                            if let Ok(div) = Div::read(&mut parser, &attributes) {
+                                settings.divs.push(div);
+                            }
                         }
+                        XMLElement::OptimizeForBrowser => {
+                            settings = settings.optimize_for_browser(read_bool(&attributes));
+                        }
+                        _ => {}
                     }
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
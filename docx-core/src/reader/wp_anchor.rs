@@ -5,35 +5,131 @@ use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
 use super::*;
+use crate::types::emu;
 
 impl ElementReader for WpAnchor {
     fn read<R: Read>(
         r: &mut EventReader<R>,
-        _attrs: &[OwnedAttribute],
+        attrs: &[OwnedAttribute],
     ) -> Result<Self, ReaderError> {
-        let mut anchor = WpAnchor::new();
-        loop {
-            let e = r.next();
-            match e {
-                Ok(XmlEvent::StartElement {
-                    name, attributes, ..
-                }) => {
-                    let e = AXMLElement::from_str(&name.local_name)
-                        .expect("should convert to XMLElement");
-                    if let AXMLElement::Graphic = e {
-                        let g = AGraphic::read(r, &attributes)?;
-                        anchor = anchor.add_graphic(g);
+        Ok(read_anchor(r, attrs)?.0)
+    }
+}
+
+// Reads the anchor, with the text box it holds if it is a wps:wsp.
+// The text box is also kept in the anchor children as before.
+pub(crate) fn read_anchor<R: Read>(
+    r: &mut EventReader<R>,
+    attrs: &[OwnedAttribute],
+) -> Result<(WpAnchor, Option<TextBox>), ReaderError> {
+    let mut anchor = WpAnchor::new();
+    let dist = |name: &str| {
+        read(attrs, name)
+            .and_then(|v| u32::from_str(&v).ok())
+            .unwrap_or(0)
+    };
+    anchor.wrap_distance = (dist("distT"), dist("distB"), dist("distL"), dist("distR"));
+    let mut text_box: Option<TextBox> = None;
+    // Graphic data of a shape, which is read here to get the text box.
+    let mut shape_data: Option<AGraphicData> = None;
+    // (horizontal, vertical) offsets in px.
+    let mut offset = (0, 0);
+    let mut horizontal = true;
+    let mut in_offset = false;
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                match WpXMLElement::from_str(&name.local_name).unwrap() {
+                    WpXMLElement::PositionH => horizontal = true,
+                    WpXMLElement::PositionV => horizontal = false,
+                    WpXMLElement::PosOffset => in_offset = true,
+                    _ => {}
+                }
+                if let Ok(AXMLElement::GraphicData) = AXMLElement::from_str(&name.local_name) {
+                    let t = read(&attributes, "uri")
+                        .and_then(|v| GraphicDataType::from_str(&v).ok())
+                        .unwrap_or(GraphicDataType::Unsupported);
+                    if t == GraphicDataType::WpShape {
+                        shape_data = Some(AGraphicData::new(t));
+                    } else {
+                        let g = AGraphicData::read(r, &attributes)?;
+                        anchor = anchor.add_graphic(AGraphic::new().add_graphic_data(g));
                     }
+                    continue;
                 }
-                Ok(XmlEvent::EndElement { name, .. }) => {
-                    let e = WpXMLElement::from_str(&name.local_name).unwrap();
-                    if e == WpXMLElement::Anchor {
-                        return Ok(anchor);
+                let is_wsp = WpsXMLElement::from_str(&name.local_name) == Ok(WpsXMLElement::Wsp);
+                if let (true, Some(data)) = (is_wsp, shape_data.take()) {
+                    let t = TextBox::read(r, &attributes)?;
+                    let mut shape = WpsShape::new();
+                    if t.link.is_none() {
+                        shape =
+                            shape.add_text_box(WpsTextBox::new().add_content(t.content.clone()));
                     }
+                    shape_data = Some(data.add_shape(shape));
+                    text_box = Some(t);
+                }
+            }
+            Ok(XmlEvent::Characters(c)) if in_offset => {
+                let px = emu::to_px(u32::from_str(c.trim()).unwrap_or(0)) as usize;
+                if horizontal {
+                    offset.0 = px;
+                } else {
+                    offset.1 = px;
+                }
+            }
+            Ok(XmlEvent::EndElement { name, .. }) => {
+                if let Ok(AXMLElement::GraphicData) = AXMLElement::from_str(&name.local_name) {
+                    if let Some(data) = shape_data.take() {
+                        anchor = anchor.add_graphic(AGraphic::new().add_graphic_data(data));
+                    }
+                }
+                match WpXMLElement::from_str(&name.local_name).unwrap() {
+                    WpXMLElement::PosOffset => in_offset = false,
+                    WpXMLElement::Anchor => {
+                        let text_box = text_box.map(|t| t.offset_x(offset.0).offset_y(offset.1));
+                        return Ok((anchor, text_box));
+                    }
+                    _ => {}
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_wrap_distance() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+        xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing">
+    <wp:anchor distT="95250" distB="95250" distL="190500" distR="0" simplePos="0" relativeHeight="1905000" behindDoc="0" locked="0" layoutInCell="1" allowOverlap="1">
+        <wp:simplePos x="0" y="0" />
+    </wp:anchor>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let mut attrs = vec![];
+        loop {
+            if let Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) = parser.next()
+            {
+                if name.local_name == "anchor" {
+                    attrs = attributes;
+                    break;
                 }
-                Err(_) => return Err(ReaderError::XMLReadError),
-                _ => {}
             }
         }
+        let anchor = WpAnchor::read(&mut parser, &attrs).unwrap();
+        assert_eq!(anchor.wrap_distance, (95250, 95250, 190500, 0));
     }
 }
@@ -19,6 +19,7 @@ pub enum XMLElement {
     SizeCs,
     Spacing,
     Vanish,
+    SpecVanish,
     TextBorder,
     Italic,
     ItalicCs,
@@ -28,6 +29,7 @@ pub enum XMLElement {
     Bold,
     BoldCs,
     Break,
+    LastRenderedPageBreak,
     Tab,
     ParagraphStyle,
     ParagraphPropertyChange,
@@ -43,6 +45,7 @@ pub enum XMLElement {
     Insert,
     KeepNext,
     KeepLines,
+    ContextualSpacing,
     PageBreakBefore,
     WindowControl,
     DivId,
@@ -53,9 +56,14 @@ pub enum XMLElement {
     MarginTop,
     MarginBottom,
     Delete,
+    MoveFrom,
+    MoveTo,
     DeleteText,
+    FieldChar,
+    InstrText,
     BookmarkStart,
     BookmarkEnd,
+    AltChunk,
     Comment,
     Comments,
     CommentRangeStart,
@@ -66,6 +74,8 @@ pub enum XMLElement {
     VAlign,
     Shading,
     TextDirection,
+    NoWrap,
+    TableCellFitText,
     Table,
     TableProperty,
     TableRow,
@@ -82,9 +92,12 @@ pub enum XMLElement {
     TableBorders,
     TableCellMargin,
     TableStyle,
+    TableCaption,
+    TableDescription,
     // Change
     TableGridChange,
     TablePropertyChange,
+    TablePropertyExceptions,
     TableRowPropertyChange,
     TableCellPropertyChange,
     Top,
@@ -105,6 +118,10 @@ pub enum XMLElement {
     WidthBefore,
     Style,
     Next,
+    UiPriority,
+    SemiHidden,
+    UnhideWhenUsed,
+    QFormat,
     Styles,
     Relationship,
     Relationships,
@@ -123,20 +140,51 @@ pub enum XMLElement {
     LevelRestart,
     StyleLink,
     NumStyleLink,
+    NumberingPictureBullet,
+    LevelPictureBulletId,
     Drawing,
     TxbxContent,
     Pict,
     DocId,
     DocVars,
     DocVar,
+    TrackRevisions,
+    DocumentProtection,
+    AutoHyphenation,
+    ThemeFontLang,
+    Lang,
+    Zoom,
+    FootnoteProperty,
+    EndnoteProperty,
+    Position,
+    RunPosition,
+    NumStart,
+    NumRestart,
+    Rsid,
     DocGrid,
+    Columns,
+    SectionType,
+    TitlePg,
+    HeaderReference,
+    FooterReference,
     DocDefaults,
     DefaultTabStop,
     RunPropertyDefault,
+    ParagraphPropertyDefault,
     SectionProperty,
     PageSize,
     PageMargin,
     WebSettings,
+    Compat,
+    SpaceForUL,
+    BalanceSingleByteDoubleByteWidth,
+    DoNotLeaveBackslashAlone,
+    UlTrailSpace,
+    DoNotExpandShiftReturn,
+    AdjustLineHeightInTable,
+    UseFELayout,
+    AutoSpaceLikeWord95,
+    OptimizeForBrowser,
     Unsupported,
 }
 
@@ -171,6 +219,7 @@ pub enum AXMLElement {
     Ext,
     PrstGeom,
     SolidFill,
+    SrgbClr,
     Ln,
     Unsupported,
 }
@@ -182,12 +231,15 @@ pub enum WpsXMLElement {
     SpProperty,
     Style,
     Txbx,
+    LinkedTxbx,
     BodyPr,
     Unsupported,
 }
 #[derive(PartialEq, Debug)]
 pub enum VXMLElement {
     Rect,
+    Shape,
+    ImageData,
     Stroke,
     Fill,
     TextBox,
@@ -221,10 +273,12 @@ impl FromStr for XMLElement {
             "i" => Ok(XMLElement::Italic),
             "iCs" => Ok(XMLElement::ItalicCs),
             "vanish" => Ok(XMLElement::Vanish),
+            "specVanish" => Ok(XMLElement::SpecVanish),
             "italic" => Ok(XMLElement::Italic),
             "name" => Ok(XMLElement::Name),
             "tab" => Ok(XMLElement::Tab),
             "br" => Ok(XMLElement::Break),
+            "lastRenderedPageBreak" => Ok(XMLElement::LastRenderedPageBreak),
             "ind" => Ok(XMLElement::Indent),
             "numPr" => Ok(XMLElement::NumberingProperty),
             "ilvl" => Ok(XMLElement::IndentLevel),
@@ -232,9 +286,14 @@ impl FromStr for XMLElement {
             "jc" => Ok(XMLElement::Justification),
             "ins" => Ok(XMLElement::Insert),
             "del" => Ok(XMLElement::Delete),
+            "moveFrom" => Ok(XMLElement::MoveFrom),
+            "moveTo" => Ok(XMLElement::MoveTo),
             "delText" => Ok(XMLElement::DeleteText),
+            "fldChar" => Ok(XMLElement::FieldChar),
+            "instrText" => Ok(XMLElement::InstrText),
             "bookmarkStart" => Ok(XMLElement::BookmarkStart),
             "bookmarkEnd" => Ok(XMLElement::BookmarkEnd),
+            "altChunk" => Ok(XMLElement::AltChunk),
             "comments" => Ok(XMLElement::Comments),
             "comment" => Ok(XMLElement::Comment),
             "commentRangeStart" => Ok(XMLElement::CommentRangeStart),
@@ -259,11 +318,15 @@ impl FromStr for XMLElement {
             "gridBefore" => Ok(XMLElement::GridBefore),
             "wBefore" => Ok(XMLElement::WidthBefore),
             "textDirection" => Ok(XMLElement::TextDirection),
+            "noWrap" => Ok(XMLElement::NoWrap),
+            "tcFitText" => Ok(XMLElement::TableCellFitText),
             "tblW" => Ok(XMLElement::TableWidth),
             "tblInd" => Ok(XMLElement::TableIndent),
             "tblBorders" => Ok(XMLElement::TableBorders),
             "tblCellMar" => Ok(XMLElement::TableCellMargin),
             "tblStyle" => Ok(XMLElement::TableStyle),
+            "tblCaption" => Ok(XMLElement::TableCaption),
+            "tblDescription" => Ok(XMLElement::TableDescription),
             "top" => Ok(XMLElement::Top),
             "right" => Ok(XMLElement::Right),
             "start" => Ok(XMLElement::Start),
@@ -276,6 +339,7 @@ impl FromStr for XMLElement {
             "tr2bl" => Ok(XMLElement::Tr2bl),
             "tblGrid" => Ok(XMLElement::TableGrid),
             "tblPrChange" => Ok(XMLElement::TablePropertyChange),
+            "tblPrEx" => Ok(XMLElement::TablePropertyExceptions),
             "trPrChange" => Ok(XMLElement::TableRowPropertyChange),
             "tcPrChange" => Ok(XMLElement::TableCellPropertyChange),
             "tblGridChange" => Ok(XMLElement::TableGridChange),
@@ -284,6 +348,10 @@ impl FromStr for XMLElement {
             "basedOn" => Ok(XMLElement::BasedOn),
             "bdr" => Ok(XMLElement::TextBorder),
             "next" => Ok(XMLElement::Next),
+            "uiPriority" => Ok(XMLElement::UiPriority),
+            "semiHidden" => Ok(XMLElement::SemiHidden),
+            "unhideWhenUsed" => Ok(XMLElement::UnhideWhenUsed),
+            "qFormat" => Ok(XMLElement::QFormat),
             "vertAlign" => Ok(XMLElement::VertAlign),
             "spacing" => Ok(XMLElement::Spacing),
             "styles" => Ok(XMLElement::Styles),
@@ -301,6 +369,8 @@ impl FromStr for XMLElement {
             "lvlRestart" => Ok(XMLElement::LevelRestart),
             "lvlJc" => Ok(XMLElement::LevelJustification),
             "numStyleLink" => Ok(XMLElement::NumStyleLink),
+            "numPicBullet" => Ok(XMLElement::NumberingPictureBullet),
+            "lvlPicBulletId" => Ok(XMLElement::LevelPictureBulletId),
             "styleLink" => Ok(XMLElement::StyleLink),
             "vAlign" => Ok(XMLElement::VAlign),
             "drawing" => Ok(XMLElement::Drawing),
@@ -311,13 +381,32 @@ impl FromStr for XMLElement {
             "docId" => Ok(XMLElement::DocId),
             "docVar" => Ok(XMLElement::DocVar),
             "docVars" => Ok(XMLElement::DocVars),
+            "trackRevisions" => Ok(XMLElement::TrackRevisions),
+            "documentProtection" => Ok(XMLElement::DocumentProtection),
+            "autoHyphenation" => Ok(XMLElement::AutoHyphenation),
+            "themeFontLang" => Ok(XMLElement::ThemeFontLang),
+            "lang" => Ok(XMLElement::Lang),
+            "zoom" => Ok(XMLElement::Zoom),
+            "footnotePr" => Ok(XMLElement::FootnoteProperty),
+            "endnotePr" => Ok(XMLElement::EndnoteProperty),
+            "pos" => Ok(XMLElement::Position),
+            "position" => Ok(XMLElement::RunPosition),
+            "numStart" => Ok(XMLElement::NumStart),
+            "numRestart" => Ok(XMLElement::NumRestart),
+            "rsid" => Ok(XMLElement::Rsid),
             "sectPr" => Ok(XMLElement::SectionProperty),
+            "titlePg" => Ok(XMLElement::TitlePg),
+            "headerReference" => Ok(XMLElement::HeaderReference),
+            "footerReference" => Ok(XMLElement::FooterReference),
             "pgSz" => Ok(XMLElement::PageSize),
             "rFonts" => Ok(XMLElement::RunFonts),
             "pgMar" => Ok(XMLElement::PageMargin),
             "docDefaults" => Ok(XMLElement::DocDefaults),
             "docGrid" => Ok(XMLElement::DocGrid),
+            "cols" => Ok(XMLElement::Columns),
+            "type" => Ok(XMLElement::SectionType),
             "rPrDefault" => Ok(XMLElement::RunPropertyDefault),
+            "pPrDefault" => Ok(XMLElement::ParagraphPropertyDefault),
             "defaultTabStop" => Ok(XMLElement::DefaultTabStop),
             "divId" => Ok(XMLElement::DivId),
             "div" => Ok(XMLElement::Div),
@@ -327,8 +416,19 @@ impl FromStr for XMLElement {
             "marTop" => Ok(XMLElement::MarginTop),
             "marBottom" => Ok(XMLElement::MarginBottom),
             "webSettings" => Ok(XMLElement::WebSettings),
+            "compat" => Ok(XMLElement::Compat),
+            "spaceForUL" => Ok(XMLElement::SpaceForUL),
+            "balanceSingleByteDoubleByteWidth" => Ok(XMLElement::BalanceSingleByteDoubleByteWidth),
+            "doNotLeaveBackslashAlone" => Ok(XMLElement::DoNotLeaveBackslashAlone),
+            "ulTrailSpace" => Ok(XMLElement::UlTrailSpace),
+            "doNotExpandShiftReturn" => Ok(XMLElement::DoNotExpandShiftReturn),
+            "adjustLineHeightInTable" => Ok(XMLElement::AdjustLineHeightInTable),
+            "useFELayout" => Ok(XMLElement::UseFELayout),
+            "autoSpaceLikeWord95" => Ok(XMLElement::AutoSpaceLikeWord95),
+            "optimizeForBrowser" => Ok(XMLElement::OptimizeForBrowser),
             "keepNext" => Ok(XMLElement::KeepNext),
             "keepLines" => Ok(XMLElement::KeepLines),
+            "contextualSpacing" => Ok(XMLElement::ContextualSpacing),
             "pageBreakBefore" => Ok(XMLElement::PageBreakBefore),
             "windowControl" => Ok(XMLElement::WindowControl),
             _ => Ok(XMLElement::Unsupported),
@@ -377,6 +477,7 @@ impl FromStr for AXMLElement {
             "ext" => Ok(AXMLElement::Ext),
             "prstGeom" => Ok(AXMLElement::PrstGeom),
             "solidFill" => Ok(AXMLElement::SolidFill),
+            "srgbClr" => Ok(AXMLElement::SrgbClr),
             "ln" => Ok(AXMLElement::Ln),
             _ => Ok(AXMLElement::Unsupported),
         }
@@ -392,6 +493,7 @@ impl FromStr for WpsXMLElement {
             "spPr" => Ok(WpsXMLElement::SpProperty),
             "style" => Ok(WpsXMLElement::Style),
             "txbx" => Ok(WpsXMLElement::Txbx),
+            "linkedTxbx" => Ok(WpsXMLElement::LinkedTxbx),
             "bodyPr" => Ok(WpsXMLElement::BodyPr),
             _ => Ok(WpsXMLElement::Unsupported),
         }
@@ -403,6 +505,8 @@ impl FromStr for VXMLElement {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "rect" => Ok(VXMLElement::Rect),
+            "shape" => Ok(VXMLElement::Shape),
+            "imagedata" => Ok(VXMLElement::ImageData),
             "stroke" => Ok(VXMLElement::Stroke),
             "fill" => Ok(VXMLElement::Fill),
             "textbox" => Ok(VXMLElement::TextBox),
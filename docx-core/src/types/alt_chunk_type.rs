@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+//
+// The format of the altChunk part, which is told by its content type.
+//
+// text/html
+// application/xhtml+xml
+// message/rfc822
+// application/rtf
+// text/plain
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum AltChunkType {
+    Html,
+    Xhtml,
+    Mht,
+    Rtf,
+    Text,
+}
+
+impl AltChunkType {
+    pub fn content_type(&self) -> &'static str {
+        match *self {
+            AltChunkType::Html => "text/html",
+            AltChunkType::Xhtml => "application/xhtml+xml",
+            AltChunkType::Mht => "message/rfc822",
+            AltChunkType::Rtf => "application/rtf",
+            AltChunkType::Text => "text/plain",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            AltChunkType::Html => "html",
+            AltChunkType::Xhtml => "xhtml",
+            AltChunkType::Mht => "mht",
+            AltChunkType::Rtf => "rtf",
+            AltChunkType::Text => "txt",
+        }
+    }
+
+    pub fn from_extension(ext: &str) -> Option<AltChunkType> {
+        match ext.to_ascii_lowercase().as_str() {
+            "html" | "htm" => Some(AltChunkType::Html),
+            "xhtml" => Some(AltChunkType::Xhtml),
+            "mht" | "mhtml" => Some(AltChunkType::Mht),
+            "rtf" => Some(AltChunkType::Rtf),
+            "txt" => Some(AltChunkType::Text),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AltChunkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.content_type())
+    }
+}
+
+impl FromStr for AltChunkType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text/html" => Ok(AltChunkType::Html),
+            "application/xhtml+xml" => Ok(AltChunkType::Xhtml),
+            "message/rfc822" => Ok(AltChunkType::Mht),
+            "application/rtf" | "text/rtf" => Ok(AltChunkType::Rtf),
+            "text/plain" => Ok(AltChunkType::Text),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
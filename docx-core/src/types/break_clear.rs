@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see <xsd:simpleType name="ST_BrClear">
+//
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum BreakClear {
+    None,
+    Left,
+    Right,
+    All,
+}
+
+impl fmt::Display for BreakClear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BreakClear::None => write!(f, "none"),
+            BreakClear::Left => write!(f, "left"),
+            BreakClear::Right => write!(f, "right"),
+            BreakClear::All => write!(f, "all"),
+        }
+    }
+}
+
+impl FromStr for BreakClear {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(BreakClear::None),
+            "left" => Ok(BreakClear::Left),
+            "right" => Ok(BreakClear::Right),
+            "all" => Ok(BreakClear::All),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_DocProtect
+//
+// <xsd:enumeration value="none"/>
+// <xsd:enumeration value="readOnly"/>
+// <xsd:enumeration value="comments"/>
+// <xsd:enumeration value="trackedChanges"/>
+// <xsd:enumeration value="forms"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DocumentProtectionType {
+    None,
+    ReadOnly,
+    Comments,
+    TrackedChanges,
+    Forms,
+}
+
+impl fmt::Display for DocumentProtectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DocumentProtectionType::None => write!(f, "none"),
+            DocumentProtectionType::ReadOnly => write!(f, "readOnly"),
+            DocumentProtectionType::Comments => write!(f, "comments"),
+            DocumentProtectionType::TrackedChanges => write!(f, "trackedChanges"),
+            DocumentProtectionType::Forms => write!(f, "forms"),
+        }
+    }
+}
+
+impl FromStr for DocumentProtectionType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(DocumentProtectionType::None),
+            "readOnly" => Ok(DocumentProtectionType::ReadOnly),
+            "comments" => Ok(DocumentProtectionType::Comments),
+            "trackedChanges" => Ok(DocumentProtectionType::TrackedChanges),
+            "forms" => Ok(DocumentProtectionType::Forms),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
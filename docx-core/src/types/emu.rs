@@ -1,10 +1,111 @@
-// One inch equates to 914400 EMUs and a centimeter is 360000 one pixel equates to 9525
-type Emu = u32;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use serde::Serialize;
 
-pub fn to_px(v: Emu) -> u32 {
+use super::Twip;
+
+// One inch equates to 914400 EMUs and a centimeter is 360000 one pixel equates to 9525
+pub fn to_px(v: u32) -> u32 {
     v / 9525
 }
 
-pub fn from_px(v: Emu) -> u32 {
+pub fn from_px(v: u32) -> u32 {
     v * 9525
 }
+
+// English Metric Unit, the unit of DrawingML extents and offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize)]
+pub struct Emu(pub i64);
+
+impl Emu {
+    pub fn from_px(v: u32) -> Emu {
+        Emu(i64::from(v) * 9525)
+    }
+
+    pub fn from_pt(v: f64) -> Emu {
+        Emu((v * 12700.0).round() as i64)
+    }
+
+    pub fn from_mm(v: f64) -> Emu {
+        Emu((v * 36000.0).round() as i64)
+    }
+
+    pub fn from_cm(v: f64) -> Emu {
+        Emu((v * 360000.0).round() as i64)
+    }
+
+    pub fn from_inch(v: f64) -> Emu {
+        Emu((v * 914400.0).round() as i64)
+    }
+
+    // Rounded to the nearest pixel.
+    pub fn to_px(self) -> u32 {
+        ((self.0.max(0) + 4762) / 9525) as u32
+    }
+}
+
+impl From<i64> for Emu {
+    fn from(v: i64) -> Emu {
+        Emu(v)
+    }
+}
+
+impl From<i32> for Emu {
+    fn from(v: i32) -> Emu {
+        Emu(i64::from(v))
+    }
+}
+
+impl From<u32> for Emu {
+    fn from(v: u32) -> Emu {
+        Emu(i64::from(v))
+    }
+}
+
+// A twip is 635 EMUs.
+impl From<Twip> for Emu {
+    fn from(v: Twip) -> Emu {
+        Emu(i64::from(v.0) * 635)
+    }
+}
+
+impl From<Emu> for i64 {
+    fn from(v: Emu) -> i64 {
+        v.0
+    }
+}
+
+impl Add for Emu {
+    type Output = Emu;
+    fn add(self, rhs: Emu) -> Emu {
+        Emu(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Emu {
+    type Output = Emu;
+    fn sub(self, rhs: Emu) -> Emu {
+        Emu(self.0 - rhs.0)
+    }
+}
+
+impl Mul<i64> for Emu {
+    type Output = Emu;
+    fn mul(self, rhs: i64) -> Emu {
+        Emu(self.0 * rhs)
+    }
+}
+
+impl Div<i64> for Emu {
+    type Output = Emu;
+    fn div(self, rhs: i64) -> Emu {
+        Emu(self.0 / rhs)
+    }
+}
+
+impl Neg for Emu {
+    type Output = Emu;
+    fn neg(self) -> Emu {
+        Emu(-self.0)
+    }
+}
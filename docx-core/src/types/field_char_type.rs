@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_FldCharType
+//
+// <xsd:enumeration value="begin"/>
+// <xsd:enumeration value="separate"/>
+// <xsd:enumeration value="end"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FieldCharType {
+    Begin,
+    Separate,
+    End,
+}
+
+impl fmt::Display for FieldCharType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FieldCharType::Begin => write!(f, "begin"),
+            FieldCharType::Separate => write!(f, "separate"),
+            FieldCharType::End => write!(f, "end"),
+        }
+    }
+}
+
+impl FromStr for FieldCharType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "begin" => Ok(FieldCharType::Begin),
+            "separate" => Ok(FieldCharType::Separate),
+            "end" => Ok(FieldCharType::End),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_Hint
+//
+// <xsd:enumeration value="default"/>
+// <xsd:enumeration value="eastAsia"/>
+// <xsd:enumeration value="cs"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FontHintType {
+    Default,
+    EastAsia,
+    Cs,
+}
+
+impl fmt::Display for FontHintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FontHintType::Default => write!(f, "default"),
+            FontHintType::EastAsia => write!(f, "eastAsia"),
+            FontHintType::Cs => write!(f, "cs"),
+        }
+    }
+}
+
+impl FromStr for FontHintType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(FontHintType::Default),
+            "eastAsia" => Ok(FontHintType::EastAsia),
+            "cs" => Ok(FontHintType::Cs),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_HdrFtr
+//
+// <xsd:enumeration value="even"/>
+// <xsd:enumeration value="default"/>
+// <xsd:enumeration value="first"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum HeaderFooterReferenceType {
+    Default,
+    First,
+    Even,
+}
+
+impl fmt::Display for HeaderFooterReferenceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HeaderFooterReferenceType::Default => write!(f, "default"),
+            HeaderFooterReferenceType::First => write!(f, "first"),
+            HeaderFooterReferenceType::Even => write!(f, "even"),
+        }
+    }
+}
+
+impl FromStr for HeaderFooterReferenceType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(HeaderFooterReferenceType::Default),
+            "first" => Ok(HeaderFooterReferenceType::First),
+            "even" => Ok(HeaderFooterReferenceType::Even),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -1,48 +1,76 @@
 pub mod alignment_type;
+pub mod alt_chunk_type;
 pub mod border_position;
 pub mod border_type;
+pub mod break_clear;
 pub mod break_type;
 pub mod doc_grid_type;
+pub mod document_protection_type;
 pub mod emu;
 pub mod errors;
+pub mod field_char_type;
+pub mod font_hint_type;
 pub mod font_pitch_type;
+pub mod header_footer_reference_type;
 pub mod height_rule;
 pub mod level_suffix_type;
 pub mod line_spacing_type;
+pub mod note_position_type;
+pub mod note_restart_type;
+pub mod number_format_type;
 pub mod page_margin;
 pub mod page_orientation_type;
 pub mod section_type;
+pub mod shape_type;
 pub mod shd_type;
 pub mod special_indent_type;
 pub mod style_type;
+pub mod tab_leader_type;
+pub mod tab_value_type;
 pub mod table_alignment_type;
 pub mod table_layout_type;
+pub mod target_mode;
 pub mod text_direction_type;
+pub mod twip;
 pub mod vert_align_type;
 pub mod vertical_align_type;
 pub mod vertical_merge_type;
 pub mod width_type;
 
 pub use alignment_type::*;
+pub use alt_chunk_type::*;
 pub use border_position::*;
 pub use border_type::*;
+pub use break_clear::*;
 pub use break_type::*;
 pub use doc_grid_type::*;
+pub use document_protection_type::*;
 pub use emu::*;
 pub use errors::*;
+pub use field_char_type::*;
+pub use font_hint_type::*;
 pub use font_pitch_type::*;
+pub use header_footer_reference_type::*;
 pub use height_rule::*;
 pub use level_suffix_type::*;
 pub use line_spacing_type::*;
+pub use note_position_type::*;
+pub use note_restart_type::*;
+pub use number_format_type::*;
 pub use page_margin::*;
 pub use page_orientation_type::*;
 pub use section_type::*;
+pub use shape_type::*;
 pub use shd_type::*;
 pub use special_indent_type::*;
 pub use style_type::*;
+pub use tab_leader_type::*;
+pub use tab_value_type::*;
 pub use table_alignment_type::*;
 pub use table_layout_type::*;
+pub use target_mode::*;
 pub use text_direction_type::*;
+pub use twip::*;
 pub use vert_align_type::*;
 pub use vertical_align_type::*;
 pub use vertical_merge_type::*;
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_FtnPos
+//
+// <xsd:enumeration value="pageBottom"/>
+// <xsd:enumeration value="beneathText"/>
+// <xsd:enumeration value="sectEnd"/>
+// <xsd:enumeration value="docEnd"/>
+//
+// ST_EdnPos has only sectEnd and docEnd.
+//
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FootnotePositionType {
+    PageBottom,
+    BeneathText,
+    SectEnd,
+    DocEnd,
+}
+
+impl fmt::Display for FootnotePositionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FootnotePositionType::PageBottom => write!(f, "pageBottom"),
+            FootnotePositionType::BeneathText => write!(f, "beneathText"),
+            FootnotePositionType::SectEnd => write!(f, "sectEnd"),
+            FootnotePositionType::DocEnd => write!(f, "docEnd"),
+        }
+    }
+}
+
+impl FromStr for FootnotePositionType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pageBottom" => Ok(FootnotePositionType::PageBottom),
+            "beneathText" => Ok(FootnotePositionType::BeneathText),
+            "sectEnd" => Ok(FootnotePositionType::SectEnd),
+            "docEnd" => Ok(FootnotePositionType::DocEnd),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum EndnotePositionType {
+    SectEnd,
+    DocEnd,
+}
+
+impl fmt::Display for EndnotePositionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EndnotePositionType::SectEnd => write!(f, "sectEnd"),
+            EndnotePositionType::DocEnd => write!(f, "docEnd"),
+        }
+    }
+}
+
+impl FromStr for EndnotePositionType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sectEnd" => Ok(EndnotePositionType::SectEnd),
+            "docEnd" => Ok(EndnotePositionType::DocEnd),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_RestartNumber
+//
+// <xsd:enumeration value="continuous"/>
+// <xsd:enumeration value="eachSect"/>
+// <xsd:enumeration value="eachPage"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum NoteRestartType {
+    Continuous,
+    EachSection,
+    EachPage,
+}
+
+impl fmt::Display for NoteRestartType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NoteRestartType::Continuous => write!(f, "continuous"),
+            NoteRestartType::EachSection => write!(f, "eachSect"),
+            NoteRestartType::EachPage => write!(f, "eachPage"),
+        }
+    }
+}
+
+impl FromStr for NoteRestartType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "continuous" => Ok(NoteRestartType::Continuous),
+            "eachSect" => Ok(NoteRestartType::EachSection),
+            "eachPage" => Ok(NoteRestartType::EachPage),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
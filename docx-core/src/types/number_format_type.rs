@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_NumberFormat
+//
+// <xsd:enumeration value="decimal"/>
+// <xsd:enumeration value="upperRoman"/>
+// <xsd:enumeration value="lowerRoman"/>
+// <xsd:enumeration value="upperLetter"/>
+// <xsd:enumeration value="lowerLetter"/>
+// <xsd:enumeration value="ordinal"/>
+// <xsd:enumeration value="cardinalText"/>
+// <xsd:enumeration value="ordinalText"/>
+// <xsd:enumeration value="hex"/>
+// <xsd:enumeration value="chicago"/>
+// <xsd:enumeration value="ideographDigital"/>
+// <xsd:enumeration value="japaneseCounting"/>
+// <xsd:enumeration value="aiueo"/>
+// <xsd:enumeration value="iroha"/>
+// <xsd:enumeration value="decimalFullWidth"/>
+// <xsd:enumeration value="decimalHalfWidth"/>
+// <xsd:enumeration value="japaneseLegal"/>
+// <xsd:enumeration value="japaneseDigitalTenThousand"/>
+// <xsd:enumeration value="decimalEnclosedCircle"/>
+// <xsd:enumeration value="decimalFullWidth2"/>
+// <xsd:enumeration value="aiueoFullWidth"/>
+// <xsd:enumeration value="irohaFullWidth"/>
+// <xsd:enumeration value="decimalZero"/>
+// <xsd:enumeration value="bullet"/>
+// <xsd:enumeration value="ganada"/>
+// <xsd:enumeration value="chosung"/>
+// <xsd:enumeration value="decimalEnclosedFullstop"/>
+// <xsd:enumeration value="decimalEnclosedParen"/>
+// <xsd:enumeration value="decimalEnclosedCircleChinese"/>
+// <xsd:enumeration value="ideographEnclosedCircle"/>
+// <xsd:enumeration value="ideographTraditional"/>
+// <xsd:enumeration value="ideographZodiac"/>
+// <xsd:enumeration value="ideographZodiacTraditional"/>
+// <xsd:enumeration value="taiwaneseCounting"/>
+// <xsd:enumeration value="ideographLegalTraditional"/>
+// <xsd:enumeration value="taiwaneseCountingThousand"/>
+// <xsd:enumeration value="taiwaneseDigital"/>
+// <xsd:enumeration value="chineseCounting"/>
+// <xsd:enumeration value="chineseLegalSimplified"/>
+// <xsd:enumeration value="chineseCountingThousand"/>
+// <xsd:enumeration value="koreanDigital"/>
+// <xsd:enumeration value="koreanCounting"/>
+// <xsd:enumeration value="koreanLegal"/>
+// <xsd:enumeration value="koreanDigital2"/>
+// <xsd:enumeration value="vietnameseCounting"/>
+// <xsd:enumeration value="russianLower"/>
+// <xsd:enumeration value="russianUpper"/>
+// <xsd:enumeration value="none"/>
+// <xsd:enumeration value="numberInDash"/>
+// <xsd:enumeration value="hebrew1"/>
+// <xsd:enumeration value="hebrew2"/>
+// <xsd:enumeration value="arabicAlpha"/>
+// <xsd:enumeration value="arabicAbjad"/>
+// <xsd:enumeration value="hindiVowels"/>
+// <xsd:enumeration value="hindiConsonants"/>
+// <xsd:enumeration value="hindiNumbers"/>
+// <xsd:enumeration value="hindiCounting"/>
+// <xsd:enumeration value="thaiLetters"/>
+// <xsd:enumeration value="thaiNumbers"/>
+// <xsd:enumeration value="thaiCounting"/>
+// <xsd:enumeration value="bahtText"/>
+// <xsd:enumeration value="dollarText"/>
+// <xsd:enumeration value="custom"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum NumberFormatType {
+    Decimal,
+    UpperRoman,
+    LowerRoman,
+    UpperLetter,
+    LowerLetter,
+    Ordinal,
+    CardinalText,
+    OrdinalText,
+    Hex,
+    Chicago,
+    IdeographDigital,
+    JapaneseCounting,
+    Aiueo,
+    Iroha,
+    DecimalFullWidth,
+    DecimalHalfWidth,
+    JapaneseLegal,
+    JapaneseDigitalTenThousand,
+    DecimalEnclosedCircle,
+    DecimalFullWidth2,
+    AiueoFullWidth,
+    IrohaFullWidth,
+    DecimalZero,
+    Bullet,
+    Ganada,
+    Chosung,
+    DecimalEnclosedFullstop,
+    DecimalEnclosedParen,
+    DecimalEnclosedCircleChinese,
+    IdeographEnclosedCircle,
+    IdeographTraditional,
+    IdeographZodiac,
+    IdeographZodiacTraditional,
+    TaiwaneseCounting,
+    IdeographLegalTraditional,
+    TaiwaneseCountingThousand,
+    TaiwaneseDigital,
+    ChineseCounting,
+    ChineseLegalSimplified,
+    ChineseCountingThousand,
+    KoreanDigital,
+    KoreanCounting,
+    KoreanLegal,
+    KoreanDigital2,
+    VietnameseCounting,
+    RussianLower,
+    RussianUpper,
+    None,
+    NumberInDash,
+    Hebrew1,
+    Hebrew2,
+    ArabicAlpha,
+    ArabicAbjad,
+    HindiVowels,
+    HindiConsonants,
+    HindiNumbers,
+    HindiCounting,
+    ThaiLetters,
+    ThaiNumbers,
+    ThaiCounting,
+    BahtText,
+    DollarText,
+    Custom,
+}
+
+impl fmt::Display for NumberFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NumberFormatType::Decimal => write!(f, "decimal"),
+            NumberFormatType::UpperRoman => write!(f, "upperRoman"),
+            NumberFormatType::LowerRoman => write!(f, "lowerRoman"),
+            NumberFormatType::UpperLetter => write!(f, "upperLetter"),
+            NumberFormatType::LowerLetter => write!(f, "lowerLetter"),
+            NumberFormatType::Ordinal => write!(f, "ordinal"),
+            NumberFormatType::CardinalText => write!(f, "cardinalText"),
+            NumberFormatType::OrdinalText => write!(f, "ordinalText"),
+            NumberFormatType::Hex => write!(f, "hex"),
+            NumberFormatType::Chicago => write!(f, "chicago"),
+            NumberFormatType::IdeographDigital => write!(f, "ideographDigital"),
+            NumberFormatType::JapaneseCounting => write!(f, "japaneseCounting"),
+            NumberFormatType::Aiueo => write!(f, "aiueo"),
+            NumberFormatType::Iroha => write!(f, "iroha"),
+            NumberFormatType::DecimalFullWidth => write!(f, "decimalFullWidth"),
+            NumberFormatType::DecimalHalfWidth => write!(f, "decimalHalfWidth"),
+            NumberFormatType::JapaneseLegal => write!(f, "japaneseLegal"),
+            NumberFormatType::JapaneseDigitalTenThousand => write!(f, "japaneseDigitalTenThousand"),
+            NumberFormatType::DecimalEnclosedCircle => write!(f, "decimalEnclosedCircle"),
+            NumberFormatType::DecimalFullWidth2 => write!(f, "decimalFullWidth2"),
+            NumberFormatType::AiueoFullWidth => write!(f, "aiueoFullWidth"),
+            NumberFormatType::IrohaFullWidth => write!(f, "irohaFullWidth"),
+            NumberFormatType::DecimalZero => write!(f, "decimalZero"),
+            NumberFormatType::Bullet => write!(f, "bullet"),
+            NumberFormatType::Ganada => write!(f, "ganada"),
+            NumberFormatType::Chosung => write!(f, "chosung"),
+            NumberFormatType::DecimalEnclosedFullstop => write!(f, "decimalEnclosedFullstop"),
+            NumberFormatType::DecimalEnclosedParen => write!(f, "decimalEnclosedParen"),
+            NumberFormatType::DecimalEnclosedCircleChinese => {
+                write!(f, "decimalEnclosedCircleChinese")
+            }
+            NumberFormatType::IdeographEnclosedCircle => write!(f, "ideographEnclosedCircle"),
+            NumberFormatType::IdeographTraditional => write!(f, "ideographTraditional"),
+            NumberFormatType::IdeographZodiac => write!(f, "ideographZodiac"),
+            NumberFormatType::IdeographZodiacTraditional => write!(f, "ideographZodiacTraditional"),
+            NumberFormatType::TaiwaneseCounting => write!(f, "taiwaneseCounting"),
+            NumberFormatType::IdeographLegalTraditional => write!(f, "ideographLegalTraditional"),
+            NumberFormatType::TaiwaneseCountingThousand => write!(f, "taiwaneseCountingThousand"),
+            NumberFormatType::TaiwaneseDigital => write!(f, "taiwaneseDigital"),
+            NumberFormatType::ChineseCounting => write!(f, "chineseCounting"),
+            NumberFormatType::ChineseLegalSimplified => write!(f, "chineseLegalSimplified"),
+            NumberFormatType::ChineseCountingThousand => write!(f, "chineseCountingThousand"),
+            NumberFormatType::KoreanDigital => write!(f, "koreanDigital"),
+            NumberFormatType::KoreanCounting => write!(f, "koreanCounting"),
+            NumberFormatType::KoreanLegal => write!(f, "koreanLegal"),
+            NumberFormatType::KoreanDigital2 => write!(f, "koreanDigital2"),
+            NumberFormatType::VietnameseCounting => write!(f, "vietnameseCounting"),
+            NumberFormatType::RussianLower => write!(f, "russianLower"),
+            NumberFormatType::RussianUpper => write!(f, "russianUpper"),
+            NumberFormatType::None => write!(f, "none"),
+            NumberFormatType::NumberInDash => write!(f, "numberInDash"),
+            NumberFormatType::Hebrew1 => write!(f, "hebrew1"),
+            NumberFormatType::Hebrew2 => write!(f, "hebrew2"),
+            NumberFormatType::ArabicAlpha => write!(f, "arabicAlpha"),
+            NumberFormatType::ArabicAbjad => write!(f, "arabicAbjad"),
+            NumberFormatType::HindiVowels => write!(f, "hindiVowels"),
+            NumberFormatType::HindiConsonants => write!(f, "hindiConsonants"),
+            NumberFormatType::HindiNumbers => write!(f, "hindiNumbers"),
+            NumberFormatType::HindiCounting => write!(f, "hindiCounting"),
+            NumberFormatType::ThaiLetters => write!(f, "thaiLetters"),
+            NumberFormatType::ThaiNumbers => write!(f, "thaiNumbers"),
+            NumberFormatType::ThaiCounting => write!(f, "thaiCounting"),
+            NumberFormatType::BahtText => write!(f, "bahtText"),
+            NumberFormatType::DollarText => write!(f, "dollarText"),
+            NumberFormatType::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+impl FromStr for NumberFormatType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "decimal" => Ok(NumberFormatType::Decimal),
+            "upperRoman" => Ok(NumberFormatType::UpperRoman),
+            "lowerRoman" => Ok(NumberFormatType::LowerRoman),
+            "upperLetter" => Ok(NumberFormatType::UpperLetter),
+            "lowerLetter" => Ok(NumberFormatType::LowerLetter),
+            "ordinal" => Ok(NumberFormatType::Ordinal),
+            "cardinalText" => Ok(NumberFormatType::CardinalText),
+            "ordinalText" => Ok(NumberFormatType::OrdinalText),
+            "hex" => Ok(NumberFormatType::Hex),
+            "chicago" => Ok(NumberFormatType::Chicago),
+            "ideographDigital" => Ok(NumberFormatType::IdeographDigital),
+            "japaneseCounting" => Ok(NumberFormatType::JapaneseCounting),
+            "aiueo" => Ok(NumberFormatType::Aiueo),
+            "iroha" => Ok(NumberFormatType::Iroha),
+            "decimalFullWidth" => Ok(NumberFormatType::DecimalFullWidth),
+            "decimalHalfWidth" => Ok(NumberFormatType::DecimalHalfWidth),
+            "japaneseLegal" => Ok(NumberFormatType::JapaneseLegal),
+            "japaneseDigitalTenThousand" => Ok(NumberFormatType::JapaneseDigitalTenThousand),
+            "decimalEnclosedCircle" => Ok(NumberFormatType::DecimalEnclosedCircle),
+            "decimalFullWidth2" => Ok(NumberFormatType::DecimalFullWidth2),
+            "aiueoFullWidth" => Ok(NumberFormatType::AiueoFullWidth),
+            "irohaFullWidth" => Ok(NumberFormatType::IrohaFullWidth),
+            "decimalZero" => Ok(NumberFormatType::DecimalZero),
+            "bullet" => Ok(NumberFormatType::Bullet),
+            "ganada" => Ok(NumberFormatType::Ganada),
+            "chosung" => Ok(NumberFormatType::Chosung),
+            "decimalEnclosedFullstop" => Ok(NumberFormatType::DecimalEnclosedFullstop),
+            "decimalEnclosedParen" => Ok(NumberFormatType::DecimalEnclosedParen),
+            "decimalEnclosedCircleChinese" => Ok(NumberFormatType::DecimalEnclosedCircleChinese),
+            "ideographEnclosedCircle" => Ok(NumberFormatType::IdeographEnclosedCircle),
+            "ideographTraditional" => Ok(NumberFormatType::IdeographTraditional),
+            "ideographZodiac" => Ok(NumberFormatType::IdeographZodiac),
+            "ideographZodiacTraditional" => Ok(NumberFormatType::IdeographZodiacTraditional),
+            "taiwaneseCounting" => Ok(NumberFormatType::TaiwaneseCounting),
+            "ideographLegalTraditional" => Ok(NumberFormatType::IdeographLegalTraditional),
+            "taiwaneseCountingThousand" => Ok(NumberFormatType::TaiwaneseCountingThousand),
+            "taiwaneseDigital" => Ok(NumberFormatType::TaiwaneseDigital),
+            "chineseCounting" => Ok(NumberFormatType::ChineseCounting),
+            "chineseLegalSimplified" => Ok(NumberFormatType::ChineseLegalSimplified),
+            "chineseCountingThousand" => Ok(NumberFormatType::ChineseCountingThousand),
+            "koreanDigital" => Ok(NumberFormatType::KoreanDigital),
+            "koreanCounting" => Ok(NumberFormatType::KoreanCounting),
+            "koreanLegal" => Ok(NumberFormatType::KoreanLegal),
+            "koreanDigital2" => Ok(NumberFormatType::KoreanDigital2),
+            "vietnameseCounting" => Ok(NumberFormatType::VietnameseCounting),
+            "russianLower" => Ok(NumberFormatType::RussianLower),
+            "russianUpper" => Ok(NumberFormatType::RussianUpper),
+            "none" => Ok(NumberFormatType::None),
+            "numberInDash" => Ok(NumberFormatType::NumberInDash),
+            "hebrew1" => Ok(NumberFormatType::Hebrew1),
+            "hebrew2" => Ok(NumberFormatType::Hebrew2),
+            "arabicAlpha" => Ok(NumberFormatType::ArabicAlpha),
+            "arabicAbjad" => Ok(NumberFormatType::ArabicAbjad),
+            "hindiVowels" => Ok(NumberFormatType::HindiVowels),
+            "hindiConsonants" => Ok(NumberFormatType::HindiConsonants),
+            "hindiNumbers" => Ok(NumberFormatType::HindiNumbers),
+            "hindiCounting" => Ok(NumberFormatType::HindiCounting),
+            "thaiLetters" => Ok(NumberFormatType::ThaiLetters),
+            "thaiNumbers" => Ok(NumberFormatType::ThaiNumbers),
+            "thaiCounting" => Ok(NumberFormatType::ThaiCounting),
+            "bahtText" => Ok(NumberFormatType::BahtText),
+            "dollarText" => Ok(NumberFormatType::DollarText),
+            "custom" => Ok(NumberFormatType::Custom),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_ShapeType
+//
+// Only the preset geometries commonly used for diagrams are supported.
+//
+// <xsd:enumeration value="rect"/>
+// <xsd:enumeration value="roundRect"/>
+// <xsd:enumeration value="ellipse"/>
+// <xsd:enumeration value="triangle"/>
+// <xsd:enumeration value="diamond"/>
+// <xsd:enumeration value="rightArrow"/>
+// <xsd:enumeration value="leftArrow"/>
+// <xsd:enumeration value="upArrow"/>
+// <xsd:enumeration value="downArrow"/>
+// <xsd:enumeration value="flowChartProcess"/>
+// <xsd:enumeration value="flowChartDecision"/>
+// <xsd:enumeration value="flowChartTerminator"/>
+// <xsd:enumeration value="wedgeRectCallout"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ShapeType {
+    Rect,
+    RoundRect,
+    Ellipse,
+    Triangle,
+    Diamond,
+    RightArrow,
+    LeftArrow,
+    UpArrow,
+    DownArrow,
+    FlowChartProcess,
+    FlowChartDecision,
+    FlowChartTerminator,
+    WedgeRectCallout,
+}
+
+impl fmt::Display for ShapeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ShapeType::Rect => write!(f, "rect"),
+            ShapeType::RoundRect => write!(f, "roundRect"),
+            ShapeType::Ellipse => write!(f, "ellipse"),
+            ShapeType::Triangle => write!(f, "triangle"),
+            ShapeType::Diamond => write!(f, "diamond"),
+            ShapeType::RightArrow => write!(f, "rightArrow"),
+            ShapeType::LeftArrow => write!(f, "leftArrow"),
+            ShapeType::UpArrow => write!(f, "upArrow"),
+            ShapeType::DownArrow => write!(f, "downArrow"),
+            ShapeType::FlowChartProcess => write!(f, "flowChartProcess"),
+            ShapeType::FlowChartDecision => write!(f, "flowChartDecision"),
+            ShapeType::FlowChartTerminator => write!(f, "flowChartTerminator"),
+            ShapeType::WedgeRectCallout => write!(f, "wedgeRectCallout"),
+        }
+    }
+}
+
+impl FromStr for ShapeType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rect" => Ok(ShapeType::Rect),
+            "roundRect" => Ok(ShapeType::RoundRect),
+            "ellipse" => Ok(ShapeType::Ellipse),
+            "triangle" => Ok(ShapeType::Triangle),
+            "diamond" => Ok(ShapeType::Diamond),
+            "rightArrow" => Ok(ShapeType::RightArrow),
+            "leftArrow" => Ok(ShapeType::LeftArrow),
+            "upArrow" => Ok(ShapeType::UpArrow),
+            "downArrow" => Ok(ShapeType::DownArrow),
+            "flowChartProcess" => Ok(ShapeType::FlowChartProcess),
+            "flowChartDecision" => Ok(ShapeType::FlowChartDecision),
+            "flowChartTerminator" => Ok(ShapeType::FlowChartTerminator),
+            "wedgeRectCallout" => Ok(ShapeType::WedgeRectCallout),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_TabTlc
+//
+// <xsd:enumeration value="none"/>
+// <xsd:enumeration value="dot"/>
+// <xsd:enumeration value="hyphen"/>
+// <xsd:enumeration value="underscore"/>
+// <xsd:enumeration value="heavy"/>
+// <xsd:enumeration value="middleDot"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TabLeaderType {
+    None,
+    Dot,
+    Hyphen,
+    Underscore,
+    Heavy,
+    MiddleDot,
+}
+
+impl fmt::Display for TabLeaderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TabLeaderType::None => write!(f, "none"),
+            TabLeaderType::Dot => write!(f, "dot"),
+            TabLeaderType::Hyphen => write!(f, "hyphen"),
+            TabLeaderType::Underscore => write!(f, "underscore"),
+            TabLeaderType::Heavy => write!(f, "heavy"),
+            TabLeaderType::MiddleDot => write!(f, "middleDot"),
+        }
+    }
+}
+
+impl FromStr for TabLeaderType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(TabLeaderType::None),
+            "dot" => Ok(TabLeaderType::Dot),
+            "hyphen" => Ok(TabLeaderType::Hyphen),
+            "underscore" => Ok(TabLeaderType::Underscore),
+            "heavy" => Ok(TabLeaderType::Heavy),
+            "middleDot" => Ok(TabLeaderType::MiddleDot),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
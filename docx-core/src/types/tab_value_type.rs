@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_TabJc
+//
+// <xsd:enumeration value="clear"/>
+// <xsd:enumeration value="start"/>
+// <xsd:enumeration value="center"/>
+// <xsd:enumeration value="end"/>
+// <xsd:enumeration value="decimal"/>
+// <xsd:enumeration value="bar"/>
+// <xsd:enumeration value="num"/>
+// <xsd:enumeration value="left"/>
+// <xsd:enumeration value="right"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TabValueType {
+    Clear,
+    Start,
+    Center,
+    End,
+    Decimal,
+    Bar,
+    Num,
+    Left,
+    Right,
+}
+
+impl fmt::Display for TabValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TabValueType::Clear => write!(f, "clear"),
+            TabValueType::Start => write!(f, "start"),
+            TabValueType::Center => write!(f, "center"),
+            TabValueType::End => write!(f, "end"),
+            TabValueType::Decimal => write!(f, "decimal"),
+            TabValueType::Bar => write!(f, "bar"),
+            TabValueType::Num => write!(f, "num"),
+            TabValueType::Left => write!(f, "left"),
+            TabValueType::Right => write!(f, "right"),
+        }
+    }
+}
+
+impl FromStr for TabValueType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clear" => Ok(TabValueType::Clear),
+            "start" => Ok(TabValueType::Start),
+            "center" => Ok(TabValueType::Center),
+            "end" => Ok(TabValueType::End),
+            "decimal" => Ok(TabValueType::Decimal),
+            "bar" => Ok(TabValueType::Bar),
+            "num" => Ok(TabValueType::Num),
+            "left" => Ok(TabValueType::Left),
+            "right" => Ok(TabValueType::Right),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
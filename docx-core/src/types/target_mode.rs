@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_TargetMode
+//
+// <xsd:enumeration value="External"/>
+// <xsd:enumeration value="Internal"/>
+
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TargetMode {
+    Internal,
+    External,
+}
+
+impl fmt::Display for TargetMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TargetMode::Internal => write!(f, "Internal"),
+            TargetMode::External => write!(f, "External"),
+        }
+    }
+}
+
+impl FromStr for TargetMode {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Internal" => Ok(TargetMode::Internal),
+            "External" => Ok(TargetMode::External),
+            _ => Err(errors::TypeError::FromStrError),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use serde::Serialize;
+
+// Twentieth of a point, the unit of page sizes, margins, indents and spacing.
+// Font sizes are half-points instead, see Run::size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize)]
+pub struct Twip(pub i32);
+
+impl Twip {
+    pub fn from_pt(v: f64) -> Twip {
+        Twip((v * 20.0).round() as i32)
+    }
+
+    pub fn from_mm(v: f64) -> Twip {
+        Twip((v * 1440.0 / 25.4).round() as i32)
+    }
+
+    pub fn from_cm(v: f64) -> Twip {
+        Twip((v * 1440.0 / 2.54).round() as i32)
+    }
+
+    pub fn from_inch(v: f64) -> Twip {
+        Twip((v * 1440.0).round() as i32)
+    }
+
+    // For unsigned measures such as page sizes, negative values are clamped to 0.
+    pub(crate) fn unsigned(self) -> u32 {
+        self.0.max(0) as u32
+    }
+}
+
+impl From<i32> for Twip {
+    fn from(v: i32) -> Twip {
+        Twip(v)
+    }
+}
+
+impl From<u32> for Twip {
+    fn from(v: u32) -> Twip {
+        Twip(v as i32)
+    }
+}
+
+impl From<Twip> for i32 {
+    fn from(v: Twip) -> i32 {
+        v.0
+    }
+}
+
+impl From<Twip> for u32 {
+    fn from(v: Twip) -> u32 {
+        v.unsigned()
+    }
+}
+
+impl Add for Twip {
+    type Output = Twip;
+    fn add(self, rhs: Twip) -> Twip {
+        Twip(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Twip {
+    type Output = Twip;
+    fn sub(self, rhs: Twip) -> Twip {
+        Twip(self.0 - rhs.0)
+    }
+}
+
+impl Mul<i32> for Twip {
+    type Output = Twip;
+    fn mul(self, rhs: i32) -> Twip {
+        Twip(self.0 * rhs)
+    }
+}
+
+impl Div<i32> for Twip {
+    type Output = Twip;
+    fn div(self, rhs: i32) -> Twip {
+        Twip(self.0 / rhs)
+    }
+}
+
+impl Neg for Twip {
+    type Output = Twip;
+    fn neg(self) -> Twip {
+        Twip(-self.0)
+    }
+}
@@ -45,4 +45,5 @@ impl XMLBuilder {
     open!(open_position_v, "wp:positionV", "relativeFrom");
     closed_with_child!(pos_offset, "wp:posOffset");
     closed!(wrap_none, "wp:wrapNone");
+    closed!(wrap_square, "wp:wrapSquare", "wrapText");
 }
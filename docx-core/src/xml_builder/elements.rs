@@ -24,30 +24,26 @@ impl XMLBuilder {
         self.close()
     }
 
-    pub(crate) fn run_fonts(
-        mut self,
-        ascii: Option<&String>,
-        hi_ansi: Option<&String>,
-        cs: Option<&String>,
-        east_asia: Option<&String>,
-    ) -> Self {
+    // i.e. <w:rFonts ... >
+    // Attributes are written in the given order, absent font slots are left out.
+    pub(crate) fn run_fonts(mut self, attrs: &[(&str, &str)]) -> Self {
         let mut w = XmlEvent::start_element("w:rFonts");
-        if let Some(ascii) = ascii {
-            w = w.attr("w:ascii", ascii);
-        }
-        if let Some(hi_ansi) = hi_ansi {
-            w = w.attr("w:hAnsi", hi_ansi);
-        }
-        if let Some(cs) = cs {
-            w = w.attr("w:cs", cs);
-        }
-        if let Some(east_asia) = east_asia {
-            w = w.attr("w:eastAsia", east_asia);
+        for (k, v) in attrs {
+            w = w.attr(*k, v);
         }
         self.writer.write(w).expect(EXPECT_MESSAGE);
         self.close()
     }
 
+    // i.e. <w:instrText ... >
+    pub(crate) fn instr_text(mut self, text: &str) -> Self {
+        self.writer
+            .write(XmlEvent::start_element("w:instrText").attr("xml:space", "preserve"))
+            .expect(EXPECT_MESSAGE);
+        self.writer.write(text).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
     // i.e. <w:delText ... >
     pub(crate) fn delete_text(mut self, text: &str, preserve_space: bool) -> Self {
         let space = if preserve_space {
@@ -63,13 +59,32 @@ impl XMLBuilder {
     }
     // i.e. <w:r ... >
     open!(open_run, "w:r");
+    // i.e. <w:r w:rsidR=... >
+    pub(crate) fn open_run_with_attrs(mut self, attrs: &[(&str, &str)]) -> Self {
+        let mut e = XmlEvent::start_element("w:r");
+        for (k, v) in attrs {
+            e = e.attr(*k, v);
+        }
+        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self
+    }
     open!(open_run_property, "w:rPr");
     open!(open_run_property_default, "w:rPrDefault");
+    open!(open_paragraph_property_default, "w:pPrDefault");
     // i.e. <w:qFormat ... >
     closed!(q_format, "w:qFormat");
     // i.e. <w:p ... >
     // open!(open_paragraph, "w:p");
     open!(open_paragraph, "w:p", "w14:paraId");
+    // i.e. <w:p w14:paraId=... w:rsidR=... >
+    pub(crate) fn open_paragraph_with_attrs(mut self, id: &str, attrs: &[(&str, &str)]) -> Self {
+        let mut e = XmlEvent::start_element("w:p").attr("w14:paraId", id);
+        for (k, v) in attrs {
+            e = e.attr(*k, v);
+        }
+        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self
+    }
     open!(open_paragraph_property, "w:pPr");
     open!(open_doc_defaults, "w:docDefaults");
     // i.e. <w:outlineLvl ...>
@@ -108,9 +123,20 @@ impl XMLBuilder {
     }
     // i.e. <w:next ... >
     closed_with_str!(next, "w:next");
+    // i.e. <w:uiPriority ... >
+    closed_with_usize!(ui_priority, "w:uiPriority");
+    closed!(semi_hidden, "w:semiHidden");
+    closed!(unhide_when_used, "w:unhideWhenUsed");
 
     // i.e. <w:color ... >
-    closed_with_str!(color, "w:color");
+    pub(crate) fn color(mut self, attrs: &[(String, String)]) -> Self {
+        let mut w = XmlEvent::start_element("w:color");
+        for (k, v) in attrs {
+            w = w.attr(k.as_str(), v);
+        }
+        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.close()
+    }
 
     // i.e. <w:highlight ... >
     closed_with_str!(highlight, "w:highlight");
@@ -161,6 +187,14 @@ impl XMLBuilder {
         self.close()
     }
 
+    // i.e. <w:position ... >
+    pub(crate) fn position(mut self, s: i32) -> Self {
+        self.writer
+            .write(XmlEvent::start_element("w:position").attr("w:val", &format!("{}", s)))
+            .expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
     // i.e. <w:spacing ... >
     pub(crate) fn line_spacing(
         mut self,
@@ -210,6 +244,7 @@ impl XMLBuilder {
     open!(open_table_property, "w:tblPr");
     open!(open_table_grid, "w:tblGrid");
     open!(open_table_row, "w:tr");
+    open!(open_table_property_exceptions, "w:tblPrEx");
     open!(open_table_row_property, "w:trPr");
     open!(open_table_cell, "w:tc");
     open!(open_table_cell_property, "w:tcPr");
@@ -219,6 +254,8 @@ impl XMLBuilder {
 
     closed!(table_layout, "w:tblLayout", "w:type");
     closed_with_str!(table_style, "w:tblStyle");
+    closed_with_str!(table_caption, "w:tblCaption");
+    closed_with_str!(table_description, "w:tblDescription");
     closed_w_with_type_el!(table_width, "w:tblW");
     closed_w_with_type_el!(table_indent, "w:tblInd");
     closed_w_with_type_el!(grid_column, "w:gridCol");
@@ -229,6 +266,8 @@ impl XMLBuilder {
     closed_with_usize!(grid_span, "w:gridSpan");
     closed_with_str!(vertical_merge, "w:vMerge");
     closed_with_str!(vertical_align, "w:vAlign");
+    closed!(no_wrap, "w:noWrap");
+    closed!(table_cell_fit_text, "w:tcFitText");
 
     closed_w_with_type_el!(margin_top, "w:top");
     closed_w_with_type_el!(margin_left, "w:left");
@@ -246,12 +285,24 @@ impl XMLBuilder {
 
     closed_border_el!(text_border, "w:bdr");
 
-    closed!(shd, "w:shd", "w:val", "w:color", "w:fill");
+    // i.e. <w:shd ... >
+    pub(crate) fn shd(mut self, attrs: &[(String, String)]) -> Self {
+        let mut w = XmlEvent::start_element("w:shd");
+        for (k, v) in attrs {
+            w = w.attr(k.as_str(), v);
+        }
+        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.close()
+    }
 
     closed!(tab, "w:tab");
+    closed!(last_rendered_page_break, "w:lastRenderedPageBreak");
     closed!(tab_with_pos, "w:tab", "w:val", "w:pos");
 
     closed!(br, "w:br", "w:type");
+    closed!(br_with_clear, "w:br", "w:type", "w:clear");
+    closed!(field_character, "w:fldChar", "w:fldCharType");
+    closed!(field_character_with_dirty, "w:fldChar", "w:fldCharType", "w:dirty");
     closed!(zoom, "w:zoom", "w:percent");
     closed_with_usize!(default_tab_stop, "w:defaultTabStop");
 
@@ -262,6 +313,8 @@ impl XMLBuilder {
 
     open!(open_section_property, "w:sectPr");
     closed!(header_reference, "w:headerReference", "w:type", "r:id");
+    closed!(alt_chunk, "w:altChunk", "r:id");
+    closed!(footer_reference, "w:footerReference", "w:type", "r:id");
 
     closed_with_str!(type_tag, "w:type");
     closed!(page_size, "w:pgSz", "w:w", "w:h");
@@ -278,10 +331,23 @@ impl XMLBuilder {
         "w:gutter"
     );
     closed!(columns, "w:cols", "w:space");
+    closed!(columns_with_num, "w:cols", "w:space", "w:num");
+    closed!(title_pg, "w:titlePg");
+    closed!(contextual_spacing, "w:contextualSpacing");
+    closed_with_str!(contextual_spacing_with_val, "w:contextualSpacing");
     closed!(document_grid, "w:docGrid", "w:type", "w:linePitch");
 
     open!(open_insert, "w:ins", "w:id", "w:author", "w:date");
     open!(open_delete, "w:del", "w:id", "w:author", "w:date");
+    open!(open_move_from, "w:moveFrom", "w:id", "w:author", "w:date");
+    open!(open_move_to, "w:moveTo", "w:id", "w:author", "w:date");
+    open!(
+        open_run_property_change,
+        "w:rPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
 
     closed!(bookmark_start, "w:bookmarkStart", "w:id", "w:name");
     closed!(bookmark_end, "w:bookmarkEnd", "w:id");
@@ -299,8 +365,12 @@ impl XMLBuilder {
     );
 
     open!(open_abstract_num, "w:abstractNum", "w:abstractNumId");
+    closed_with_str!(style_link, "w:styleLink");
+    closed_with_str!(num_style_link, "w:numStyleLink");
     open!(open_level, "w:lvl", "w:ilvl");
     open!(open_tabs, "w:tabs");
+    closed!(tab_stop, "w:tab", "w:val", "w:pos");
+    closed!(tab_stop_with_leader, "w:tab", "w:val", "w:leader", "w:pos");
     open!(open_num, "w:num", "w:numId");
     open!(open_numbering_property, "w:numPr");
     closed_with_usize!(indent_level, "w:ilvl");
@@ -312,15 +382,35 @@ impl XMLBuilder {
     closed_with_str!(level_justification, "w:lvlJc");
     closed_with_str!(abstract_num_id, "w:abstractNumId");
     closed!(vanish, "w:vanish");
+    closed_with_str!(vanish_with_val, "w:vanish");
+    closed!(spec_vanish, "w:specVanish");
+    closed_with_str!(spec_vanish_with_val, "w:specVanish");
 
     open!(open_drawing, "w:drawing");
-    open!(open_anchor, "wp:anchor");
+    open!(open_anchor, "wp:anchor", "distT", "distB", "distL", "distR");
     open!(open_graphic, "a:graphic", "xmlns:a");
     open!(open_graphic_data, "a:graphicData", "uri");
 
+    // picture bullet
+    open!(
+        open_numbering_picture_bullet,
+        "w:numPicBullet",
+        "w:numPicBulletId"
+    );
+    open!(open_pict, "w:pict");
+    open!(open_vml_shape, "v:shape", "id", "type", "style", "o:bullet");
+    closed!(vml_image_data, "v:imagedata", "r:id", "o:title");
+    closed_with_usize!(level_picture_bullet_id, "w:lvlPicBulletId");
+
     // shape
     open!(open_wp_shape, "wps:wsp");
     open!(open_wp_text_box, "wps:txbx");
+    open!(open_wp_text_box_with_id, "wps:txbx", "id");
+    closed!(wps_linked_text_box, "wps:linkedTxbx", "id", "seq");
+    closed!(wps_c_nv_sp_pr, "wps:cNvSpPr", "txBox");
+    closed!(wps_shape_c_nv_sp_pr, "wps:cNvSpPr");
+    open!(open_wps_sp_pr, "wps:spPr");
+    closed!(wps_body_pr, "wps:bodyPr", "wrap", "anchor");
     open!(open_text_box_content, "w:txbxContent");
 
     // compat
@@ -335,6 +425,7 @@ impl XMLBuilder {
     closed!(do_not_expand_shift_return, "w:doNotExpandShiftReturn");
     closed!(adjust_line_height_table, "w:adjustLineHeightInTable");
     closed!(use_fe_layout, "w:useFELayout");
+    closed!(auto_space_like_word95, "w:autoSpaceLikeWord95");
     closed!(
         compat_setting,
         "w:compatSetting",
@@ -343,6 +434,16 @@ impl XMLBuilder {
         "w:val"
     );
 
+    // webSettings
+    closed!(optimize_for_browser, "w:optimizeForBrowser");
+    open!(open_divs, "w:divs");
+    open!(open_div, "w:div", "w:id");
+    closed_with_usize!(div_margin_left, "w:marLeft");
+    closed_with_usize!(div_margin_right, "w:marRight");
+    closed_with_usize!(div_margin_top, "w:marTop");
+    closed_with_usize!(div_margin_bottom, "w:marBottom");
+    open!(open_divs_child, "w:divsChild");
+
     closed!(keep_next, "w:keepNext");
     closed!(keep_lines, "w:keepLines");
     closed!(page_break_before, "w:pageBreakBefore");
@@ -361,6 +462,44 @@ impl XMLBuilder {
     open!(open_doc_vars, "w:docVars");
     closed!(doc_var, "w:docVar", "w:name", "w:val");
 
+    closed!(track_revisions, "w:trackRevisions");
+    closed!(
+        document_protection,
+        "w:documentProtection",
+        "w:edit",
+        "w:enforcement"
+    );
+    closed!(auto_hyphenation, "w:autoHyphenation");
+
+    // i.e. <w:themeFontLang ... >
+    pub(crate) fn theme_font_lang(mut self, attrs: &[(&str, &str)]) -> Self {
+        let mut w = XmlEvent::start_element("w:themeFontLang");
+        for (k, v) in attrs {
+            w = w.attr(*k, v);
+        }
+        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
+    // i.e. <w:lang w:val="en-GB" ... >
+    pub(crate) fn lang(mut self, attrs: &[(&str, &str)]) -> Self {
+        let mut w = XmlEvent::start_element("w:lang");
+        for (k, v) in attrs {
+            w = w.attr(*k, v);
+        }
+        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.close()
+    }
+
+    open!(open_footnote_property, "w:footnotePr");
+    open!(open_endnote_property, "w:endnotePr");
+    closed_with_str!(note_position, "w:pos");
+    closed_with_usize!(note_number_start, "w:numStart");
+    closed_with_str!(note_number_restart, "w:numRestart");
+
+    open!(open_rsids, "w:rsids");
+    closed_with_str!(rsid, "w:rsid");
+
     // webextension
     open!(open_webextension, "we:webextension", "xmlns:we", "id");
     closed!(
@@ -495,7 +634,9 @@ mod tests {
     #[test]
     fn test_color() {
         let b = XMLBuilder::new();
-        let r = b.color("2E74B5").build();
+        let r = b
+            .color(&[("w:val".to_owned(), "2E74B5".to_owned())])
+            .build();
         assert_eq!(str::from_utf8(&r).unwrap(), r#"<w:color w:val="2E74B5" />"#);
     }
 
@@ -2,10 +2,18 @@ use super::XMLBuilder;
 use super::XmlEvent;
 
 impl XMLBuilder {
-    pub(crate) fn open_header(mut self) -> Self {
+    pub(crate) fn open_header(self) -> Self {
+        self.open_header_footer("w:hdr")
+    }
+
+    pub(crate) fn open_footer(self) -> Self {
+        self.open_header_footer("w:ftr")
+    }
+
+    fn open_header_footer(mut self, name: &str) -> Self {
         self.writer
             .write(
-                XmlEvent::start_element("w:hdr")
+                XmlEvent::start_element(name)
                     .attr(
                         "xmlns:r",
                         "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
@@ -25,4 +25,7 @@ impl XMLBuilder {
     closed!(a_ext, "a:ext", "cx", "cy");
     open!(open_a_prst_geom, "a:prstGeom", "prst");
     closed!(a_av_lst, "a:avLst");
+    open!(open_a_solid_fill, "a:solidFill");
+    closed!(a_srgb_clr, "a:srgbClr", "val");
+    open!(open_a_ln, "a:ln", "w");
 }
@@ -22,4 +22,25 @@ impl XMLBuilder {
             .expect("should write to buf");
         self
     }
+
+    pub(crate) fn open_web_settings(mut self) -> Self {
+        self.writer
+            .write(
+                XmlEvent::start_element("w:webSettings")
+                    .attr(
+                        "xmlns:w",
+                        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+                    )
+                    .attr(
+                        "xmlns:w14",
+                        "http://schemas.microsoft.com/office/word/2010/wordml",
+                    )
+                    .attr(
+                        "xmlns:w15",
+                        "http://schemas.microsoft.com/office/word/2012/wordml",
+                    ),
+            )
+            .expect("should write to buf");
+        self
+    }
 }
@@ -1,8 +1,11 @@
-use crate::XMLDocx;
+use crate::{BuildOptions, CompressionLevel, XMLDocx, XmlDeclStyle};
+
+use std::borrow::Cow;
 
 use std::io::prelude::*;
 use std::io::Seek;
 use zip::write::FileOptions;
+use zip::DateTime;
 
 pub fn zip<W>(w: W, xml: XMLDocx) -> zip::result::ZipResult<()>
 where
@@ -10,46 +13,87 @@ where
 {
     let mut zip = zip::ZipWriter::new(w);
 
-    zip.add_directory("word/", Default::default())?;
-    zip.add_directory("word/_rels", Default::default())?;
-    zip.add_directory("_rels/", Default::default())?;
-    zip.add_directory("docProps/", Default::default())?;
-
-    let options = FileOptions::default()
-        .compression_method(zip::CompressionMethod::Stored)
+    let method = match xml.options.compression {
+        CompressionLevel::Stored => zip::CompressionMethod::Stored,
+        CompressionLevel::Deflated => zip::CompressionMethod::Deflated,
+    };
+    let mut dir_options = FileOptions::default();
+    let mut options = FileOptions::default()
+        .compression_method(method)
         .unix_permissions(0o755);
+    if xml.options.deterministic {
+        dir_options = dir_options.last_modified_time(DateTime::default());
+        options = options.last_modified_time(DateTime::default());
+    }
+
+    zip.add_directory("word/", dir_options)?;
+    zip.add_directory("word/_rels", dir_options)?;
+    zip.add_directory("_rels/", dir_options)?;
+    zip.add_directory("docProps/", dir_options)?;
 
     zip.start_file("[Content_Types].xml", options)?;
-    zip.write_all(&xml.content_type)?;
+    zip.write_all(&xml_part(&xml.content_type, &xml.options))?;
     zip.start_file("_rels/.rels", options)?;
-    zip.write_all(&xml.rels)?;
+    zip.write_all(&xml_part(&xml.rels, &xml.options))?;
     zip.start_file("docProps/app.xml", options)?;
-    zip.write_all(&xml.doc_props.app)?;
+    zip.write_all(&xml_part(&xml.doc_props.app, &xml.options))?;
     zip.start_file("docProps/core.xml", options)?;
-    zip.write_all(&xml.doc_props.core)?;
+    zip.write_all(&xml_part(&xml.doc_props.core, &xml.options))?;
     zip.start_file("docProps/custom.xml", options)?;
-    zip.write_all(&xml.doc_props.custom)?;
+    zip.write_all(&xml_part(&xml.doc_props.custom, &xml.options))?;
     zip.start_file("word/_rels/document.xml.rels", options)?;
-    zip.write_all(&xml.document_rels)?;
+    zip.write_all(&xml_part(&xml.document_rels, &xml.options))?;
     zip.start_file("word/document.xml", options)?;
-    zip.write_all(&xml.document)?;
+    zip.write_all(&xml_part(&xml.document, &xml.options))?;
     zip.start_file("word/styles.xml", options)?;
-    zip.write_all(&xml.styles)?;
+    zip.write_all(&xml_part(&xml.styles, &xml.options))?;
     zip.start_file("word/settings.xml", options)?;
-    zip.write_all(&xml.settings)?;
+    zip.write_all(&xml_part(&xml.settings, &xml.options))?;
+    if let Some(web_settings) = xml.web_settings {
+        zip.start_file("word/webSettings.xml", options)?;
+        zip.write_all(&xml_part(&web_settings, &xml.options))?;
+    }
     zip.start_file("word/fontTable.xml", options)?;
-    zip.write_all(&xml.font_table)?;
+    zip.write_all(&xml_part(&xml.font_table, &xml.options))?;
     zip.start_file("word/comments.xml", options)?;
-    zip.write_all(&xml.comments)?;
+    zip.write_all(&xml_part(&xml.comments, &xml.options))?;
     zip.start_file("word/numbering.xml", options)?;
-    zip.write_all(&xml.numberings)?;
+    zip.write_all(&xml_part(&xml.numberings, &xml.options))?;
+    if let Some(numbering_rels) = xml.numbering_rels {
+        zip.start_file("word/_rels/numbering.xml.rels", options)?;
+        zip.write_all(&xml_part(&numbering_rels, &xml.options))?;
+    }
     zip.start_file("word/header1.xml", options)?;
-    zip.write_all(&xml.header)?;
+    zip.write_all(&xml_part(&xml.header, &xml.options))?;
+    if let Some(footer) = xml.footer {
+        zip.start_file("word/footer1.xml", options)?;
+        zip.write_all(&xml_part(&footer, &xml.options))?;
+    }
+    for (id, header) in xml.section_headers {
+        zip.start_file(format!("word/header{}.xml", id), options)?;
+        zip.write_all(&xml_part(&header, &xml.options))?;
+    }
+    for (id, footer) in xml.section_footers {
+        zip.start_file(format!("word/footer{}.xml", id), options)?;
+        zip.write_all(&xml_part(&footer, &xml.options))?;
+    }
+    for (part_name, data) in xml.alt_chunks {
+        zip.start_file(format!("word/{}", part_name), options)?;
+        zip.write_all(&data)?;
+    }
+    if let Some(vba_project) = xml.vba_project {
+        zip.start_file("word/vbaProject.bin", options)?;
+        zip.write_all(&vba_project)?;
+    }
+    if let Some(theme) = xml.theme {
+        zip.start_file("word/theme/theme1.xml", options)?;
+        zip.write_all(&xml_part(&theme, &xml.options))?;
+    }
     zip.start_file("word/commentsExtended.xml", options)?;
-    zip.write_all(&xml.comments_extended)?;
+    zip.write_all(&xml_part(&xml.comments_extended, &xml.options))?;
 
     if !xml.media.is_empty() {
-        zip.add_directory("word/media/", Default::default())?;
+        zip.add_directory("word/media/", dir_options)?;
         for m in xml.media {
             zip.start_file(format!("word/media/image{}.jpg", m.0), options)?;
             zip.write_all(&m.1)?;
@@ -58,37 +102,105 @@ where
 
     // For now support only taskpanes
     if let Some(taskpanes) = xml.taskpanes {
-        zip.add_directory("word/webextensions/", Default::default())?;
+        zip.add_directory("word/webextensions/", dir_options)?;
         zip.start_file("word/webextensions/taskpanes.xml", options)?;
-        zip.write_all(&taskpanes)?;
+        zip.write_all(&xml_part(&taskpanes, &xml.options))?;
 
-        zip.add_directory("word/webextensions/_rels", Default::default())?;
+        zip.add_directory("word/webextensions/_rels", dir_options)?;
         zip.start_file("word/webextensions/_rels/taskpanes.xml.rels", options)?;
-        zip.write_all(&xml.taskpanes_rels)?;
+        zip.write_all(&xml_part(&xml.taskpanes_rels, &xml.options))?;
 
         for (i, ext) in xml.web_extensions.iter().enumerate() {
             zip.start_file(
                 format!("word/webextensions/webextension{}.xml", i + 1),
                 options,
             )?;
-            zip.write_all(ext)?;
+            zip.write_all(&xml_part(ext, &xml.options))?;
         }
     }
 
     if !xml.custom_items.is_empty() {
-        zip.add_directory("customXml/_rels", Default::default())?;
+        zip.add_directory("customXml/_rels", dir_options)?;
     }
 
     for (i, item) in xml.custom_items.into_iter().enumerate() {
         let n = i + 1;
         zip.start_file(format!("customXml/_rels/item{}.xml.rels", n), options)?;
-        zip.write_all(&xml.custom_item_rels[i])?;
+        zip.write_all(&xml_part(&xml.custom_item_rels[i], &xml.options))?;
         zip.start_file(format!("customXml/item{}.xml", n), options)?;
-        zip.write_all(&item)?;
+        zip.write_all(&xml_part(&item, &xml.options))?;
         zip.start_file(format!("customXml/itemProps{}.xml", n), options)?;
-        zip.write_all(&xml.custom_item_props[i])?;
+        zip.write_all(&xml_part(&xml.custom_item_props[i], &xml.options))?;
     }
 
     zip.finish()?;
     Ok(())
 }
+
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+const WORD_DECLARATION: &[u8] =
+    b"<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n";
+
+// Replaces the declaration of an XML part and prepends the BOM as configured.
+fn xml_part<'a>(data: &'a [u8], options: &BuildOptions) -> Cow<'a, [u8]> {
+    if options.xml_declaration == XmlDeclStyle::Default && !options.bom {
+        return Cow::Borrowed(data);
+    }
+    // Parts kept as read, i.e. the theme, may come with a BOM of their own.
+    let data = data.strip_prefix(BOM).unwrap_or(data);
+    let mut part = vec![];
+    if options.bom {
+        part.extend_from_slice(BOM);
+    }
+    match options.xml_declaration {
+        XmlDeclStyle::Default => part.extend_from_slice(data),
+        XmlDeclStyle::Word => {
+            part.extend_from_slice(WORD_DECLARATION);
+            part.extend_from_slice(strip_declaration(data));
+        }
+    }
+    Cow::Owned(part)
+}
+
+fn strip_declaration(data: &[u8]) -> &[u8] {
+    if !data.starts_with(b"<?xml") {
+        return data;
+    }
+    match data.windows(2).position(|w| w == b"?>") {
+        Some(end) => {
+            let rest = &data[end + 2..];
+            let start = rest
+                .iter()
+                .position(|b| !b.is_ascii_whitespace())
+                .unwrap_or(rest.len());
+            &rest[start..]
+        }
+        None => data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_xml_part() {
+        let data = b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<w:document />";
+        let options = BuildOptions::new().xml_declaration(XmlDeclStyle::Word);
+        assert_eq!(
+            str::from_utf8(&xml_part(data, &options)).unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n<w:document />"
+        );
+        let part = xml_part(data, &BuildOptions::new().bom(true));
+        assert_eq!(&part[..3], BOM);
+        assert_eq!(&part[3..], &data[..]);
+        assert_eq!(
+            xml_part(data, &BuildOptions::new()),
+            Cow::Borrowed(&data[..])
+        );
+    }
+}
@@ -23,21 +23,21 @@ pub fn indent() -> Result<(), DocxError> {
     let file = std::fs::File::create(&path).unwrap();
     Docx::new()
         .add_paragraph(Paragraph::new().add_run(Run::new().add_text(DUMMY)).indent(
-            Some(840),
+            Some(Twip(840)),
             None,
             None,
             None,
         ))
         .add_paragraph(Paragraph::new())
         .add_paragraph(Paragraph::new().add_run(Run::new().add_text(DUMMY)).indent(
-            Some(840),
+            Some(Twip(840)),
             Some(SpecialIndentType::FirstLine(720)),
             None,
             None,
         ))
         .add_paragraph(Paragraph::new())
         .add_paragraph(Paragraph::new().add_run(Run::new().add_text(DUMMY)).indent(
-            Some(1560),
+            Some(Twip(1560)),
             Some(SpecialIndentType::Hanging(720)),
             None,
             None,
@@ -359,7 +359,7 @@ pub fn user_numbering() -> Result<(), DocxError> {
                     LevelJc::new("left"),
                 )
                 .indent(
-                    Some(1620),
+                    Some(Twip(1620)),
                     Some(SpecialIndentType::Hanging(320)),
                     None,
                     None,
@@ -424,7 +424,12 @@ pub fn line_spacing() -> Result<(), DocxError> {
         .add_paragraph(
             Paragraph::new()
                 .add_run(Run::new().add_text(DUMMY))
-                .line_spacing(Some(300), None, Some(300), Some(LineSpacingType::Auto)),
+                .line_spacing(
+                    Some(Twip(300)),
+                    None,
+                    Some(300),
+                    Some(LineSpacingType::Auto),
+                ),
         )
         .add_paragraph(
             Paragraph::new()
@@ -434,9 +439,1012 @@ pub fn line_spacing() -> Result<(), DocxError> {
         .add_paragraph(
             Paragraph::new()
                 .add_run(Run::new().add_text(DUMMY).character_spacing(100))
-                .line_spacing(None, Some(300), Some(300), Some(LineSpacingType::Exact)),
+                .line_spacing(
+                    None,
+                    Some(Twip(300)),
+                    Some(300),
+                    Some(LineSpacingType::Exact),
+                ),
         )
         .build()
         .pack(file)?;
     Ok(())
 }
+
+#[test]
+pub fn deterministic_build() -> Result<(), DocxError> {
+    let pack = || -> Result<Vec<u8>, DocxError> {
+        let mut buf = std::io::Cursor::new(vec![]);
+        Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_insert(Insert::new(Run::new().add_text("Hello")).author("bokuweb")),
+            )
+            .add_paragraph(
+                Paragraph::new().add_text_box(
+                    TextBox::new(200, 100)
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Box"))),
+                ),
+            )
+            .build_with(
+                BuildOptions::new()
+                    .compression(CompressionLevel::Deflated)
+                    .deterministic(true),
+            )
+            .pack(&mut buf)?;
+        Ok(buf.into_inner())
+    };
+    assert_eq!(pack()?, pack()?);
+
+    // Ids which are set are kept, and the document itself is not renumbered.
+    let mut docx = Docx::new()
+        .add_paragraph(Paragraph::new().id("0ABCDEF0"))
+        .add_paragraph(Paragraph::new());
+    let ids = |docx: &Docx| -> Vec<String> {
+        docx.document_children()
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(p.id.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+    let before = ids(&docx);
+    let xml = docx.build_with(BuildOptions::new().deterministic(true));
+    let document = std::str::from_utf8(&xml.document).unwrap();
+    assert!(document.contains(r#"w14:paraId="0ABCDEF0""#));
+    assert!(document.contains(r#"w14:paraId="00000001""#));
+    assert_eq!(ids(&docx), before);
+    Ok(())
+}
+
+#[test]
+pub fn word_xml_declaration() -> Result<(), DocxError> {
+    use std::io::Read;
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+        .footer(Footer::new().add_paragraph(Paragraph::new()))
+        .add_theme(THEME.as_bytes().to_vec())
+        .add_custom_item("06AC5857-5C65-A94A-BCEC-37356A209BC3", "<root />")
+        .build_with(
+            BuildOptions::new()
+                .xml_declaration(XmlDeclStyle::Word)
+                .bom(true),
+        )
+        .pack(&mut buf)?;
+    let buf = buf.into_inner();
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(&buf[..])).unwrap();
+    let mut parts = 0;
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let name = file.name().to_owned();
+        if !name.ends_with(".xml") && !name.ends_with(".rels") {
+            continue;
+        }
+        let mut data = vec![];
+        file.read_to_end(&mut data).unwrap();
+        assert!(
+            data.starts_with(
+                b"\xEF\xBB\xBF<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n<"
+            ),
+            "{}",
+            name
+        );
+        parts += 1;
+    }
+    assert!(parts > 15);
+    let docx = read_docx(&buf).unwrap();
+    assert_eq!(docx.document_children().len(), 1);
+    Ok(())
+}
+
+#[test]
+pub fn section_header_link() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/section_header_link.docx");
+    let file = std::fs::File::create(&path).unwrap();
+    let mut docx = Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("First")))
+        .add_section(Section::new().header(
+            HeaderFooterReferenceType::Default,
+            Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Own"))),
+        ))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Second")))
+        .add_section(Section::new().link_to_previous(HeaderFooterReferenceType::Default, true))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Third")));
+    let xml = docx.build();
+    let document = std::str::from_utf8(&xml.document).unwrap();
+    assert_eq!(document.matches("r:id=\"rIdHeader2\"").count(), 1);
+    assert_eq!(document.matches("w:headerReference").count(), 2);
+    assert_eq!(xml.section_headers.len(), 1);
+    xml.pack(file)?;
+    Ok(())
+}
+
+#[test]
+pub fn section_footer_link() -> Result<(), DocxError> {
+    let mut docx = Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("First")))
+        .add_section(Section::new().footer(
+            HeaderFooterReferenceType::First,
+            Footer::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Own"))),
+        ))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Second")))
+        .add_section(Section::new().link_to_previous(HeaderFooterReferenceType::First, true))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Third")))
+        .add_section(
+            Section::new().link_footer_to_previous(HeaderFooterReferenceType::Default, false),
+        );
+    let xml = docx.build();
+    let document = std::str::from_utf8(&xml.document).unwrap();
+    assert_eq!(
+        document
+            .matches(r#"<w:footerReference w:type="first" r:id="rIdFooter2" />"#)
+            .count(),
+        1
+    );
+    assert_eq!(
+        document
+            .matches(r#"<w:footerReference w:type="default" r:id="rIdFooter3" />"#)
+            .count(),
+        1
+    );
+    // Only the section with the first page footer shows it.
+    assert_eq!(document.matches("<w:titlePg />").count(), 1);
+    assert_eq!(xml.section_headers.len(), 0);
+    assert_eq!(xml.section_footers.len(), 2);
+    Ok(())
+}
+
+#[test]
+pub fn no_first_page_header() -> Result<(), DocxError> {
+    let mut docx = Docx::new()
+        .header(Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Head"))))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Cover")))
+        .add_section(Section::new().no_first_page_header())
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Body")));
+    let xml = docx.build();
+    let document = std::str::from_utf8(&xml.document).unwrap();
+    assert!(document.contains(r#"<w:headerReference w:type="first" r:id="rIdHeader2" />"#));
+    assert!(document.contains(r#"<w:footerReference w:type="first" r:id="rIdFooter2" />"#));
+    assert!(document.contains("<w:titlePg />"));
+    assert_eq!(xml.section_headers.len(), 1);
+    assert_eq!(xml.section_footers.len(), 1);
+    let rels = std::str::from_utf8(&xml.document_rels).unwrap();
+    assert!(rels.contains(r#"Id="rIdHeader2""#));
+    assert!(rels.contains(r#"Id="rIdFooter2""#));
+
+    let mut buf = std::io::Cursor::new(vec![]);
+    xml.pack(&mut buf)?;
+    let mut docx = read_docx(&buf.into_inner()).unwrap();
+    let blank_first_page = |docx: &Docx| {
+        docx.document_children().iter().any(|c| match c {
+            DocumentChild::Paragraph(p) => p
+                .property
+                .section_property
+                .as_ref()
+                .map(|s| s.has_title_pg() && s.has_blank_first_page())
+                .unwrap_or_default(),
+            _ => false,
+        })
+    };
+    assert!(blank_first_page(&docx));
+    assert!(!docx.document.section_property.has_blank_first_page());
+
+    // Writing the read document keeps the blank first page.
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert!(blank_first_page(&docx));
+    Ok(())
+}
+
+#[test]
+pub fn doc_variables() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .set_variable("Client", "ACME")
+        .set_variable("Client", "Initech")
+        .set_variable("Query", r#"a < b && c = "d""#)
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_doc_variable_field("Client")))
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(docx.get_variable("Client"), Some("Initech"));
+    assert_eq!(docx.get_variable("Query"), Some(r#"a < b && c = "d""#));
+    assert_eq!(docx.variables().len(), 2);
+    Ok(())
+}
+
+#[test]
+pub fn rsids() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .rsids(Rsids::new().r("00A1").r_default("00A1"))
+                .add_run(
+                    Run::new()
+                        .rsids(Rsids::new().r_pr("00B2"))
+                        .add_text("Hello"),
+                ),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    match &docx.document.children[0] {
+        DocumentChild::Paragraph(p) => {
+            assert_eq!(p.property.rsids, Rsids::new().r("00A1").r_default("00A1"));
+            assert_eq!(
+                p.children[0],
+                ParagraphChild::Run(Box::new(
+                    Run::new()
+                        .rsids(Rsids::new().r_pr("00B2"))
+                        .add_text("Hello")
+                ))
+            );
+        }
+        _ => unreachable!(),
+    }
+    let json: serde_json::Value = serde_json::from_str(&docx.json()).unwrap();
+    assert_eq!(
+        json["settings"]["rsids"],
+        serde_json::json!(["00A1", "00B2"])
+    );
+    Ok(())
+}
+
+#[test]
+pub fn index_entries() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Ownership rules"))
+                .add_run(Run::new().add_index_entry(
+                    "Rust",
+                    IndexEntryOptions::new().sub_entry("Ownership").bold(),
+                )),
+        )
+        .add_index(IndexOptions::new().columns(2))
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(docx.index_entries(), vec!["Rust:Ownership".to_owned()]);
+    Ok(())
+}
+
+#[test]
+pub fn figure_captions() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_table_of_figures("Figure")
+        .add_paragraph(Paragraph::new().add_caption("Figure", SeqField::new()))
+        .add_paragraph(Paragraph::new().add_caption("Table", SeqField::new().format("ROMAN")))
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(
+        docx.seq_identifiers(),
+        vec!["Figure".to_owned(), "Table".to_owned()]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn date_fields() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_date_field("MMMM d, yyyy", true))
+                .add_run(Run::new().add_text(" "))
+                .add_run(Run::new().add_time_field("HH:mm", false)),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(
+        docx.date_field_formats(),
+        vec!["MMMM d, yyyy".to_owned(), "HH:mm".to_owned()]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn external_relationships() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    let mut docx = Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Links")))
+        .add_relationship(Relationship::hyperlink("rIdLink1", "https://example.com"))
+        .add_relationship(Relationship::hyperlink("rIdLink2", "../specs/spec.docx"))
+        .add_relationship(Relationship::hyperlink(
+            "rIdLink3",
+            r#"https://x.test/?a=1&b=2&q="<tag>""#,
+        ));
+    assert_eq!(docx.relationships().len(), 3);
+    docx.build().pack(&mut buf)?;
+    let mut docx = read_docx(&buf.into_inner()).unwrap();
+    let rels = docx.relationships();
+    assert_eq!(
+        rels.get("rIdLink1").map(|r| r.target_mode),
+        Some(TargetMode::External)
+    );
+    assert_eq!(
+        rels.get("rIdLink2"),
+        Some(&Relationship::hyperlink("rIdLink2", "../specs/spec.docx"))
+    );
+    assert_eq!(
+        rels.get("rIdLink3").map(|r| r.target.as_str()),
+        Some(r#"https://x.test/?a=1&b=2&q="<tag>""#)
+    );
+
+    // Read relationships are written again, while the parts keep a single relationship each.
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(docx.relationships().external().count(), 3);
+    assert_eq!(
+        docx.relationships()
+            .iter()
+            .filter(|r| r.target == "styles.xml")
+            .count(),
+        1
+    );
+    Ok(())
+}
+
+const THEME: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Corporate"><a:themeElements /></a:theme>"#;
+
+#[test]
+pub fn docx_template() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .styles(Styles::new().add_style(Style::new("Title", StyleType::Paragraph).bold()))
+        .add_abstract_numbering(AbstractNumbering::new(2).add_level(Level::new(
+            0,
+            Start::new(1),
+            NumberFormat::new("decimal"),
+            LevelText::new("%1."),
+            LevelJc::new("left"),
+        )))
+        .add_numbering(Numbering::new(2, 2))
+        .doc_id("6F3B7A2C-1D4E-4F5A-9B8C-7D6E5F4A3B2C")
+        .add_theme(THEME.as_bytes().to_vec())
+        .add_paragraph(
+            Paragraph::new()
+                .rsids(Rsids::new().r("00A1").r_default("00A1"))
+                .add_run(Run::new().add_text("Template body")),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let template = DocxTemplate::read(&buf.into_inner()).unwrap();
+
+    for name in &["Alice", "Bob"] {
+        let mut buf = std::io::Cursor::new(vec![]);
+        template
+            .new_document()
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Title")
+                    .add_run(Run::new().add_text(*name)),
+            )
+            .build()
+            .pack(&mut buf)?;
+        let docx = read_docx(&buf.into_inner()).unwrap();
+        assert_eq!(docx.document_children().len(), 1);
+        assert!(docx.styles.find_style_by_id("Title").is_some());
+        assert_eq!(&docx.numberings, template.get_numberings());
+        assert_eq!(docx.theme(), Some(THEME.as_bytes()));
+        assert_eq!(docx.get_settings().get_doc_id(), None);
+        assert!(docx.get_settings().get_rsids().is_empty());
+    }
+    Ok(())
+}
+
+#[test]
+pub fn default_language() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .default_language(LangSettings::new().val("en-GB").east_asia("ja-JP"))
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Colour"))
+                .add_run(
+                    Run::new()
+                        .lang(LangSettings::new().val("fr-FR"))
+                        .add_text(" couleur"),
+                ),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let default = LangSettings::new().val("en-GB").east_asia("ja-JP");
+    assert_eq!(
+        docx.styles.get_doc_defaults().get_run_property().lang,
+        Some(default.clone())
+    );
+    assert_eq!(docx.get_settings().get_theme_font_lang(), Some(&default));
+    let p = match &docx.document_children()[0] {
+        DocumentChild::Paragraph(p) => p,
+        _ => unreachable!(),
+    };
+    let langs: Vec<_> = p
+        .children
+        .iter()
+        .map(|c| match c {
+            ParagraphChild::Run(r) => r.run_property.lang.clone(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(langs, vec![None, Some(LangSettings::new().val("fr-FR"))]);
+    Ok(())
+}
+
+#[test]
+pub fn text_box() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Body"))
+                .add_text_box(
+                    TextBox::new(200, 100)
+                        .offset_x(100)
+                        .offset_y(100)
+                        .fill("FFF2CC")
+                        .border("BF9000", 1)
+                        .chain_id(1)
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Pull quote"))),
+                ),
+        )
+        .add_paragraph(
+            Paragraph::new().add_text_box(TextBox::new(200, 50).offset_y(300).link(1, 1)),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let text_boxes: Vec<TextBox> = docx
+        .document
+        .children
+        .iter()
+        .flat_map(|c| match c {
+            DocumentChild::Paragraph(p) => p.children.clone(),
+            _ => vec![],
+        })
+        .filter_map(|c| match c {
+            ParagraphChild::Run(r) => match &r.children[..] {
+                [RunChild::Drawing(d)] => match &d.data {
+                    Some(DrawingData::TextBox(t)) => Some(t.clone()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text_boxes.len(), 2);
+    let (quote, linked) = (&text_boxes[0], &text_boxes[1]);
+    assert_eq!(quote.size, (200, 100));
+    assert_eq!(quote.position_h, DrawingPosition::Offset(100));
+    assert_eq!(quote.position_v, DrawingPosition::Offset(100));
+    assert_eq!(quote.fill, Some("FFF2CC".to_owned()));
+    assert_eq!(quote.border, Some(("BF9000".to_owned(), 1)));
+    assert_eq!(quote.chain_id, Some(1));
+    assert_eq!(quote.link, None);
+    match &quote.content.children[..] {
+        [TextBoxContentChild::Paragraph(p)] => match &p.children[0] {
+            ParagraphChild::Run(r) => {
+                assert_eq!(r.children, vec![RunChild::Text(Text::new("Pull quote"))])
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+    assert_eq!(linked.size, (200, 50));
+    assert_eq!(linked.position_v, DrawingPosition::Offset(300));
+    assert_eq!(linked.link, Some((1, 1)));
+    assert!(linked.content.children.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn shapes() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new().add_run(
+                Run::new().add_shape(
+                    Shape::new(ShapeType::Ellipse, 120, 60)
+                        .fill("4472C4")
+                        .line("2F528F", 1)
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Start"))),
+                ),
+            ),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let shape = match &docx.document.children[0] {
+        DocumentChild::Paragraph(p) => match &p.children[0] {
+            ParagraphChild::Run(r) => match &r.children[0] {
+                RunChild::Drawing(d) => match &d.data {
+                    Some(DrawingData::Shape(s)) => s.clone(),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(shape.geometry, ShapeType::Ellipse);
+    assert_eq!(shape.size, (120, 60));
+    assert_eq!(shape.fill, Some("4472C4".to_owned()));
+    assert_eq!(shape.line, Some(("2F528F".to_owned(), 1)));
+    assert!(docx.json().contains("Start"));
+    Ok(())
+}
+
+#[test]
+pub fn floating_pic_wrap_distance() -> Result<(), DocxError> {
+    use std::io::Read;
+
+    let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+    let mut image = vec![];
+    img.read_to_end(&mut image).unwrap();
+    let pic = Pic::new(image).floating().wrap_distance(
+        Emu::from_mm(2.0),
+        Emu::from_mm(2.0),
+        Emu::from_mm(3.5),
+        Emu::from_mm(3.5),
+    );
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_image(pic)))
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let anchor = match &docx.document.children[0] {
+        DocumentChild::Paragraph(p) => match &p.children[0] {
+            ParagraphChild::Run(r) => match &r.children[0] {
+                RunChild::Drawing(d) => match &d.children[0] {
+                    DrawingChild::WpAnchor(a) => a.clone(),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(anchor.wrap_distance, (72000, 72000, 126000, 126000));
+    Ok(())
+}
+
+#[test]
+pub fn picture_bullet_build_twice() -> Result<(), DocxError> {
+    use std::io::Read;
+
+    let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+    let mut image = vec![];
+    img.read_to_end(&mut image).unwrap();
+    let mut docx = Docx::new().add_numbering_picture_bullet(0, Pic::new(image).size(12, 12));
+    let first = docx.build().media;
+    let second = docx.build().media;
+    assert_eq!(first.len(), 1);
+    assert!(!first[0].1.is_empty());
+    assert_eq!(first, second);
+    Ok(())
+}
+
+#[test]
+pub fn header_and_footer() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/header_and_footer.docx");
+    let file = std::fs::File::create(&path).unwrap();
+    let xml = Docx::new()
+        .header(Header::with_text("Quarterly report"))
+        .footer(Footer::with_page_number_center())
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+        .build();
+    let document = std::str::from_utf8(&xml.document).unwrap();
+    assert!(document.contains(r#"<w:footerReference w:type="default" r:id="rIdFooter1" />"#));
+    let footer = std::str::from_utf8(xml.footer.as_ref().unwrap()).unwrap();
+    assert!(footer.contains(" PAGE "));
+    xml.pack(file)?;
+    Ok(())
+}
+
+#[test]
+pub fn sections() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Cover")))
+        .add_section(Section::new())
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Two columns")))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("More")))
+        .add_section(Section::new().columns(2))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Appendix")))
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let sections = docx.sections();
+    assert_eq!(sections.len(), 3);
+    assert_eq!(
+        sections
+            .iter()
+            .map(|s| s.children.len())
+            .collect::<Vec<_>>(),
+        vec![2, 3, 1]
+    );
+    assert_eq!(
+        *sections[1].property,
+        SectionProperty::new().columns(2).column_space(425)
+    );
+    Ok(())
+}
+
+#[test]
+pub fn resolve_list_numbers() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    let item = |text: &str, level: usize| {
+        Paragraph::new()
+            .add_run(Run::new().add_text(text))
+            .numbering(NumberingId::new(2), IndentLevel::new(level))
+    };
+    Docx::new()
+        .add_paragraph(item("Intro", 0))
+        .add_paragraph(item("Scope", 1))
+        .add_paragraph(item("Terms", 1))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Note")))
+        .add_paragraph(item("Usage", 0))
+        .add_abstract_numbering(
+            AbstractNumbering::new(2)
+                .add_level(Level::new(
+                    0,
+                    Start::new(1),
+                    NumberFormat::new("upperRoman"),
+                    LevelText::new("%1."),
+                    LevelJc::new("left"),
+                ))
+                .add_level(Level::new(
+                    1,
+                    Start::new(1),
+                    NumberFormat::new("decimal"),
+                    LevelText::new("%1.%2"),
+                    LevelJc::new("left"),
+                )),
+        )
+        .add_numbering(Numbering::new(2, 2))
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let numbers = docx.resolve_list_numbers();
+    let texts: Vec<Option<&str>> = docx
+        .document
+        .children
+        .iter()
+        .filter_map(|c| match c {
+            DocumentChild::Paragraph(p) => Some(numbers.get(&p.id).map(|s| s.as_str())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        texts,
+        vec![Some("I."), Some("I.1"), Some("I.2"), None, Some("II.")]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn read_settings() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+        .settings(
+            Settings::new()
+                .zoom(80)
+                .default_tab_stop(720)
+                .track_revisions(true)
+                .auto_hyphenation(true)
+                .add_doc_var("client", "ACME")
+                .document_protection(DocumentProtection::new(DocumentProtectionType::Comments)),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let settings = docx.get_settings();
+    assert_eq!(settings.get_zoom(), 80);
+    assert_eq!(settings.get_default_tab_stop(), 720);
+    assert!(settings.get_track_revisions());
+    assert!(settings.get_auto_hyphenation());
+    assert_eq!(settings.get_doc_var("client"), Some("ACME"));
+    assert_eq!(
+        settings.get_document_protection(),
+        Some(&DocumentProtection::new(DocumentProtectionType::Comments))
+    );
+    assert!(settings.get_print_settings().space_for_ul);
+    Ok(())
+}
+
+#[test]
+pub fn statistics() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello world")))
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Kept"))
+                .add_delete(Delete::new().add_run(Run::new().add_delete_text(" and dropped"))),
+        )
+        .update_statistics()
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let s = docx.statistics();
+    assert_eq!(s.words, 3);
+    assert_eq!(s.characters, 14);
+    assert_eq!(s.characters_with_spaces, 15);
+    assert_eq!(s.paragraphs, 2);
+    assert_eq!(s.pages, 1);
+    Ok(())
+}
+
+#[test]
+pub fn alt_chunk() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Before")))
+        .add_alt_chunk(AltChunk::html(
+            "<html><body><table><tr><td>A</td></tr></table></body></html>",
+        ))
+        .add_alt_chunk(AltChunk::rtf(r"{\rtf1 Hello}"))
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let chunks: Vec<&AltChunk> = docx
+        .document
+        .children
+        .iter()
+        .filter_map(|c| match c {
+            DocumentChild::AltChunk(c) => Some(c),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].id, "rIdAltChunk1");
+    assert_eq!(chunks[0].chunk_type, AltChunkType::Html);
+    assert_eq!(
+        chunks[0].data,
+        b"<html><body><table><tr><td>A</td></tr></table></body></html>".to_vec()
+    );
+    assert_eq!(chunks[1].chunk_type, AltChunkType::Rtf);
+    assert_eq!(chunks[1].data, br"{\rtf1 Hello}".to_vec());
+    assert_eq!(
+        docx.content_types().content_type_of("/word/afchunk2.rtf"),
+        Some("application/rtf")
+    );
+    Ok(())
+}
+
+#[test]
+pub fn vba_project() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Macro")))
+        .add_vba_project(vec![0xCC, 0x61, 0xFF, 0x00])
+        .build()
+        .pack(&mut buf)?;
+    let mut docx = read_docx(&buf.into_inner()).unwrap();
+    assert!(docx.has_vba_project());
+    assert_eq!(docx.vba_project(), Some(&[0xCC, 0x61, 0xFF, 0x00][..]));
+    assert_eq!(
+        docx.content_types().content_type_of("/word/document.xml"),
+        Some("application/vnd.ms-word.document.macroEnabled.main+xml")
+    );
+
+    // Rebuilding the read document keeps the project.
+    let mut buf = std::io::Cursor::new(vec![]);
+    docx.build().pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(docx.vba_project(), Some(&[0xCC, 0x61, 0xFF, 0x00][..]));
+
+    assert!(!Docx::new().has_vba_project());
+    Ok(())
+}
+
+#[test]
+pub fn colors() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(
+                    Run::new()
+                        .add_text("Hello")
+                        .color(Color::theme("accent1").shade(191).fallback("2F5496"))
+                        .shading(Shading::new().fill("auto")),
+                )
+                .shading(Shading::new().fill(Color::theme("accent2").tint(51))),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let p = match &docx.document.children[0] {
+        DocumentChild::Paragraph(p) => p,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        p.property.shading.as_ref().map(|s| &s.fill),
+        Some(&Color::theme("accent2").tint(51))
+    );
+    let r = match &p.children[0] {
+        ParagraphChild::Run(r) => r,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        r.run_property.color,
+        Some(Color::theme("accent1").shade(191).fallback("2F5496"))
+    );
+    assert_eq!(
+        r.run_property.shading.as_ref().map(|s| &s.fill),
+        Some(&Color::Auto)
+    );
+    Ok(())
+}
+
+#[test]
+pub fn numbering_info() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("First"))
+                .numbering(NumberingId::new(3), IndentLevel::new(1)),
+        )
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Plain")))
+        .add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Styled"))
+                .style("ListLetter"),
+        )
+        .styles(
+            Styles::new().add_style(Style::new("ListLetter", StyleType::Paragraph).numbering(3)),
+        )
+        .add_abstract_numbering(
+            AbstractNumbering::new(2)
+                .add_level(Level::new(
+                    0,
+                    Start::new(1),
+                    NumberFormat::new("decimal"),
+                    LevelText::new("%1."),
+                    LevelJc::new("left"),
+                ))
+                .add_level(Level::new(
+                    1,
+                    Start::new(1),
+                    NumberFormat::new("lowerLetter"),
+                    LevelText::new("%2)"),
+                    LevelJc::new("left"),
+                )),
+        )
+        .add_numbering(Numbering::new(3, 2).add_override(LevelOverride::new(1).start(4)))
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    let infos: Vec<Option<ResolvedNumbering>> = docx
+        .document
+        .children
+        .iter()
+        .filter_map(|c| match c {
+            DocumentChild::Paragraph(p) => Some(p.numbering_info(&docx)),
+            _ => None,
+        })
+        .collect();
+    let info = infos[0].as_ref().unwrap();
+    assert_eq!(info.numbering_id, 3);
+    assert_eq!(info.abstract_numbering.id, 2);
+    assert_eq!(info.level.level, 1);
+    assert_eq!(info.level.format, NumberFormat::new("lowerLetter"));
+    assert_eq!(info.level.text, LevelText::new("%2)"));
+    assert_eq!(info.start, 4);
+    assert_eq!(infos[1], None);
+    // numId from the style, ilvl defaults to 0.
+    let info = infos[2].as_ref().unwrap();
+    assert_eq!(info.numbering_id, 3);
+    assert_eq!(info.level.level, 0);
+    assert_eq!(info.level.format, NumberFormat::new("decimal"));
+    Ok(())
+}
+
+#[test]
+pub fn default_script_fonts() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    Docx::new()
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("こんにちは")))
+        .default_script_fonts(
+            "Calibri",
+            "MS Mincho",
+            "Arial",
+            LangSettings::new().val("en-US").east_asia("ja-JP"),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(
+        docx.get_default_fonts(),
+        Some(
+            &RunFonts::new()
+                .ascii("Calibri")
+                .hi_ansi("Calibri")
+                .east_asia("MS Mincho")
+                .cs("Arial")
+        )
+    );
+    assert_eq!(
+        docx.get_settings().get_theme_font_lang(),
+        Some(&LangSettings::new().val("en-US").east_asia("ja-JP"))
+    );
+    Ok(())
+}
+
+#[test]
+pub fn reject_moves_and_formatting_changes() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    let mut reformatted = Run::new().add_text("text").bold();
+    reformatted.run_property = reformatted
+        .run_property
+        .property_change(RunPropertyChange::new(RunProperty::new().italic()));
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_move_to(MoveTo::new().add_run(Run::new().add_text("Moved ")))
+                .add_run(reformatted)
+                .add_move_from(MoveFrom::new().add_run(Run::new().add_delete_text(" moved"))),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let docx = read_docx(&buf.into_inner()).unwrap().reject_all_revisions();
+    let p = match &docx.document_children()[0] {
+        DocumentChild::Paragraph(p) => p,
+        _ => unreachable!(),
+    };
+    assert_eq!(p.children.len(), 2);
+    match &p.children[0] {
+        ParagraphChild::Run(r) => {
+            assert!(r.run_property.italic.is_some());
+            assert!(r.run_property.bold.is_none());
+            assert!(r.run_property.property_change.is_none());
+        }
+        _ => unreachable!(),
+    }
+    match &p.children[1] {
+        ParagraphChild::Run(r) => assert!(matches!(r.children[0], RunChild::Text(_))),
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+#[test]
+pub fn generated_bookmark_ids_skip_used_ids() -> Result<(), DocxError> {
+    let mut buf = std::io::Cursor::new(vec![]);
+    let used = 100_000;
+    Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_bookmark_start(used, "read")
+                .add_run(Run::new().add_text("Hello"))
+                .add_bookmark_end(used),
+        )
+        .build()
+        .pack(&mut buf)?;
+    let read = read_docx(&buf.into_inner()).unwrap();
+    assert_eq!(read.document_children().len(), 1);
+    let p = Paragraph::new().add_bookmark(BookmarkStart::AUTO_ID, "new", vec![]);
+    match &p.children[0] {
+        ParagraphChild::BookmarkStart(b) => assert!(b.id > used),
+        _ => unreachable!(),
+    }
+    Ok(())
+}
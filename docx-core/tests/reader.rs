@@ -274,3 +274,40 @@ pub fn read_line_spacing() {
     file.write_all(json.as_bytes()).unwrap();
     file.flush().unwrap();
 }
+
+#[test]
+pub fn read_relationships_and_content_types() {
+    let mut file = File::open("../fixtures/hello_world/hello_world.docx").unwrap();
+    let mut buf = vec![];
+    file.read_to_end(&mut buf).unwrap();
+    let docx = read_docx(&buf).unwrap();
+
+    let styles = docx
+        .relationships()
+        .iter()
+        .find(|r| r.target == "styles.xml")
+        .unwrap();
+    assert_eq!(styles.target_mode, TargetMode::Internal);
+    assert_eq!(docx.relationships().external().count(), 0);
+    assert_eq!(
+        docx.content_types().content_type_of("/word/document.xml"),
+        Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml")
+    );
+}
+
+#[test]
+pub fn read_attached_template() {
+    let mut file = File::open("../fixtures/attached_template/attached_template.docx").unwrap();
+    let mut buf = vec![];
+    file.read_to_end(&mut buf).unwrap();
+    let docx = read_docx(&buf).unwrap();
+
+    assert_eq!(docx.settings_relationships().len(), 1);
+    let template = docx.attached_template().unwrap();
+    assert_eq!(template.target, "https://example.com/templates/report.dotm");
+    assert_eq!(template.target_mode, TargetMode::External);
+    assert!(docx
+        .relationships()
+        .iter()
+        .all(|r| r.rel_type != ATTACHED_TEMPLATE_TYPE));
+}
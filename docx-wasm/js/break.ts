@@ -1,8 +1,14 @@
 export type BreakType = "page" | "column" | "textWrapping";
 
+export type BreakClear = "none" | "left" | "right" | "all";
+
 export class Break {
   type: BreakType;
-  constructor(type: BreakType) {
+  clear: BreakClear | null = null;
+  constructor(type: BreakType, clear?: BreakClear) {
     this.type = type;
+    if (clear) {
+      this.clear = clear;
+    }
   }
 }
@@ -4,6 +4,7 @@ import { Table } from "./table";
 export class Comment {
   id: number;
   _author: string;
+  _initials: string;
   _date: string;
   children: (Paragraph | Table)[] = [];
   _parentCommentId: number;
@@ -17,6 +18,11 @@ export class Comment {
     return this;
   }
 
+  initials(initials: string) {
+    this._initials = initials;
+    return this;
+  }
+
   date(date: string) {
     this._date = date;
     return this;
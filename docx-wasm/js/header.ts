@@ -0,0 +1,31 @@
+import { Paragraph } from "./paragraph";
+import { Run } from "./run";
+import { Table } from "./table";
+
+export class Header {
+  children: (Paragraph | Table)[] = [];
+  _pageNumberCenter = false;
+
+  static withText(text: string) {
+    return new Header().addParagraph(
+      new Paragraph().addRun(new Run().addText(text))
+    );
+  }
+
+  // Starts with a centered paragraph holding the page number.
+  static withPageNumberCenter() {
+    const h = new Header();
+    h._pageNumberCenter = true;
+    return h;
+  }
+
+  addParagraph(p: Paragraph) {
+    this.children.push(p);
+    return this;
+  }
+
+  addTable(t: Table) {
+    this.children.push(t);
+    return this;
+  }
+}
@@ -8,8 +8,10 @@ import {BorderType} from "./border";
 import {Run, RunFonts} from "./run";
 import {Text} from "./text";
 import {Tab} from "./tab";
-import {Break} from "./break";
+import {Break, BreakClear} from "./break";
 import {Comment} from "./comment";
+import {Header} from "./header";
+import {Footer} from "./footer";
 import {CommentEnd} from "./comment-end";
 import {AbstractNumbering} from "./abstract-numbering";
 import {Numbering} from "./numbering";
@@ -80,9 +82,12 @@ export class Docx {
     docProps: DocProps = new DocProps();
     sectionProperty: SectionProperty = new SectionProperty();
     _taskpanes: boolean = false;
+    _defaultLanguage: { val: string; eastAsia: string; bidi: string } | null = null;
     webextensions: WebExtension[] = [];
     customItems: { id: string; xml: string }[] = [];
     styles = new Styles();
+    _header: Header | null = null;
+    _footer: Footer | null = null;
 
     addParagraph(p: Paragraph) {
         if (p.hasNumberings) {
@@ -110,6 +115,16 @@ export class Docx {
         return this;
     }
 
+    header(h: Header) {
+        this._header = h;
+        return this;
+    }
+
+    footer(f: Footer) {
+        this._footer = f;
+        return this;
+    }
+
     addAbstractNumbering(num: AbstractNumbering) {
         this.abstractNumberings.push(num);
         return this;
@@ -150,6 +165,16 @@ export class Docx {
         return this;
     }
 
+    setVariable(name: string, val: string) {
+        this.settings.setVariable(name, val);
+        return this;
+    }
+
+    trackRevisions(v: boolean) {
+        this.settings.trackRevisions(v);
+        return this;
+    }
+
     pageSize(w: number, h: number) {
         this.sectionProperty.pageSize(w, h);
         return this;
@@ -185,6 +210,11 @@ export class Docx {
         return this;
     }
 
+    defaultLanguage(val: string, eastAsia = "", bidi = "") {
+        this._defaultLanguage = { val, eastAsia, bidi };
+        return this;
+    }
+
     taskpanes() {
         this._taskpanes = true;
         return this;
@@ -214,9 +244,48 @@ export class Docx {
         if (fonts?._eastAsia) {
             f = f.east_asia(fonts._eastAsia);
         }
+        if (fonts?._asciiTheme) {
+            f = f.ascii_theme(fonts._asciiTheme);
+        }
+        if (fonts?._hiAnsiTheme) {
+            f = f.hi_ansi_theme(fonts._hiAnsiTheme);
+        }
+        if (fonts?._csTheme) {
+            f = f.cs_theme(fonts._csTheme);
+        }
+        if (fonts?._eastAsiaTheme) {
+            f = f.east_asia_theme(fonts._eastAsiaTheme);
+        }
+        switch (fonts?._hint) {
+            case "default": {
+                f = f.hint(wasm.FontHintType.Default);
+                break;
+            }
+            case "eastAsia": {
+                f = f.hint(wasm.FontHintType.EastAsia);
+                break;
+            }
+            case "cs": {
+                f = f.hint(wasm.FontHintType.Cs);
+                break;
+            }
+        }
         return f;
     };
 
+    buildBreakClear(c: BreakClear) {
+        switch (c) {
+            case "left":
+                return wasm.BreakClear.Left;
+            case "right":
+                return wasm.BreakClear.Right;
+            case "all":
+                return wasm.BreakClear.All;
+            default:
+                return wasm.BreakClear.None;
+        }
+    }
+
     buildRun(r: Run) {
         let run = wasm.createRun();
         r.children.forEach((child) => {
@@ -227,7 +296,9 @@ export class Docx {
             } else if (child instanceof Tab) {
                 run = run.add_tab();
             } else if (child instanceof Break) {
-                if (child.type === "column") {
+                if (child.clear) {
+                    run = run.add_break_clear(this.buildBreakClear(child.clear));
+                } else if (child.type === "column") {
                     run = run.add_break(wasm.BreakType.Column);
                 } else if (child.type === "page") {
                     run = run.add_break(wasm.BreakType.Page);
@@ -241,6 +312,10 @@ export class Docx {
             run = run.size(r.property.size);
         }
 
+        if (typeof r.property.sizeCs !== "undefined") {
+            run = run.size_cs(r.property.sizeCs);
+        }
+
         if (r.property.color) {
             run = run.color(r.property.color);
         }
@@ -271,12 +346,22 @@ export class Docx {
 
         if (r.property.vanish) {
             run = run.vanish();
+        } else if (r.property.vanish === false) {
+            run = run.disable_vanish();
+        }
+
+        if (r.property.specVanish) {
+            run = run.spec_vanish();
         }
 
         if (r.property.spacing != null) {
             run = run.spacing(r.property.spacing);
         }
 
+        if (r.property.position != null) {
+            run = run.position(r.property.position);
+        }
+
         if (r.property.textBorder) {
             const {borderType, color, space, size} = r.property.textBorder;
             run = run.text_border(convertBorderType(borderType), size, space, color);
@@ -324,6 +409,9 @@ export class Docx {
         if (c._author) {
             comment = comment.author(c._author);
         }
+        if (c._initials) {
+            comment = comment.initials(c._initials);
+        }
         if (c._date) {
             comment = comment.date(c._date);
         }
@@ -446,19 +534,7 @@ export class Docx {
         }
 
         if (p.property.runProperty.fonts) {
-            let f = wasm.createRunFonts();
-            if (p.property.runProperty.fonts._ascii) {
-                f = f.ascii(p.property.runProperty.fonts._ascii);
-            }
-            if (p.property.runProperty.fonts._hiAnsi) {
-                f = f.hi_ansi(p.property.runProperty.fonts._hiAnsi);
-            }
-            if (p.property.runProperty.fonts._cs) {
-                f = f.cs(p.property.runProperty.fonts._cs);
-            }
-            if (p.property.runProperty.fonts._eastAsia) {
-                f = f.east_asia(p.property.runProperty.fonts._eastAsia);
-            }
+            const f = this.buildRunFonts(p.property.runProperty.fonts);
             paragraph = paragraph.fonts(f);
         }
 
@@ -550,6 +626,22 @@ export class Docx {
             }
         }
 
+        if (typeof t.property.caption !== "undefined") {
+            table = table.caption(t.property.caption);
+        }
+
+        if (typeof t.property.description !== "undefined") {
+            table = table.description(t.property.description);
+        }
+
+        if (t.property.keepTogether) {
+            table = table.keep_together();
+        }
+
+        if (typeof t.property.keepWithNext !== "undefined") {
+            table = table.keep_with_next(t.property.keepWithNext);
+        }
+
         return table;
     }
 
@@ -591,7 +683,14 @@ export class Docx {
         }
 
         if (typeof c.property.width !== "undefined") {
-            cell = cell.width(c.property.width);
+            if (typeof c.property.widthType !== "undefined") {
+                cell = cell.width_with_type(
+                    c.property.width,
+                    convertWidthType(c.property.widthType)
+                );
+            } else {
+                cell = cell.width(c.property.width);
+            }
         }
 
         if (typeof c.property.textDirection !== "undefined") {
@@ -600,6 +699,14 @@ export class Docx {
             );
         }
 
+        if (typeof c.property.noWrap !== "undefined") {
+            cell = cell.no_wrap(c.property.noWrap);
+        }
+
+        if (typeof c.property.fitText !== "undefined") {
+            cell = cell.fit_text(c.property.fitText);
+        }
+
         if (typeof c.property.borders !== "undefined") {
             cell = this.buildCellBorders(c, cell);
         }
@@ -721,19 +828,7 @@ export class Docx {
         }
 
         if (l.runProperty.fonts) {
-            let f = wasm.createRunFonts();
-            if (l.runProperty.fonts._ascii) {
-                f = f.ascii(l.runProperty.fonts._ascii);
-            }
-            if (l.runProperty.fonts._hiAnsi) {
-                f = f.hi_ansi(l.runProperty.fonts._hiAnsi);
-            }
-            if (l.runProperty.fonts._cs) {
-                f = f.cs(l.runProperty.fonts._cs);
-            }
-            if (l.runProperty.fonts._eastAsia) {
-                f = f.east_asia(l.runProperty.fonts._eastAsia);
-            }
+            const f = this.buildRunFonts(l.runProperty.fonts);
             level = level.fonts(f);
         }
 
@@ -807,10 +902,24 @@ export class Docx {
 
         docx = docx.default_tab_stop(this.settings._defaultTabStop);
 
+        if (this.settings._trackRevisions) {
+            docx = docx.track_revisions(true);
+        }
+
         this.settings._docVars.forEach((v) => {
             docx = docx.add_doc_var(v.name, v.val);
         });
 
+        if (this.settings._themeFontLang) {
+            const { val, eastAsia, bidi } = this.settings._themeFontLang;
+            docx = docx.theme_font_lang(val, eastAsia, bidi);
+        }
+
+        if (this._defaultLanguage) {
+            const { val, eastAsia, bidi } = this._defaultLanguage;
+            docx = docx.default_language(val, eastAsia, bidi);
+        }
+
         if (this.sectionProperty._pageMargin) {
             const {top, left, right, bottom, header, footer, gutter} =
                 this.sectionProperty._pageMargin;
@@ -915,6 +1024,34 @@ export class Docx {
             docx = docx.add_custom_item(item.id, item.xml);
         }
 
+        if (this._header) {
+            let header = this._header._pageNumberCenter
+                ? wasm.createHeaderWithPageNumberCenter()
+                : wasm.createHeader();
+            for (const c of this._header.children) {
+                if (c instanceof Paragraph) {
+                    header = header.add_paragraph(this.buildParagraph(c));
+                } else {
+                    header = header.add_table(this.buildTable(c));
+                }
+            }
+            docx = docx.header(header);
+        }
+
+        if (this._footer) {
+            let footer = this._footer._pageNumberCenter
+                ? wasm.createFooterWithPageNumberCenter()
+                : wasm.createFooter();
+            for (const c of this._footer.children) {
+                if (c instanceof Paragraph) {
+                    footer = footer.add_paragraph(this.buildParagraph(c));
+                } else {
+                    footer = footer.add_table(this.buildTable(c));
+                }
+            }
+            docx = docx.footer(footer);
+        }
+
         return docx;
     }
 
@@ -951,6 +1088,8 @@ export * from "./text";
 export * from "./style";
 export * from "./styles";
 export * from "./comment";
+export * from "./header";
+export * from "./footer";
 export * from "./comment-end";
 export * from "./numbering";
 export * from "./abstract-numbering";
@@ -5,6 +5,7 @@ export type CommentChildJSON = ParagraphJSON | TableJSON;
 export type CommentJSON = {
   id: number;
   author: string;
+  initials: string;
   date: string;
   children: CommentChildJSON[];
   parentCommentId: number | null;
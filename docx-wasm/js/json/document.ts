@@ -1,6 +1,6 @@
 import { ParagraphJSON, BookmarkStartJSON, BookmarkEndJSON } from "./paragraph";
 import { TableJSON } from "./table";
-import { SectionPropertyJSON } from "./section-property";
+import { SectionPropertyJSON, SectionJSON } from "./section-property";
 import { CommentRangeStartJSON, CommentRangeEndJSON } from "..";
 
 export type DocumentChildJSON =
@@ -9,7 +9,17 @@ export type DocumentChildJSON =
   | CommentRangeStartJSON
   | CommentRangeEndJSON
   | BookmarkStartJSON
-  | BookmarkEndJSON;
+  | BookmarkEndJSON
+  | SectionJSON
+  | AltChunkJSON;
+
+export type AltChunkJSON = {
+  type: "altChunk";
+  data: {
+    id: string;
+    chunkType: "html" | "xhtml" | "mht" | "rtf" | "text";
+  };
+};
 
 export type DocumentJSON = {
   children: DocumentChildJSON[];
@@ -13,6 +13,8 @@ export type WpAnchorJSON = {
   type: "anchor";
   data: {
     children: AGraphicJSON[];
+    // top, bottom, left and right in EMU
+    wrapDistance: [number, number, number, number];
   };
 };
 
@@ -0,0 +1,12 @@
+import { ParagraphJSON } from "./paragraph";
+import { TableJSON } from "./table";
+
+export type HeaderChildJSON = ParagraphJSON | TableJSON;
+
+export type HeaderJSON = {
+  children: HeaderChildJSON[];
+};
+
+export type FooterJSON = {
+  children: HeaderChildJSON[];
+};
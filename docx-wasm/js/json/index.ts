@@ -3,6 +3,7 @@ import { DocumentJSON } from "./document";
 import { NumberingsJSON } from "./numbering";
 import { CommentJSON } from "./comment";
 import { WebSettingsJSON } from "./web-settings";
+import { LangSettingsJSON } from "./run";
 
 export type DocxJSON = {
   contentType: {
@@ -52,6 +53,45 @@ export type SettingsJSON = {
   defaultTabStop: number;
   zoom: number;
   docVars: { name: string; val: string }[];
+  trackRevisions: boolean;
+  documentProtection: DocumentProtectionJSON | null;
+  autoHyphenation: boolean;
+  footnoteProperties: NotePropertiesJSON<FootnotePositionJSON> | null;
+  endnoteProperties: NotePropertiesJSON<EndnotePositionJSON> | null;
+  rsids: string[];
+  themeFontLang: LangSettingsJSON | null;
+  printSettings: PrintSettingsJSON;
+};
+
+export type DocumentProtectionJSON = {
+  edit: "none" | "readOnly" | "comments" | "trackedChanges" | "forms";
+  enforcement: boolean;
+};
+
+export type PrintSettingsJSON = {
+  spaceForUl: boolean;
+  balanceSingleByteDoubleByteWidth: boolean;
+  doNotLeaveBackslashAlone: boolean;
+  ulTrailSpace: boolean;
+  doNotExpandShiftReturn: boolean;
+  adjustLineHeightTable: boolean;
+  useFeLayout: boolean;
+  autoSpaceLikeWord95: boolean;
+};
+
+export type FootnotePositionJSON =
+  | "pageBottom"
+  | "beneathText"
+  | "sectEnd"
+  | "docEnd";
+
+export type EndnotePositionJSON = "sectEnd" | "docEnd";
+
+export type NotePropertiesJSON<P> = {
+  position: P | null;
+  numberFormat: string | null;
+  start: number | null;
+  restart: "continuous" | "eachSection" | "eachPage" | null;
 };
 
 export * from "./styles";
@@ -67,3 +107,4 @@ export * from "./web-settings";
 export * from "./comment";
 export * from "./textbox-content";
 export * from "./section-property";
+export * from "./header";
@@ -10,6 +10,7 @@ export type LevelJSON = {
   paragraphProperty: ParagraphPropertyJSON;
   pstyle: string | null;
   levelRestart: number | null;
+  pictureBulletId: number | null;
 };
 
 export type AbstractNumberingJSON = {
@@ -31,7 +32,18 @@ export type LevelOverrideJSON = {
   overrideLevel: LevelJSON | null;
 };
 
+export type NumberingPictureBulletJSON = {
+  id: number;
+  pic: {
+    id: number;
+    image: number[];
+    // width and height in EMU
+    size: [number, number];
+  };
+};
+
 export type NumberingsJSON = {
+  pictureBullets: NumberingPictureBulletJSON[];
   abstractNums: AbstractNumberingJSON[];
   numberings: NumberingJSON[];
 };
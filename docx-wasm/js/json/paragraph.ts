@@ -1,22 +1,42 @@
-import { RunJSON, RunPropertyJSON } from "./run";
+import { RunJSON, RunPropertyJSON, RawXmlJSON } from "./run";
 import { IndentJSON } from "./indent";
 import { CommentRangeStartJSON, CommentRangeEndJSON } from "..";
 import {LineSpacingJSON} from "./line_spacing";
+import { SectionPropertyJSON } from "./section-property";
+import { ShadingJSON } from "./shading";
 
 export type ParagraphChildJSON =
   | RunJSON
   | InsertJSON
   | DeleteJSON
+  | MoveFromJSON
+  | MoveToJSON
   | CommentRangeStartJSON
   | CommentRangeEndJSON
   | BookmarkStartJSON
-  | BookmarkEndJSON;
+  | BookmarkEndJSON
+  | RawXmlJSON;
 
 export type NumberingPropertyJSON = {
   id: number | null;
   level: number | null;
 };
 
+export type TabStopJSON = {
+  val:
+    | "bar"
+    | "center"
+    | "clear"
+    | "decimal"
+    | "end"
+    | "num"
+    | "start"
+    | "left"
+    | "right";
+  pos: number;
+  leader: "none" | "dot" | "hyphen" | "underscore" | "heavy" | "middleDot" | null;
+};
+
 export type ParagraphPropertyJSON = {
   runProperty: RunPropertyJSON;
   style: string | null;
@@ -24,11 +44,15 @@ export type ParagraphPropertyJSON = {
   alignment: "left" | "center" | "right" | "justified" | "both";
   indent: IndentJSON | null;
   lineSpacing: LineSpacingJSON | null;
+  contextualSpacing: boolean | null;
   divId: string | null;
+  sectionProperty: SectionPropertyJSON | null;
   keepNext: boolean;
   keepLines: boolean;
   pageBreakBefore: boolean;
   windowControl: boolean;
+  tabs: TabStopJSON[];
+  shading: ShadingJSON | null;
 };
 
 export type ParagraphJSON = {
@@ -63,6 +87,24 @@ export type DeleteJSON = {
   };
 };
 
+export type MoveFromJSON = {
+  type: "moveFrom";
+  data: {
+    author: string;
+    date: string;
+    children: RunJSON[];
+  };
+};
+
+export type MoveToJSON = {
+  type: "moveTo";
+  data: {
+    author: string;
+    date: string;
+    children: RunJSON[];
+  };
+};
+
 export type DeleteChildJSON =
   | RunJSON
   | CommentRangeStartJSON
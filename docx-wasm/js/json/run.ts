@@ -3,6 +3,7 @@ import { CommentRangeStartJSON, CommentRangeEndJSON } from "..";
 import { BorderType } from "../border";
 import { InsertJSON, DeleteJSON } from "./paragraph";
 import { VertAlignType } from "../run";
+import { ColorJSON, ShadingJSON } from "./shading";
 
 export type TextBorderJSON = {
   borderType: BorderType;
@@ -11,10 +12,23 @@ export type TextBorderJSON = {
   color: string;
 };
 
+// The keys are not camelCased, to keep those of earlier versions.
+export type RunFontsJSON = {
+  ascii: string | null;
+  hi_ansi: string | null;
+  east_asia: string | null;
+  cs: string | null;
+  ascii_theme: string | null;
+  hi_ansi_theme: string | null;
+  east_asia_theme: string | null;
+  cs_theme: string | null;
+  hint: "default" | "eastAsia" | "cs" | null;
+};
+
 export type RunPropertyJSON = {
   sz: number | null;
   szCs: number | null;
-  color: string | null;
+  color: ColorJSON | null;
   highlight: string | null;
   vertAlign: VertAlignType | null;
   underline: string | null;
@@ -23,10 +37,28 @@ export type RunPropertyJSON = {
   italic: boolean | null;
   italicCs: boolean | null;
   vanish: boolean | null;
+  specVanish: boolean | null;
   spacing: number | null;
+  position: number | null;
+  fonts: RunFontsJSON | null;
   textBorder: TextBorderJSON | null;
+  shading: ShadingJSON | null;
   ins: InsertJSON | null;
   del: DeleteJSON | null;
+  lang: LangSettingsJSON | null;
+  propertyChange: RunPropertyChangeJSON | null;
+};
+
+export type RunPropertyChangeJSON = {
+  author: string;
+  date: string;
+  property: RunPropertyJSON;
+};
+
+export type LangSettingsJSON = {
+  val: string | null;
+  eastAsia: string | null;
+  bidi: string | null;
 };
 
 export type RunChildJSON =
@@ -36,7 +68,31 @@ export type RunChildJSON =
   | BreakJSON
   | DrawingJSON
   | CommentRangeStartJSON
-  | CommentRangeEndJSON;
+  | CommentRangeEndJSON
+  | FieldCharJSON
+  | InstrTextJSON
+  | LastRenderedPageBreakJSON
+  | RawXmlJSON;
+
+export type RawXmlJSON = {
+  type: "rawXml";
+  data: string;
+};
+
+export type FieldCharJSON = {
+  type: "fieldChar";
+  data: {
+    fieldCharType: "begin" | "separate" | "end";
+    dirty: boolean;
+  };
+};
+
+export type InstrTextJSON = {
+  type: "instrText";
+  data: {
+    instr: string;
+  };
+};
 
 export type TextJSON = {
   type: "text";
@@ -58,10 +114,17 @@ export type TabJSON = {
   type: "tab";
 };
 
+export type LastRenderedPageBreakJSON = {
+  type: "lastRenderedPageBreak";
+};
+
+export type BreakClearType = "none" | "left" | "right" | "all";
+
 export type BreakJSON = {
   type: "break";
   data: {
     breakType: "page" | "column" | "textWrapping";
+    clear: BreakClearType | null;
   };
 };
 
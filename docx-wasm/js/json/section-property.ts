@@ -1,3 +1,5 @@
+import { HeaderJSON, FooterJSON } from "./header";
+
 export type DocGridType = "default" | "lines" | "linesAndChars" | "snapToChars";
 
 export type DocGridJSON = {
@@ -21,5 +23,42 @@ export type SectionPropertyJSON = {
     gutter: number;
   };
   columns: number;
+  columnNum: number;
   docGrid: DocGridJSON;
+  headerReferences: HeaderReferenceJSON[];
+  footerReferences: FooterReferenceJSON[];
+  sectionType: SectionType | null;
+  titlePg: boolean;
+  blankFirstPage: boolean;
+};
+
+export type HeaderFooterReferenceType = "default" | "first" | "even";
+
+export type HeaderReferenceJSON = {
+  headerType: HeaderFooterReferenceType;
+  id: string;
+};
+
+export type FooterReferenceJSON = {
+  footerType: HeaderFooterReferenceType;
+  id: string;
+};
+
+export type SectionType =
+  | "nextPage"
+  | "nextColumn"
+  | "continuous"
+  | "evenPage"
+  | "oddPage";
+
+export type SectionJSON = {
+  type: "section";
+  data: {
+    property: SectionPropertyJSON;
+    balanceColumns: boolean;
+    headerLinks: [HeaderFooterReferenceType, boolean][];
+    headers: [HeaderFooterReferenceType, HeaderJSON][];
+    footerLinks: [HeaderFooterReferenceType, boolean][];
+    footers: [HeaderFooterReferenceType, FooterJSON][];
+  };
 };
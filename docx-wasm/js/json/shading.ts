@@ -1,5 +1,15 @@
+export type ThemeColorJSON = {
+  themeColor: string;
+  tint: number | null;
+  shade: number | null;
+  fallback: string | null;
+};
+
+// A hex value, "auto" or a theme color.
+export type ColorJSON = string | ThemeColorJSON;
+
 export type ShadingJSON = {
   shdType: string;
-  color: string;
-  fill: string;
+  color: ColorJSON;
+  fill: ColorJSON;
 };
@@ -12,6 +12,11 @@ export type StyleJSON = {
   tableProperty: TablePropertyJSON;
   tableCellProperty: TableCellPropertyJSON;
   basedOn: string | null;
+  next: string | null;
+  uiPriority: number | null;
+  semiHidden: boolean | null;
+  unhideWhenUsed: boolean | null;
+  qFormat: boolean | null;
 };
 
 export type StylesJSON = {
@@ -19,6 +24,9 @@ export type StylesJSON = {
     runPropertyDefault: {
       runProperty: RunPropertyJSON;
     };
+    paragraphPropertyDefault: {
+      paragraphProperty: ParagraphPropertyJSON;
+    } | null;
   };
   styles: StyleJSON[];
 };
@@ -24,6 +24,8 @@ export type TableCellPropertyJSON = {
   textDirection: TextDirectionType | null;
   hasNumbering: boolean;
   shading: ShadingJSON | null;
+  noWrap: boolean;
+  fitText: boolean;
 };
 
 export type TableRowPropertyJSON = {
@@ -43,6 +45,13 @@ export type TableCellJSON = {
 export type TableRowJSON = {
   cells: TableCellJSON[];
   property: TableRowPropertyJSON;
+  exceptionProperties: TablePropertyExceptionsJSON | null;
+};
+
+export type TablePropertyExceptionsJSON = {
+  borders: TablePropertyJSON["borders"];
+  shading: ShadingJSON | null;
+  margins: TableCellMarginsJSON | null;
 };
 
 export type TableCellMarginJSON = { val: number; widthType: WidthType };
@@ -75,6 +84,8 @@ export type TablePropertyJSON = {
   };
   style: string | null;
   layout: TableLayoutType | null;
+  caption: string | null;
+  description: string | null;
 };
 
 export type TableJSON = {
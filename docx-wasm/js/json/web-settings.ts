@@ -1,5 +1,6 @@
 export type WebSettingsJSON = {
   divs: DivJSON[];
+  optimizeForBrowser: boolean;
 };
 
 export type DivJSON = {
@@ -1,7 +1,7 @@
 import { Text } from "./text";
 import { DeleteText } from "./delete-text";
 import { Tab } from "./tab";
-import { Break, BreakType } from "./break";
+import { Break, BreakClear, BreakType } from "./break";
 import { BorderType } from "./border";
 
 export type RunChild = Text | DeleteText | Tab | Break;
@@ -15,8 +15,11 @@ export type TextBorder = {
 
 export type VertAlignType = "baseline" | "superscript" | "subscript";
 
+export type FontHintType = "default" | "eastAsia" | "cs";
+
 export type RunProperty = {
   size?: number;
+  sizeCs?: number;
   color?: string;
   highlight?: string;
   vertAlign?: VertAlignType;
@@ -24,8 +27,10 @@ export type RunProperty = {
   italic?: boolean;
   underline?: string;
   vanish?: boolean;
+  specVanish?: boolean;
   fonts?: RunFonts;
   spacing?: number;
+  position?: number;
   textBorder?: TextBorder;
 };
 
@@ -38,6 +43,11 @@ export class RunFonts {
   _hiAnsi?: string;
   _eastAsia?: string;
   _cs?: string;
+  _asciiTheme?: string;
+  _hiAnsiTheme?: string;
+  _eastAsiaTheme?: string;
+  _csTheme?: string;
+  _hint?: FontHintType;
 
   ascii(f: string) {
     this._ascii = f;
@@ -58,6 +68,31 @@ export class RunFonts {
     this._eastAsia = f;
     return this;
   }
+
+  asciiTheme(f: string) {
+    this._asciiTheme = f;
+    return this;
+  }
+
+  hiAnsiTheme(f: string) {
+    this._hiAnsiTheme = f;
+    return this;
+  }
+
+  eastAsiaTheme(f: string) {
+    this._eastAsiaTheme = f;
+    return this;
+  }
+
+  csTheme(f: string) {
+    this._csTheme = f;
+    return this;
+  }
+
+  hint(h: FontHintType) {
+    this._hint = h;
+    return this;
+  }
 }
 
 export class Run {
@@ -79,6 +114,11 @@ export class Run {
     return this;
   }
 
+  addBreakClear(clear: BreakClear) {
+    this.children.push(new Break("textWrapping", clear));
+    return this;
+  }
+
   addBreak(type: BreakType) {
     this.children.push(new Break(type));
     return this;
@@ -89,6 +129,11 @@ export class Run {
     return this;
   }
 
+  sizeCs(sizeCs: number) {
+    this.property = { ...this.property, sizeCs };
+    return this;
+  }
+
   color(color: string) {
     this.property = { ...this.property, color };
     return this;
@@ -124,6 +169,16 @@ export class Run {
     return this;
   }
 
+  disableVanish() {
+    this.property = { ...this.property, vanish: false };
+    return this;
+  }
+
+  specVanish() {
+    this.property = { ...this.property, specVanish: true };
+    return this;
+  }
+
   fonts(fonts: RunFonts) {
     this.property = { ...this.property, fonts };
     return this;
@@ -134,6 +189,11 @@ export class Run {
     return this;
   }
 
+  position(position: number) {
+    this.property = { ...this.property, position };
+    return this;
+  }
+
   textBorder(type: BorderType, size: number, space: number, color: string) {
     this.property = {
       ...this.property,
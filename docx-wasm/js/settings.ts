@@ -7,6 +7,8 @@ export class Settings {
   _docId: string | null = null;
   _docVars: DocVar[] = [];
   _defaultTabStop = 840;
+  _trackRevisions = false;
+  _themeFontLang: { val: string; eastAsia: string; bidi: string } | null = null;
 
   docId(id: string) {
     this._docId = id;
@@ -18,8 +20,28 @@ export class Settings {
     return this;
   }
 
+  trackRevisions(v: boolean) {
+    this._trackRevisions = v;
+    return this;
+  }
+
+  themeFontLang(val: string, eastAsia = "", bidi = "") {
+    this._themeFontLang = { val, eastAsia, bidi };
+    return this;
+  }
+
   addDocVar(name: string, val: string) {
     this._docVars.push({ name, val });
     return this;
   }
+
+  setVariable(name: string, val: string) {
+    const v = this._docVars.find((v) => v.name === name);
+    if (v) {
+      v.val = val;
+    } else {
+      this._docVars.push({ name, val });
+    }
+    return this;
+  }
 }
@@ -3,6 +3,7 @@ import { Table } from "./table";
 import { Shading } from "./shading";
 import { TableCellBorders, PositionKeys } from "./table-cell-borders";
 import { TableCellBorderPosition, TableCellBorder } from "./table-cell-border";
+import { WidthType } from "./json/table";
 import * as wasm from "./pkg";
 
 export type VMergeType = "restart" | "continue";
@@ -56,8 +57,11 @@ export type CellProperty = {
   verticalAlign?: VAlignType;
   gridSpan?: number;
   width?: number;
+  widthType?: WidthType;
   textDirection?: TextDirectionType;
   shading?: Shading;
+  noWrap?: boolean;
+  fitText?: boolean;
 };
 
 export class TableCell {
@@ -98,8 +102,9 @@ export class TableCell {
     return this;
   }
 
-  width(v: number) {
+  width(v: number, type?: WidthType) {
     this.property.width = v;
+    this.property.widthType = type;
     return this;
   }
 
@@ -117,6 +122,16 @@ export class TableCell {
     return this;
   }
 
+  noWrap(v: boolean) {
+    this.property.noWrap = v;
+    return this;
+  }
+
+  fitText(v: boolean) {
+    this.property.fitText = v;
+    return this;
+  }
+
   setBorder(border: TableCellBorder) {
     this.property.borders[
       border.position.toLowerCase() as PositionKeys
@@ -15,6 +15,10 @@ export type TableProperty = {
     right: { val: number; type: WidthType };
   };
   layout?: TableLayoutType;
+  keepWithNext?: boolean;
+  keepTogether?: boolean;
+  caption?: string;
+  description?: string;
 };
 
 export const createDefaultTableCellMargins = () => {
@@ -67,6 +71,26 @@ export class Table {
     return this;
   }
 
+  caption(v: string) {
+    this.property.caption = v;
+    return this;
+  }
+
+  description(v: string) {
+    this.property.description = v;
+    return this;
+  }
+
+  keepWithNext(v: boolean) {
+    this.property.keepWithNext = v;
+    return this;
+  }
+
+  keepTogether() {
+    this.property.keepTogether = true;
+    return this;
+  }
+
   cellMargins(top: number, right: number, bottom: number, left: number) {
     this.property.cellMargins = {
       top: { val: top, type: "DXA" },
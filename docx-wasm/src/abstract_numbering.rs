@@ -22,4 +22,14 @@ impl AbstractNumbering {
         self.0 = self.0.add_level(level.take());
         self
     }
+
+    pub fn style_link(mut self, link: &str) -> Self {
+        self.0 = self.0.style_link(link);
+        self
+    }
+
+    pub fn num_style_link(mut self, link: &str) -> Self {
+        self.0 = self.0.num_style_link(link);
+        self
+    }
 }
@@ -23,6 +23,11 @@ impl Comment {
         self
     }
 
+    pub fn initials(mut self, initials: String) -> Comment {
+        self.0.initials = initials;
+        self
+    }
+
     pub fn date(mut self, date: String) -> Comment {
         self.0.date = date;
         self
@@ -28,11 +28,31 @@ impl Docx {
         self
     }
 
+    pub fn add_alt_chunk(mut self, t: docx_rs::AltChunkType, data: &str) -> Docx {
+        self.0 = self.0.add_alt_chunk(docx_rs::AltChunk::new(t, data));
+        self
+    }
+
+    pub fn add_vba_project(mut self, data: Vec<u8>) -> Docx {
+        self.0 = self.0.add_vba_project(data);
+        self
+    }
+
     pub fn add_table(mut self, t: Table) -> Docx {
         self.0.document = self.0.document.add_table(t.take());
         self
     }
 
+    pub fn header(mut self, h: Header) -> Docx {
+        self.0 = self.0.header(h.take());
+        self
+    }
+
+    pub fn footer(mut self, f: Footer) -> Docx {
+        self.0 = self.0.footer(f.take());
+        self
+    }
+
     pub fn add_abstract_numbering(mut self, num: AbstractNumbering) -> Docx {
         self.0.numberings = self.0.numberings.add_abstract_numbering(num.take());
         self
@@ -63,11 +83,61 @@ impl Docx {
         self
     }
 
+    pub fn theme_font_lang(mut self, val: &str, east_asia: &str, bidi: &str) -> Docx {
+        let mut l = docx_rs::LangSettings::new();
+        if !val.is_empty() {
+            l = l.val(val);
+        }
+        if !east_asia.is_empty() {
+            l = l.east_asia(east_asia);
+        }
+        if !bidi.is_empty() {
+            l = l.bidi(bidi);
+        }
+        self.0 = self.0.theme_font_lang(l);
+        self
+    }
+
+    pub fn default_language(mut self, val: &str, east_asia: &str, bidi: &str) -> Docx {
+        let mut l = docx_rs::LangSettings::new();
+        if !val.is_empty() {
+            l = l.val(val);
+        }
+        if !east_asia.is_empty() {
+            l = l.east_asia(east_asia);
+        }
+        if !bidi.is_empty() {
+            l = l.bidi(bidi);
+        }
+        self.0 = self.0.default_language(l);
+        self
+    }
+
     pub fn add_doc_var(mut self, name: &str, val: &str) -> Docx {
         self.0 = self.0.add_doc_var(name, val);
         self
     }
 
+    pub fn add_index(mut self) -> Docx {
+        self.0 = self.0.add_index(docx_rs::IndexOptions::new());
+        self
+    }
+
+    pub fn add_table_of_figures(mut self, identifier: &str) -> Docx {
+        self.0 = self.0.add_table_of_figures(identifier);
+        self
+    }
+
+    pub fn set_variable(mut self, name: &str, val: &str) -> Docx {
+        self.0 = self.0.set_variable(name, val);
+        self
+    }
+
+    pub fn track_revisions(mut self, v: bool) -> Docx {
+        self.0 = self.0.track_revisions(v);
+        self
+    }
+
     pub fn default_tab_stop(mut self, stop: usize) -> Docx {
         self.0 = self.0.default_tab_stop(stop);
         self
@@ -33,7 +33,7 @@ impl Level {
         self.0.paragraph_property =
             self.0
                 .paragraph_property
-                .indent(Some(left), special_indent, None, None);
+                .indent(Some(docx_rs::Twip(left)), special_indent, None, None);
         self
     }
 
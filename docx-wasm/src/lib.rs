@@ -3,6 +3,8 @@ mod adaptors;
 mod comment;
 mod delete;
 mod doc;
+mod footer;
+mod header;
 mod insert;
 mod level;
 mod level_override;
@@ -23,6 +25,8 @@ pub use adaptors::*;
 pub use comment::*;
 pub use delete::*;
 pub use doc::*;
+pub use footer::*;
+pub use header::*;
 pub use insert::*;
 pub use level::*;
 pub use level_override::*;
@@ -17,6 +17,11 @@ impl Paragraph {
         self
     }
 
+    pub fn add_caption(mut self, label: &str) -> Paragraph {
+        self.0 = self.0.add_caption(label, docx_rs::SeqField::new());
+        self
+    }
+
     pub fn add_insert(mut self, i: Insert) -> Paragraph {
         self.0
             .children
@@ -78,10 +83,10 @@ impl Paragraph {
         special_indent_size: Option<i32>,
     ) -> Paragraph {
         let special_indent = create_special_indent(special_indent_kind, special_indent_size);
-        self.0.property = self
-            .0
-            .property
-            .indent(Some(left), special_indent, None, None);
+        self.0.property =
+            self.0
+                .property
+                .indent(Some(docx_rs::Twip(left)), special_indent, None, None);
         self
     }
 
@@ -119,7 +124,12 @@ impl Paragraph {
         line: Option<u32>,
         spacing_type: Option<docx_rs::LineSpacingType>,
     ) -> Self {
-        self.0 = self.0.line_spacing(before, after, line, spacing_type);
+        self.0 = self.0.line_spacing(
+            before.map(docx_rs::Twip::from),
+            after.map(docx_rs::Twip::from),
+            line,
+            spacing_type,
+        );
         self
     }
 
@@ -34,6 +34,33 @@ impl Run {
         self
     }
 
+    pub fn add_doc_variable_field(mut self, name: &str) -> Run {
+        self.0 = self.0.add_doc_variable_field(name);
+        self
+    }
+
+    pub fn add_date_field(mut self, format: &str, update_on_open: bool) -> Run {
+        self.0 = self.0.add_date_field(format, update_on_open);
+        self
+    }
+
+    pub fn add_time_field(mut self, format: &str, update_on_open: bool) -> Run {
+        self.0 = self.0.add_time_field(format, update_on_open);
+        self
+    }
+
+    pub fn add_index_entry(mut self, text: &str) -> Run {
+        self.0 = self
+            .0
+            .add_index_entry(text, docx_rs::IndexEntryOptions::new());
+        self
+    }
+
+    pub fn add_break_clear(mut self, clear: docx_rs::BreakClear) -> Run {
+        self.0 = self.0.add_break_clear(clear);
+        self
+    }
+
     pub fn add_break(mut self, break_type: docx_rs::BreakType) -> Run {
         self.0
             .children
@@ -46,6 +73,11 @@ impl Run {
         self
     }
 
+    pub fn size_cs(mut self, size: usize) -> Run {
+        self.0.run_property = self.0.run_property.size_cs(size);
+        self
+    }
+
     pub fn color(mut self, color: &str) -> Run {
         self.0.run_property = self.0.run_property.color(color);
         self
@@ -76,6 +108,16 @@ impl Run {
         self
     }
 
+    pub fn disable_vanish(mut self) -> Run {
+        self.0.run_property = self.0.run_property.disable_vanish();
+        self
+    }
+
+    pub fn spec_vanish(mut self) -> Run {
+        self.0.run_property = self.0.run_property.spec_vanish();
+        self
+    }
+
     pub fn fonts(mut self, f: RunFonts) -> Self {
         self.0 = self.0.fonts(f.take());
         self
@@ -86,6 +128,11 @@ impl Run {
         self
     }
 
+    pub fn position(mut self, half_points: i32) -> Run {
+        self.0.run_property = self.0.run_property.position(half_points);
+        self
+    }
+
     pub fn vert_align(mut self, a: VertAlignType) -> Run {
         self.0.run_property = self.0.run_property.vert_align(a);
         self
@@ -49,6 +49,26 @@ impl Table {
         self
     }
 
+    pub fn caption(mut self, v: &str) -> Table {
+        self.0 = self.0.caption(v);
+        self
+    }
+
+    pub fn description(mut self, v: &str) -> Table {
+        self.0 = self.0.description(v);
+        self
+    }
+
+    pub fn keep_with_next(mut self, v: bool) -> Table {
+        self.0 = self.0.keep_with_next(v);
+        self
+    }
+
+    pub fn keep_together(mut self) -> Table {
+        self.0 = self.0.keep_together();
+        self
+    }
+
     pub fn set_cell_margins(
         mut self,
         top: usize,
@@ -45,6 +45,16 @@ impl TableCell {
         self
     }
 
+    pub fn no_wrap(mut self, v: bool) -> TableCell {
+        self.0.property = self.0.property.no_wrap(v);
+        self
+    }
+
+    pub fn fit_text(mut self, v: bool) -> TableCell {
+        self.0.property = self.0.property.fit_text(v);
+        self
+    }
+
     pub fn grid_span(mut self, v: usize) -> TableCell {
         self.0.property = self.0.property.grid_span(v);
         self
@@ -55,6 +65,11 @@ impl TableCell {
         self
     }
 
+    pub fn width_with_type(mut self, v: usize, t: docx_rs::WidthType) -> TableCell {
+        self.0.property = self.0.property.width(v, t);
+        self
+    }
+
     pub fn shading(mut self, t: &str, color: &str, fill: &str) -> TableCell {
         let mut s = Shading::new().color(color).fill(fill);
         if let Ok(t) = docx_rs::ShdType::from_str(t) {